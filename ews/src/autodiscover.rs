@@ -0,0 +1,683 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A transport-agnostic implementation of the Autodiscover process.
+//!
+//! Finding the Autodiscover endpoint for a mailbox involves trying a number of
+//! candidate URLs derived from the user's email address, following redirects,
+//! and eventually falling back to a DNS SRV lookup. This module implements the
+//! logic of that process as a state machine which does not perform any I/O
+//! itself: it tells its consumer which request to send or which DNS name to
+//! resolve next, and the consumer reports the outcome back.
+//!
+//! # Usage
+//!
+//! ```
+//! use ews::autodiscover::{Autodiscover, Endpoint, Response, Step};
+//!
+//! # fn send(_: &ews::autodiscover::Request) -> Response { Response::Success }
+//! # fn resolve(_: &str) -> Vec<ews::autodiscover::SrvRecord> { vec![] }
+//! # fn main() -> Result<(), ews::Error> {
+//! let mut autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox)?;
+//!
+//! let mut step = autodiscover.start()?;
+//! let discovered = loop {
+//!     step = match step {
+//!         Step::SendRequest(request) => autodiscover.handle_response(send(&request))?,
+//!         Step::ResolveSrv { name } => autodiscover.handle_srv_records(resolve(&name))?,
+//!         Step::Complete(discovered) => break discovered,
+//!     };
+//! };
+//!
+//! assert_eq!(
+//!     discovered.url,
+//!     "https://example.com/autodiscover/autodiscover.xml"
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See <https://learn.microsoft.com/en-us/exchange/client-developer/exchange-web-services/autodiscover-for-exchange>
+
+use std::collections::VecDeque;
+
+use crate::Error;
+
+/// The maximum number of redirects followed by default before giving up.
+///
+/// This covers HTTP redirects as well as `redirectAddr` and `redirectUrl`
+/// responses from Autodiscover endpoints, and guards against redirect loops.
+pub const DEFAULT_MAX_REDIRECTS: u8 = 10;
+
+/// The flavor of Autodiscover endpoint to look for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint {
+    /// The "plain old XML" Autodiscover endpoint.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/pox-autodiscover-web-service-reference-for-exchange>
+    Pox,
+
+    /// The SOAP Autodiscover endpoint.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/soap-autodiscover-web-service-reference-for-exchange>
+    Soap,
+}
+
+impl Endpoint {
+    /// The path of the endpoint, relative to the root of a candidate host.
+    fn path(&self) -> &'static str {
+        match self {
+            Endpoint::Pox => "/autodiscover/autodiscover.xml",
+            Endpoint::Soap => "/autodiscover/autodiscover.svc",
+        }
+    }
+}
+
+/// The HTTP method to use for a [`Request`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+/// An HTTP request the consumer should perform on behalf of the state machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    /// The URL to send the request to.
+    pub url: String,
+
+    /// The HTTP method to use.
+    ///
+    /// [`Method::Get`] is only used for the unauthenticated probe of the
+    /// `autodiscover.` subdomain over plain HTTP, which is only expected to
+    /// return an HTTP redirect.
+    pub method: Method,
+
+    /// Whether credentials should be attached to the request.
+    ///
+    /// Credentials must never be sent to an endpoint which is not accessed
+    /// over HTTPS.
+    pub authenticate: bool,
+
+    /// The email address for which the Autodiscover request body should be
+    /// built.
+    ///
+    /// This may differ from the address the discovery was started with if an
+    /// endpoint responded with a `redirectAddr` action.
+    pub email_address: String,
+}
+
+/// A DNS SRV record returned when resolving the name given by
+/// [`Step::ResolveSrv`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SrvRecord {
+    pub priority: u16,
+    pub weight: u16,
+    pub port: u16,
+    pub target: String,
+}
+
+/// The outcome of a [`Request`], as reported by the consumer.
+///
+/// Interpreting the body of an Autodiscover response is left to the consumer,
+/// which only needs to report whether it contained usable settings or one of
+/// the redirect actions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Response {
+    /// The endpoint returned a valid Autodiscover response with settings for
+    /// the mailbox.
+    Success,
+
+    /// The endpoint responded with an HTTP redirect to the given location.
+    HttpRedirect(String),
+
+    /// The endpoint asked for discovery to be restarted with a different email
+    /// address (the `redirectAddr` action).
+    RedirectAddress(String),
+
+    /// The endpoint asked for the request to be sent to a different URL (the
+    /// `redirectUrl` action).
+    RedirectUrl(String),
+
+    /// The request failed, either at the transport level or because the
+    /// endpoint responded with an error or an unusable response.
+    Failed,
+}
+
+/// The next action required from the consumer of the state machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Send the given request and report its outcome with
+    /// [`Autodiscover::handle_response`].
+    SendRequest(Request),
+
+    /// Resolve the SRV records for the given DNS name and report them with
+    /// [`Autodiscover::handle_srv_records`].
+    ///
+    /// If the name cannot be resolved, an empty list should be reported.
+    ResolveSrv { name: String },
+
+    /// Discovery completed successfully.
+    Complete(Discovered),
+}
+
+/// The result of a successful discovery.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Discovered {
+    /// The URL of the Autodiscover endpoint which returned settings.
+    pub url: String,
+
+    /// The email address for which settings were returned.
+    pub email_address: String,
+}
+
+/// A candidate which has yet to be tried.
+#[derive(Clone, Debug)]
+enum Candidate {
+    Request(Request),
+    Srv(String),
+}
+
+/// The input the state machine is currently waiting for.
+#[derive(Clone, Debug)]
+enum Pending {
+    None,
+    Response(Request),
+    SrvRecords,
+}
+
+/// A state machine driving the discovery of an Autodiscover endpoint for a
+/// mailbox.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct Autodiscover {
+    endpoint: Endpoint,
+    email_address: String,
+    max_redirects: u8,
+    redirects: u8,
+    candidates: VecDeque<Candidate>,
+    attempted: Vec<(String, String)>,
+    pending: Pending,
+}
+
+impl Autodiscover {
+    /// Creates a new state machine for discovering the Autodiscover endpoint
+    /// for the given email address.
+    ///
+    /// Returns [`Error::InvalidEmailAddress`] if no domain can be extracted
+    /// from the address.
+    pub fn new(email_address: &str, endpoint: Endpoint) -> Result<Self, Error> {
+        let mut autodiscover = Self {
+            endpoint,
+            email_address: String::new(),
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            redirects: 0,
+            candidates: VecDeque::new(),
+            attempted: Vec::new(),
+            pending: Pending::None,
+        };
+
+        autodiscover.reset_candidates(email_address)?;
+
+        Ok(autodiscover)
+    }
+
+    /// Sets the maximum number of redirects to follow before failing with
+    /// [`Error::AutodiscoverRedirectLimit`].
+    pub fn with_max_redirects(mut self, max_redirects: u8) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Returns the first step of the discovery process.
+    pub fn start(&mut self) -> Result<Step, Error> {
+        self.next_step()
+    }
+
+    /// Reports the outcome of the request returned in the latest
+    /// [`Step::SendRequest`] and returns the next step.
+    ///
+    /// Calling this method when the state machine is not waiting for the
+    /// outcome of a request has no effect beyond returning the next step.
+    pub fn handle_response(&mut self, response: Response) -> Result<Step, Error> {
+        let Pending::Response(request) = std::mem::replace(&mut self.pending, Pending::None) else {
+            return self.next_step();
+        };
+
+        match response {
+            Response::Success => {
+                return Ok(Step::Complete(Discovered {
+                    url: request.url,
+                    email_address: request.email_address,
+                }))
+            }
+
+            Response::HttpRedirect(location) | Response::RedirectUrl(location) => {
+                self.record_redirect()?;
+
+                // Never follow a redirect to an insecure endpoint, as we would
+                // end up sending credentials in the clear.
+                if is_https(&location) {
+                    self.candidates
+                        .push_front(Candidate::Request(self.authenticated_request(location)));
+                }
+            }
+
+            Response::RedirectAddress(email_address) => {
+                self.record_redirect()?;
+                self.reset_candidates(&email_address)?;
+            }
+
+            Response::Failed => (),
+        }
+
+        self.next_step()
+    }
+
+    /// Reports the records resolved for the name returned in the latest
+    /// [`Step::ResolveSrv`] and returns the next step.
+    ///
+    /// Calling this method when the state machine is not waiting for SRV
+    /// records has no effect beyond returning the next step.
+    pub fn handle_srv_records(&mut self, mut records: Vec<SrvRecord>) -> Result<Step, Error> {
+        if !matches!(
+            std::mem::replace(&mut self.pending, Pending::None),
+            Pending::SrvRecords
+        ) {
+            return self.next_step();
+        }
+
+        // Records with the lowest priority are tried first, and records with
+        // the same priority are tried by decreasing weight. Only records
+        // pointing to the HTTPS port are usable.
+        records.retain(|record| record.port == 443 && !record.target.is_empty());
+        records.sort_by(|a, b| a.priority.cmp(&b.priority).then(b.weight.cmp(&a.weight)));
+
+        for record in records.into_iter().rev() {
+            let host = record.target.trim_end_matches('.');
+            let url = format!("https://{host}{}", self.endpoint.path());
+            self.candidates
+                .push_front(Candidate::Request(self.authenticated_request(url)));
+        }
+
+        self.next_step()
+    }
+
+    /// Pops the next viable candidate and returns the corresponding step.
+    fn next_step(&mut self) -> Result<Step, Error> {
+        while let Some(candidate) = self.candidates.pop_front() {
+            match candidate {
+                Candidate::Request(request) => {
+                    // Avoid querying the same endpoint for the same address
+                    // twice, e.g. if an SRV record points to a host we've
+                    // already tried or if endpoints redirect to each other.
+                    if request.method == Method::Post {
+                        let attempt = (request.url.clone(), request.email_address.clone());
+                        if self.attempted.contains(&attempt) {
+                            continue;
+                        }
+
+                        self.attempted.push(attempt);
+                    }
+
+                    self.pending = Pending::Response(request.clone());
+                    return Ok(Step::SendRequest(request));
+                }
+
+                Candidate::Srv(name) => {
+                    self.pending = Pending::SrvRecords;
+                    return Ok(Step::ResolveSrv { name });
+                }
+            }
+        }
+
+        Err(Error::AutodiscoverFailed)
+    }
+
+    /// Replaces the list of candidates with the ones derived from the given
+    /// email address.
+    fn reset_candidates(&mut self, email_address: &str) -> Result<(), Error> {
+        let domain = match email_address.rsplit_once('@') {
+            Some((local, domain))
+                if !local.is_empty() && !domain.is_empty() && !domain.contains('/') =>
+            {
+                domain.to_ascii_lowercase()
+            }
+
+            _ => return Err(Error::InvalidEmailAddress(email_address.to_owned())),
+        };
+
+        self.email_address = email_address.to_owned();
+
+        let path = self.endpoint.path();
+        self.candidates = VecDeque::from([
+            Candidate::Request(self.authenticated_request(format!("https://{domain}{path}"))),
+            Candidate::Request(
+                self.authenticated_request(format!("https://autodiscover.{domain}{path}")),
+            ),
+            Candidate::Request(Request {
+                url: format!("http://autodiscover.{domain}{path}"),
+                method: Method::Get,
+                authenticate: false,
+                email_address: self.email_address.clone(),
+            }),
+            Candidate::Srv(format!("_autodiscover._tcp.{domain}")),
+        ]);
+
+        Ok(())
+    }
+
+    fn authenticated_request(&self, url: String) -> Request {
+        Request {
+            url,
+            method: Method::Post,
+            authenticate: true,
+            email_address: self.email_address.clone(),
+        }
+    }
+
+    fn record_redirect(&mut self) -> Result<(), Error> {
+        if self.redirects >= self.max_redirects {
+            return Err(Error::AutodiscoverRedirectLimit(self.max_redirects));
+        }
+
+        self.redirects += 1;
+        Ok(())
+    }
+}
+
+fn is_https(url: &str) -> bool {
+    url.get(..8)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("https://"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// A fake network, mapping URLs to responses and DNS names to SRV records.
+    #[derive(Default)]
+    struct FakeNetwork {
+        responses: HashMap<String, Response>,
+        srv_records: HashMap<String, Vec<SrvRecord>>,
+        requests: Vec<Request>,
+    }
+
+    impl FakeNetwork {
+        fn run(&mut self, mut autodiscover: Autodiscover) -> Result<Discovered, Error> {
+            let mut step = autodiscover.start()?;
+            loop {
+                step = match step {
+                    Step::SendRequest(request) => {
+                        let response = self
+                            .responses
+                            .get(&request.url)
+                            .cloned()
+                            .unwrap_or(Response::Failed);
+                        self.requests.push(request);
+                        autodiscover.handle_response(response)?
+                    }
+                    Step::ResolveSrv { name } => {
+                        let records = self.srv_records.get(&name).cloned().unwrap_or_default();
+                        autodiscover.handle_srv_records(records)?
+                    }
+                    Step::Complete(discovered) => return Ok(discovered),
+                }
+            }
+        }
+
+        fn requested_urls(&self) -> Vec<&str> {
+            self.requests
+                .iter()
+                .map(|request| request.url.as_str())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn discovers_root_domain() {
+        let mut network = FakeNetwork::default();
+        network.responses.insert(
+            "https://example.com/autodiscover/autodiscover.xml".into(),
+            Response::Success,
+        );
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox).unwrap();
+        let discovered = network.run(autodiscover).unwrap();
+
+        assert_eq!(
+            discovered,
+            Discovered {
+                url: "https://example.com/autodiscover/autodiscover.xml".into(),
+                email_address: "alice@example.com".into(),
+            }
+        );
+        assert_eq!(network.requests.len(), 1);
+    }
+
+    #[test]
+    fn follows_unauthenticated_http_redirect() {
+        let mut network = FakeNetwork::default();
+        network.responses.insert(
+            "http://autodiscover.example.com/autodiscover/autodiscover.svc".into(),
+            Response::HttpRedirect("https://mail.example.net/autodiscover/autodiscover.svc".into()),
+        );
+        network.responses.insert(
+            "https://mail.example.net/autodiscover/autodiscover.svc".into(),
+            Response::Success,
+        );
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Soap).unwrap();
+        let discovered = network.run(autodiscover).unwrap();
+
+        assert_eq!(
+            discovered.url,
+            "https://mail.example.net/autodiscover/autodiscover.svc"
+        );
+        assert_eq!(
+            network.requested_urls(),
+            [
+                "https://example.com/autodiscover/autodiscover.svc",
+                "https://autodiscover.example.com/autodiscover/autodiscover.svc",
+                "http://autodiscover.example.com/autodiscover/autodiscover.svc",
+                "https://mail.example.net/autodiscover/autodiscover.svc",
+            ]
+        );
+
+        let probe = &network.requests[2];
+        assert_eq!(probe.method, Method::Get);
+        assert!(
+            !probe.authenticate,
+            "credentials must not be sent over HTTP"
+        );
+    }
+
+    #[test]
+    fn does_not_follow_redirect_to_http() {
+        let mut network = FakeNetwork::default();
+        network.responses.insert(
+            "https://example.com/autodiscover/autodiscover.xml".into(),
+            Response::RedirectUrl(
+                "http://insecure.example.com/autodiscover/autodiscover.xml".into(),
+            ),
+        );
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox).unwrap();
+        let err = network.run(autodiscover).unwrap_err();
+
+        assert!(matches!(err, Error::AutodiscoverFailed), "got: {err:?}");
+        assert!(network
+            .requests
+            .iter()
+            .all(|request| is_https(&request.url) || !request.authenticate));
+    }
+
+    #[test]
+    fn falls_back_to_srv_records() {
+        let mut network = FakeNetwork::default();
+        network.srv_records.insert(
+            "_autodiscover._tcp.example.com".into(),
+            vec![
+                SrvRecord {
+                    priority: 10,
+                    weight: 0,
+                    port: 443,
+                    target: "backup.example.net.".into(),
+                },
+                SrvRecord {
+                    priority: 0,
+                    weight: 0,
+                    port: 80,
+                    target: "plain.example.net.".into(),
+                },
+                SrvRecord {
+                    priority: 0,
+                    weight: 5,
+                    port: 443,
+                    target: "primary.example.net.".into(),
+                },
+            ],
+        );
+        network.responses.insert(
+            "https://backup.example.net/autodiscover/autodiscover.xml".into(),
+            Response::Success,
+        );
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox).unwrap();
+        let discovered = network.run(autodiscover).unwrap();
+
+        assert_eq!(
+            discovered.url,
+            "https://backup.example.net/autodiscover/autodiscover.xml"
+        );
+        assert_eq!(
+            &network.requested_urls()[3..],
+            [
+                "https://primary.example.net/autodiscover/autodiscover.xml",
+                "https://backup.example.net/autodiscover/autodiscover.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn restarts_with_redirect_address() {
+        let mut network = FakeNetwork::default();
+        network.responses.insert(
+            "https://example.com/autodiscover/autodiscover.xml".into(),
+            Response::RedirectAddress("alice@example.org".into()),
+        );
+        network.responses.insert(
+            "https://autodiscover.example.org/autodiscover/autodiscover.xml".into(),
+            Response::Success,
+        );
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox).unwrap();
+        let discovered = network.run(autodiscover).unwrap();
+
+        assert_eq!(
+            discovered,
+            Discovered {
+                url: "https://autodiscover.example.org/autodiscover/autodiscover.xml".into(),
+                email_address: "alice@example.org".into(),
+            }
+        );
+        assert_eq!(network.requests[2].email_address, "alice@example.org");
+    }
+
+    #[test]
+    fn does_not_retry_endpoints_in_redirect_loop() {
+        let mut network = FakeNetwork::default();
+        network.responses.insert(
+            "https://example.com/autodiscover/autodiscover.xml".into(),
+            Response::RedirectUrl("https://example.org/autodiscover/autodiscover.xml".into()),
+        );
+        network.responses.insert(
+            "https://example.org/autodiscover/autodiscover.xml".into(),
+            Response::RedirectUrl("https://example.com/autodiscover/autodiscover.xml".into()),
+        );
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox).unwrap();
+        let err = network.run(autodiscover).unwrap_err();
+
+        assert!(matches!(err, Error::AutodiscoverFailed), "got: {err:?}");
+        assert_eq!(
+            network.requested_urls(),
+            [
+                "https://example.com/autodiscover/autodiscover.xml",
+                "https://example.org/autodiscover/autodiscover.xml",
+                "https://autodiscover.example.com/autodiscover/autodiscover.xml",
+                "http://autodiscover.example.com/autodiscover/autodiscover.xml",
+            ]
+        );
+    }
+
+    #[test]
+    fn enforces_redirect_limit() {
+        let mut network = FakeNetwork::default();
+        for i in 0..10 {
+            network.responses.insert(
+                format!("https://example{i}.com/autodiscover/autodiscover.xml"),
+                Response::RedirectAddress(format!("alice@example{}.com", i + 1)),
+            );
+        }
+
+        let autodiscover = Autodiscover::new("alice@example0.com", Endpoint::Pox)
+            .unwrap()
+            .with_max_redirects(3);
+        let err = network.run(autodiscover).unwrap_err();
+
+        assert!(
+            matches!(err, Error::AutodiscoverRedirectLimit(3)),
+            "got: {err:?}"
+        );
+        assert_eq!(network.requests.len(), 4);
+    }
+
+    #[test]
+    fn enforces_maximum_redirect_limit_without_overflow() {
+        let mut network = FakeNetwork::default();
+        for i in 0..300 {
+            network.responses.insert(
+                format!("https://example{i}.com/autodiscover/autodiscover.xml"),
+                Response::RedirectAddress(format!("alice@example{}.com", i + 1)),
+            );
+        }
+
+        let autodiscover = Autodiscover::new("alice@example0.com", Endpoint::Pox)
+            .unwrap()
+            .with_max_redirects(u8::MAX);
+        let err = network.run(autodiscover).unwrap_err();
+
+        assert!(
+            matches!(err, Error::AutodiscoverRedirectLimit(u8::MAX)),
+            "got: {err:?}"
+        );
+        assert_eq!(network.requests.len(), 256);
+    }
+
+    #[test]
+    fn fails_when_candidates_are_exhausted() {
+        let mut network = FakeNetwork::default();
+
+        let autodiscover = Autodiscover::new("alice@example.com", Endpoint::Pox).unwrap();
+        let err = network.run(autodiscover).unwrap_err();
+
+        assert!(matches!(err, Error::AutodiscoverFailed), "got: {err:?}");
+        assert_eq!(network.requests.len(), 3);
+    }
+
+    #[test]
+    fn rejects_invalid_email_address() {
+        for address in ["alice", "alice@", "@example.com"] {
+            let err = Autodiscover::new(address, Endpoint::Pox).unwrap_err();
+            assert!(
+                matches!(err, Error::InvalidEmailAddress(ref value) if value == address),
+                "got: {err:?}"
+            );
+        }
+    }
+}
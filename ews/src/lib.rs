@@ -11,6 +11,8 @@ mod types;
 
 pub use types::*;
 
+pub mod autodiscover;
+
 #[cfg(feature = "interop")]
 pub mod interop;
 
@@ -35,4 +37,34 @@ pub enum Error {
 
     #[error("unknown server version: {0}")]
     UnknownServerVersion(String),
+
+    #[error(
+        "request is not supported by server version {version}: {}",
+        .unsupported.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    UnsupportedByServerVersion {
+        version: types::server_version::ExchangeServerVersion,
+        unsupported: Vec<types::server_version::UnsupportedElement>,
+    },
+
+    #[error("the server supports no schema version older than {0}")]
+    NoOlderServerVersion(types::server_version::ExchangeServerVersion),
+
+    #[error("invalid time zone: {0}")]
+    InvalidTimeZone(String),
+
+    #[error("invalid category list: {0}")]
+    InvalidCategoryList(String),
+
+    #[error("invalid item or folder ID: {0}")]
+    InvalidId(String),
+
+    #[error("invalid email address: {0}")]
+    InvalidEmailAddress(String),
+
+    #[error("autodiscover exceeded the maximum of {0} redirects")]
+    AutodiscoverRedirectLimit(u8),
+
+    #[error("no autodiscover endpoint could be found")]
+    AutodiscoverFailed,
 }
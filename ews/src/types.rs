@@ -9,22 +9,49 @@ pub use common::*;
 pub use operations::*;
 pub mod soap;
 
+pub mod add_delegate;
+pub mod archive_item;
+pub mod convert_id;
 pub mod copy_folder;
 pub mod copy_item;
 pub mod create_folder;
 pub mod create_item;
+pub mod create_user_configuration;
 pub mod delete_folder;
 pub mod delete_item;
+pub mod delete_user_configuration;
 pub mod empty_folder;
+pub mod export_items;
 pub mod find_item;
+pub mod find_people;
+pub mod get_delegate;
 pub mod get_folder;
+pub mod get_inbox_rules;
 pub mod get_item;
+pub mod get_mail_tips;
+pub mod get_persona;
+pub mod get_reminders;
+pub mod get_room_lists;
+pub mod get_rooms;
+pub mod get_server_time_zones;
+pub mod get_service_configuration;
+pub mod get_user_configuration;
+pub mod get_user_oof_settings;
+pub mod get_user_photo;
+pub mod get_user_retention_policy_tags;
 pub mod mark_all_read;
 pub mod mark_as_junk;
 pub mod move_folder;
 pub mod move_item;
+pub mod perform_reminder_action;
+pub mod remove_delegate;
 pub mod server_version;
+pub mod set_user_oof_settings;
 pub mod sync_folder_hierarchy;
 pub mod sync_folder_items;
+pub mod update_delegate;
 pub mod update_folder;
+pub mod update_inbox_rules;
 pub mod update_item;
+pub mod update_user_configuration;
+pub mod upload_items;
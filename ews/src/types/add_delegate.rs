@@ -0,0 +1,132 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    delegates::{DelegateResponseMessage, DelegateUsers, DeliverMeetingRequests},
+    Mailbox, MESSAGES_NS_URI,
+};
+
+/// A request to grant one or more users delegate access to a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/adddelegate>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    DelegateResponseMessage,
+    min_version = Exchange2007_SP1,
+    shape = ResponseClass
+)]
+pub struct AddDelegate {
+    /// The mailbox to grant access to.
+    pub mailbox: Mailbox,
+
+    /// The users to add as delegates.
+    pub delegate_users: DelegateUsers,
+
+    /// The recipients of meeting requests sent to the mailbox.
+    pub deliver_meeting_requests: Option<DeliverMeetingRequests>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        add_delegate::{AddDelegate, AddDelegateResponse},
+        delegates::{
+            DelegateFolderPermissionLevel, DelegatePermissions, DelegateResponseMessage,
+            DelegateUserResponseMessage, DelegateUsers, DeliverMeetingRequests,
+        },
+        response::{ResponseCode, ResponseError},
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        DelegateUser, Mailbox, ResponseClass, ResponseMessages, UserId,
+    };
+
+    #[test]
+    fn test_serialize_add_delegate() {
+        let add_delegate = AddDelegate {
+            mailbox: Mailbox {
+                email_address: Some("owner@example.com".to_string()),
+                ..Default::default()
+            },
+            delegate_users: DelegateUsers {
+                inner: vec![DelegateUser {
+                    user_id: UserId {
+                        primary_smtp_address: Some("assistant@example.com".to_string()),
+                        ..Default::default()
+                    },
+                    delegate_permissions: Some(DelegatePermissions {
+                        calendar_folder_permission_level: Some(
+                            DelegateFolderPermissionLevel::Editor,
+                        ),
+                        inbox_folder_permission_level: Some(
+                            DelegateFolderPermissionLevel::Reviewer,
+                        ),
+                        ..Default::default()
+                    }),
+                    receive_copies_of_meeting_messages: Some(true),
+                    view_private_items: Some(false),
+                }],
+            },
+            deliver_meeting_requests: Some(DeliverMeetingRequests::DelegatesAndSendInformationToMe),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <AddDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <Mailbox>
+                <t:EmailAddress>owner@example.com</t:EmailAddress>
+              </Mailbox>
+              <DelegateUsers>
+                <t:DelegateUser>
+                  <t:UserId>
+                    <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                  </t:UserId>
+                  <t:DelegatePermissions>
+                    <t:CalendarFolderPermissionLevel>Editor</t:CalendarFolderPermissionLevel>
+                    <t:InboxFolderPermissionLevel>Reviewer</t:InboxFolderPermissionLevel>
+                  </t:DelegatePermissions>
+                  <t:ReceiveCopiesOfMeetingMessages>true</t:ReceiveCopiesOfMeetingMessages>
+                  <t:ViewPrivateItems>false</t:ViewPrivateItems>
+                </t:DelegateUser>
+              </DelegateUsers>
+              <DeliverMeetingRequests>DelegatesAndSendInformationToMe</DeliverMeetingRequests>
+            </AddDelegate>"#,
+        );
+
+        assert_serialized_content(&add_delegate, "AddDelegate", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_add_delegate_response() {
+        let content = r#"<AddDelegateResponse ResponseClass="Success" xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseCode>NoError</ResponseCode>
+                  <ResponseMessages>
+                    <DelegateUserResponseMessageType ResponseClass="Error">
+                      <MessageText>The delegate already exists.</MessageText>
+                      <ResponseCode>ErrorDelegateAlreadyExists</ResponseCode>
+                      <DescriptiveLinkKey>0</DescriptiveLinkKey>
+                    </DelegateUserResponseMessageType>
+                  </ResponseMessages>
+                </AddDelegateResponse>"#;
+
+        let expected = AddDelegateResponse {
+            response_message: ResponseClass::Success(DelegateResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![ResponseClass::<DelegateUserResponseMessage>::Error(
+                        ResponseError {
+                            message_text: "The delegate already exists.".to_string(),
+                            response_code: ResponseCode::ErrorDelegateAlreadyExists,
+                            message_xml: None,
+                        },
+                    )],
+                },
+                deliver_meeting_requests: None,
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,104 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{BaseFolderId, BaseItemId, ItemResponseMessage, MESSAGES_NS_URI};
+
+/// A request to move one or more items from the primary mailbox to the
+/// archive mailbox.
+///
+/// The items are moved to the folder of the archive mailbox corresponding to
+/// the source folder, which is created if it does not exist.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archiveitem-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(ItemResponseMessage, min_version = Exchange2013)]
+pub struct ArchiveItem {
+    /// The folder in the primary mailbox from which to archive the items.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivesourcefolderid>
+    pub archive_source_folder_id: BaseFolderId,
+
+    /// A list of IDs of the items to archive.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemids>
+    pub item_ids: Vec<BaseItemId>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        DistinguishedFolderName, ItemId, Items, Message, RealItem, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn test_serialize_archive_item() {
+        let archive_item = ArchiveItem {
+            archive_source_folder_id: BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Inbox,
+                change_key: None,
+                mailbox: None,
+            },
+            item_ids: vec![BaseItemId::ItemId {
+                id: "AAMkADAwATM0MDAAMS1q".to_string(),
+                change_key: Some("CQAAABYAAAAA".to_string()),
+            }],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <ArchiveItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <ArchiveSourceFolderId>
+                <t:DistinguishedFolderId Id="inbox"></t:DistinguishedFolderId>
+              </ArchiveSourceFolderId>
+              <ItemIds>
+                <t:ItemId Id="AAMkADAwATM0MDAAMS1q" ChangeKey="CQAAABYAAAAA"/>
+              </ItemIds>
+            </ArchiveItem>"#,
+        );
+
+        assert_serialized_content(&archive_item, "ArchiveItem", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_archive_item_response() {
+        let content = r#"
+            <m:ArchiveItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                   xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:ArchiveItemResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:Items>
+                    <t:Message>
+                      <t:ItemId Id="AAMkADAwATM0MDAAMS1r" ChangeKey="CQAAABYAAAAB"/>
+                    </t:Message>
+                  </m:Items>
+                </m:ArchiveItemResponseMessage>
+              </m:ResponseMessages>
+            </m:ArchiveItemResponse>"#;
+
+        let expected = ArchiveItemResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(ItemResponseMessage {
+                    items: Items {
+                        inner: vec![RealItem::Message(Message {
+                            item_id: Some(ItemId {
+                                id: "AAMkADAwATM0MDAAMS1r".to_string(),
+                                change_key: Some("CQAAABYAAAAB".to_string()),
+                            }),
+                            ..Default::default()
+                        })],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
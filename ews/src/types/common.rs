@@ -4,15 +4,39 @@
 
 use std::ops::{Deref, DerefMut};
 
+use base64::prelude::{Engine, BASE64_STANDARD};
 use serde::{de, Deserialize, Deserializer};
 use time::format_description::well_known::Iso8601;
 use time::{OffsetDateTime, PrimitiveDateTime};
-use xml_struct::XmlSerialize;
+use xml_struct::{XmlSerialize, XmlSerializeAttr};
+
+use crate::server_version::{
+    check_fields, strip_fields, ExchangeServerVersion, UnsupportedElement, VersionRequirements,
+    VersionedField,
+};
 
 pub mod response;
 pub use self::response::{ResponseClass, ResponseMessages};
 pub mod message_xml;
 pub use self::message_xml::MessageXml;
+pub mod time_zone;
+pub use self::time_zone::{MeetingTimeZone, TimeZoneDefinition};
+pub mod inbox_rules;
+pub use self::inbox_rules::Rule;
+pub mod oof;
+pub use self::oof::UserOofSettings;
+pub mod delegates;
+pub use self::delegates::DelegateUser;
+pub mod permissions;
+pub use self::permissions::{CalendarPermissionSet, EffectiveRights, PermissionSet};
+pub mod ids;
+pub use self::ids::{EntryId, EwsId, IdFormat};
+pub mod personas;
+pub use self::personas::Persona;
+pub mod restrictions;
+pub use self::restrictions::Restriction;
+pub mod user_configuration;
+pub use self::user_configuration::{CategoryList, UserConfiguration, UserConfigurationName};
 
 pub(crate) const MESSAGES_NS_URI: &str =
     "http://schemas.microsoft.com/exchange/services/2006/messages";
@@ -24,14 +48,27 @@ pub(crate) const TYPES_NS_URI: &str = "http://schemas.microsoft.com/exchange/ser
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/foldershape>.
 #[derive(Clone, Debug, Default, XmlSerialize)]
 pub struct FolderShape {
+    /// The base set of properties to include, which may be extended by other
+    /// fields.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/baseshape>
     #[xml_struct(ns_prefix = "t")]
     pub base_shape: BaseShape,
+
+    /// A list of properties which should be included in addition to those
+    /// implied by the base shape, e.g. `folder:UnreadCount` or extended MAPI
+    /// properties.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/additionalproperties>
+    #[xml_struct(ns_prefix = "t")]
+    pub additional_properties: Option<Vec<PathToElement>>,
 }
 
 /// The item properties which should be included in the response.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemshape>.
 #[derive(Clone, Debug, Default, XmlSerialize)]
+#[allow(non_snake_case)]
 pub struct ItemShape {
     /// The base set of properties to include, which may be extended by other
     /// fields.
@@ -46,6 +83,78 @@ pub struct ItemShape {
     #[xml_struct(ns_prefix = "t")]
     pub include_mime_content: Option<bool>,
 
+    /// The format in which to return the body of items.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/bodytype>
+    #[xml_struct(ns_prefix = "t")]
+    pub body_type: Option<BodyTypeResponse>,
+
+    /// The format in which to return the unique body of items.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/uniquebodytype>
+    ///
+    /// This element was introduced in Exchange 2010.
+    #[xml_struct(ns_prefix = "t")]
+    pub unique_body_type: Option<BodyTypeResponse>,
+
+    /// The format in which to return the normalized body of items.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/normalizedbodytype>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub normalized_body_type: Option<BodyTypeResponse>,
+
+    /// Whether potentially unsafe HTML content, such as scripts, should be
+    /// removed from bodies.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/filterhtmlcontent>
+    ///
+    /// This element was introduced in Exchange 2010.
+    #[xml_struct(ns_prefix = "t")]
+    pub filter_html_content: Option<bool>,
+
+    /// Whether HTML bodies should be converted to UTF-8.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/converthtmlcodepagetoutf8>
+    ///
+    /// This element was introduced in Exchange 2010 SP1.
+    #[xml_struct(ns_prefix = "t")]
+    pub convert_html_code_page_to_UTF8: Option<bool>,
+
+    /// A template for the URLs of inline images in HTML bodies.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/inlineimageurltemplate>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub inline_image_url_template: Option<String>,
+
+    /// Whether external images in HTML bodies should be blocked.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/blockexternalimages>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub block_external_images: Option<bool>,
+
+    /// Whether links in HTML bodies should be made to open in a new window.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/addblanktargettolinks>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub add_blank_target_to_links: Option<bool>,
+
+    /// The maximum size of bodies, in bytes, beyond which they are
+    /// truncated.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/maximumbodysize>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub maximum_body_size: Option<u32>,
+
     /// A list of properties which should be included in addition to those
     /// implied by other fields.
     ///
@@ -54,8 +163,93 @@ pub struct ItemShape {
     pub additional_properties: Option<Vec<PathToElement>>,
 }
 
+/// The fields of [`ItemShape`] which require a minimum server version.
+///
+/// Fields which only affect the presentation of bodies are removed by
+/// [`VersionRequirements::strip_unsupported`]. Those which limit the content
+/// returned, such as `FilterHtmlContent` or `MaximumBodySize`, are not, as
+/// silently returning unfiltered or complete bodies would defeat their purpose.
+const ITEM_SHAPE_VERSIONED_FIELDS: &[VersionedField<ItemShape>] = {
+    use ExchangeServerVersion::{Exchange2010, Exchange2010_SP1, Exchange2013};
+
+    &[
+        VersionedField::removable(
+            "UniqueBodyType",
+            Exchange2010,
+            |shape| shape.unique_body_type.is_some(),
+            |shape| shape.unique_body_type = None,
+        ),
+        VersionedField::removable(
+            "NormalizedBodyType",
+            Exchange2013,
+            |shape| shape.normalized_body_type.is_some(),
+            |shape| shape.normalized_body_type = None,
+        ),
+        VersionedField::new("FilterHtmlContent", Exchange2010, |shape| {
+            shape.filter_html_content.is_some()
+        }),
+        VersionedField::removable(
+            "ConvertHtmlCodePageToUTF8",
+            Exchange2010_SP1,
+            |shape| shape.convert_html_code_page_to_UTF8.is_some(),
+            |shape| shape.convert_html_code_page_to_UTF8 = None,
+        ),
+        VersionedField::removable(
+            "InlineImageUrlTemplate",
+            Exchange2013,
+            |shape| shape.inline_image_url_template.is_some(),
+            |shape| shape.inline_image_url_template = None,
+        ),
+        VersionedField::new("BlockExternalImages", Exchange2013, |shape| {
+            shape.block_external_images.is_some()
+        }),
+        VersionedField::removable(
+            "AddBlankTargetToLinks",
+            Exchange2013,
+            |shape| shape.add_blank_target_to_links.is_some(),
+            |shape| shape.add_blank_target_to_links = None,
+        ),
+        VersionedField::new("MaximumBodySize", Exchange2013, |shape| {
+            shape.maximum_body_size.is_some()
+        }),
+    ]
+};
+
+impl VersionRequirements for ItemShape {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        check_fields(
+            ITEM_SHAPE_VERSIONED_FIELDS,
+            self,
+            version,
+            path,
+            unsupported,
+        );
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        strip_fields(ITEM_SHAPE_VERSIONED_FIELDS, self, version);
+    }
+}
+
+/// The format in which to return a body.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/bodytype>
+#[derive(Clone, Copy, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum BodyTypeResponse {
+    /// The format in which the body is stored.
+    Best,
+    HTML,
+    Text,
+}
+
 /// An identifier for a property on an Exchange entity.
-#[derive(Clone, Debug, XmlSerialize)]
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
 #[xml_struct(variant_ns_prefix = "t")]
 pub enum PathToElement {
     /// An identifier for an extended MAPI property.
@@ -73,11 +267,13 @@ pub enum PathToElement {
     ExtendedFieldURI {
         /// A well-known identifier for a property set.
         #[xml_struct(attribute)]
+        #[serde(rename = "@DistinguishedPropertySetId")]
         distinguished_property_set_id: Option<DistinguishedPropertySet>,
 
         /// A GUID representing a property set.
         // TODO: This could use a strong type for representing a GUID.
         #[xml_struct(attribute)]
+        #[serde(rename = "@PropertySetId")]
         property_set_id: Option<String>,
 
         /// Specifies a property by integer tag.
@@ -85,18 +281,22 @@ pub enum PathToElement {
         // representation is preferred, and we should restrict the possible
         // values per the docs.
         #[xml_struct(attribute)]
+        #[serde(rename = "@PropertyTag")]
         property_tag: Option<String>,
 
         /// The name of a property within a specified property set.
         #[xml_struct(attribute)]
+        #[serde(rename = "@PropertyName")]
         property_name: Option<String>,
 
         /// The dispatch ID of a property within a specified property set.
         #[xml_struct(attribute)]
+        #[serde(rename = "@PropertyId")]
         property_id: Option<String>,
 
         /// The value type of the desired property.
         #[xml_struct(attribute)]
+        #[serde(rename = "@PropertyType")]
         property_type: PropertyType,
     },
 
@@ -112,6 +312,7 @@ pub enum PathToElement {
         // TODO: We could use an enum for this field. It's just large and not
         // worth typing out by hand.
         #[xml_struct(attribute)]
+        #[serde(rename = "@FieldURI")]
         field_URI: String,
     },
 
@@ -122,10 +323,12 @@ pub enum PathToElement {
     IndexedFieldURI {
         /// The well-known string identifier of the property.
         #[xml_struct(attribute)]
+        #[serde(rename = "@FieldURI")]
         field_URI: String,
 
         /// The member within the dictionary to access.
         #[xml_struct(attribute)]
+        #[serde(rename = "@FieldIndex")]
         field_index: String,
     },
 }
@@ -300,7 +503,7 @@ pub struct ItemResponseMessage {
 }
 
 /// An identifier for an Exchange folder.
-#[derive(Clone, Debug, XmlSerialize)]
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
 #[xml_struct(variant_ns_prefix = "t")]
 pub enum BaseFolderId {
     /// An identifier for an arbitrary folder.
@@ -308,9 +511,11 @@ pub enum BaseFolderId {
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/folderid>.
     FolderId {
         #[xml_struct(attribute)]
+        #[serde(rename = "@Id")]
         id: String,
 
         #[xml_struct(attribute)]
+        #[serde(rename = "@ChangeKey")]
         change_key: Option<String>,
     },
 
@@ -320,21 +525,146 @@ pub enum BaseFolderId {
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distinguishedfolderid>.
     DistinguishedFolderId {
         #[xml_struct(attribute)]
-        id: String,
+        #[serde(rename = "@Id")]
+        id: DistinguishedFolderName,
 
         #[xml_struct(attribute)]
+        #[serde(rename = "@ChangeKey")]
         change_key: Option<String>,
+
+        /// The mailbox containing the folder, if other than the mailbox of the
+        /// authenticated user, e.g. a shared or delegated mailbox.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailbox>
+        #[xml_struct(ns_prefix = "t")]
+        #[serde(rename = "Mailbox")]
+        mailbox: Option<Mailbox>,
     },
 }
 
+/// The well-known name of a folder which can be referenced without knowing its
+/// identifier.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distinguishedfolderid#attributes>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum DistinguishedFolderName {
+    Calendar,
+    Contacts,
+    DeletedItems,
+    Drafts,
+    Inbox,
+    Journal,
+    Notes,
+    Outbox,
+    SentItems,
+    Tasks,
+    MsgFolderRoot,
+    PublicFoldersRoot,
+    Root,
+    JunkEmail,
+    SearchFolders,
+    VoiceMail,
+    RecoverableItemsRoot,
+    RecoverableItemsDeletions,
+    RecoverableItemsVersions,
+    RecoverableItemsPurges,
+    ArchiveRoot,
+    ArchiveMsgFolderRoot,
+    ArchiveDeletedItems,
+    ArchiveInbox,
+    ArchiveRecoverableItemsRoot,
+    ArchiveRecoverableItemsDeletions,
+    ArchiveRecoverableItemsVersions,
+    ArchiveRecoverableItemsPurges,
+    SyncIssues,
+    Conflicts,
+    LocalFailures,
+    ServerFailures,
+    RecipientCache,
+    QuickContacts,
+    ConversationHistory,
+    AdminAuditLogs,
+    ToDoSearch,
+    MyContacts,
+    Directory,
+    IMContactList,
+    PeopleConnect,
+    Favorites,
+
+    /// A name not known to this crate, e.g. one introduced in a newer version
+    /// of Exchange.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl DistinguishedFolderName {
+    /// The name of the folder as represented in EWS, e.g. "inbox".
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Calendar => "calendar",
+            Self::Contacts => "contacts",
+            Self::DeletedItems => "deleteditems",
+            Self::Drafts => "drafts",
+            Self::Inbox => "inbox",
+            Self::Journal => "journal",
+            Self::Notes => "notes",
+            Self::Outbox => "outbox",
+            Self::SentItems => "sentitems",
+            Self::Tasks => "tasks",
+            Self::MsgFolderRoot => "msgfolderroot",
+            Self::PublicFoldersRoot => "publicfoldersroot",
+            Self::Root => "root",
+            Self::JunkEmail => "junkemail",
+            Self::SearchFolders => "searchfolders",
+            Self::VoiceMail => "voicemail",
+            Self::RecoverableItemsRoot => "recoverableitemsroot",
+            Self::RecoverableItemsDeletions => "recoverableitemsdeletions",
+            Self::RecoverableItemsVersions => "recoverableitemsversions",
+            Self::RecoverableItemsPurges => "recoverableitemspurges",
+            Self::ArchiveRoot => "archiveroot",
+            Self::ArchiveMsgFolderRoot => "archivemsgfolderroot",
+            Self::ArchiveDeletedItems => "archivedeleteditems",
+            Self::ArchiveInbox => "archiveinbox",
+            Self::ArchiveRecoverableItemsRoot => "archiverecoverableitemsroot",
+            Self::ArchiveRecoverableItemsDeletions => "archiverecoverableitemsdeletions",
+            Self::ArchiveRecoverableItemsVersions => "archiverecoverableitemsversions",
+            Self::ArchiveRecoverableItemsPurges => "archiverecoverableitemspurges",
+            Self::SyncIssues => "syncissues",
+            Self::Conflicts => "conflicts",
+            Self::LocalFailures => "localfailures",
+            Self::ServerFailures => "serverfailures",
+            Self::RecipientCache => "recipientcache",
+            Self::QuickContacts => "quickcontacts",
+            Self::ConversationHistory => "conversationhistory",
+            Self::AdminAuditLogs => "adminauditlogs",
+            Self::ToDoSearch => "todosearch",
+            Self::MyContacts => "mycontacts",
+            Self::Directory => "directory",
+            Self::IMContactList => "imcontactlist",
+            Self::PeopleConnect => "peopleconnect",
+            Self::Favorites => "favorites",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl XmlSerializeAttr for DistinguishedFolderName {
+    fn serialize_as_attribute(&self, start_tag: &mut quick_xml::events::BytesStart, name: &str) {
+        self.as_str().serialize_as_attribute(start_tag, name)
+    }
+}
+
 /// The unique identifier of a folder.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/folderid>
 #[derive(Clone, Debug, Deserialize, PartialEq, XmlSerialize, Eq)]
 pub struct FolderId {
+    #[xml_struct(attribute)]
     #[serde(rename = "@Id")]
     pub id: String,
 
+    #[xml_struct(attribute)]
     #[serde(rename = "@ChangeKey")]
     pub change_key: Option<String>,
 }
@@ -412,6 +742,34 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The actions the authenticated user may perform on the folder.
+        #[xml_struct(ns_prefix = "t")]
+        effective_rights: Option<EffectiveRights>,
+
+        /// The retention policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/policytag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        policy_tag: Option<RetentionTag>,
+
+        /// The archive policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        archive_tag: Option<RetentionTag>,
+
+        /// The permissions granted to users on the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-calendarpermissionsettype>
+        ///
+        /// This element was introduced in Exchange 2007 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<CalendarPermissionSet>,
     },
 
     /// A contacts folder in a mailbox.
@@ -439,6 +797,34 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The actions the authenticated user may perform on the folder.
+        #[xml_struct(ns_prefix = "t")]
+        effective_rights: Option<EffectiveRights>,
+
+        /// The retention policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/policytag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        policy_tag: Option<RetentionTag>,
+
+        /// The archive policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        archive_tag: Option<RetentionTag>,
+
+        /// The permissions granted to users on the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-permissionsettype>
+        ///
+        /// This element was introduced in Exchange 2007 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
     },
 
     /// A folder in a mailbox.
@@ -467,6 +853,34 @@ pub enum Folder {
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
 
+        /// The actions the authenticated user may perform on the folder.
+        #[xml_struct(ns_prefix = "t")]
+        effective_rights: Option<EffectiveRights>,
+
+        /// The retention policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/policytag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        policy_tag: Option<RetentionTag>,
+
+        /// The archive policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        archive_tag: Option<RetentionTag>,
+
+        /// The permissions granted to users on the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-permissionsettype>
+        ///
+        /// This element was introduced in Exchange 2007 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
+
         #[xml_struct(ns_prefix = "t")]
         unread_count: Option<u32>,
     },
@@ -496,6 +910,45 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The actions the authenticated user may perform on the folder.
+        #[xml_struct(ns_prefix = "t")]
+        effective_rights: Option<EffectiveRights>,
+
+        /// The retention policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/policytag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        policy_tag: Option<RetentionTag>,
+
+        /// The archive policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        archive_tag: Option<RetentionTag>,
+
+        /// The permissions granted to users on the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-permissionsettype>
+        ///
+        /// This element was introduced in Exchange 2007 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
+
+        #[xml_struct(ns_prefix = "t")]
+        unread_count: Option<u32>,
+
+        /// The conditions defining which items the search folder contains.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/searchparameters>
+        // The `SearchParameters` are boxed so as to keep the in-memory size of
+        // the enum itself relatively low.
+        #[xml_struct(ns_prefix = "t")]
+        search_parameters: Option<Box<SearchParameters>>,
     },
 
     /// A task folder in a mailbox.
@@ -523,9 +976,260 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The actions the authenticated user may perform on the folder.
+        #[xml_struct(ns_prefix = "t")]
+        effective_rights: Option<EffectiveRights>,
+
+        /// The retention policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/policytag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        policy_tag: Option<RetentionTag>,
+
+        /// The archive policy applied to the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+        ///
+        /// This element was introduced in Exchange 2010 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        archive_tag: Option<RetentionTag>,
+
+        /// The permissions granted to users on the folder.
+        ///
+        /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-permissionsettype>
+        ///
+        /// This element was introduced in Exchange 2007 SP1.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
+
+        #[xml_struct(ns_prefix = "t")]
+        unread_count: Option<u32>,
     },
 }
 
+impl Folder {
+    /// Gets the extended properties of the folder, as requested with the
+    /// `additional_properties` of a [`FolderShape`].
+    pub fn extended_properties(&self) -> &[ExtendedProperty] {
+        let extended_property = match self {
+            Self::CalendarFolder {
+                extended_property, ..
+            }
+            | Self::ContactsFolder {
+                extended_property, ..
+            }
+            | Self::Folder {
+                extended_property, ..
+            }
+            | Self::SearchFolder {
+                extended_property, ..
+            }
+            | Self::TasksFolder {
+                extended_property, ..
+            } => extended_property,
+        };
+
+        extended_property.as_deref().unwrap_or_default()
+    }
+
+    /// Gets the number of unread items in the folder, if it was included in
+    /// the response.
+    ///
+    /// Calendar and contacts folders have no unread count.
+    pub fn unread_count(&self) -> Option<u32> {
+        match self {
+            Self::CalendarFolder { .. } | Self::ContactsFolder { .. } => None,
+            Self::Folder { unread_count, .. }
+            | Self::SearchFolder { unread_count, .. }
+            | Self::TasksFolder { unread_count, .. } => *unread_count,
+        }
+    }
+
+    /// Gets the actions the authenticated user may perform on the folder, if
+    /// they were included in the response.
+    pub fn effective_rights(&self) -> Option<&EffectiveRights> {
+        match self {
+            Self::CalendarFolder {
+                effective_rights, ..
+            }
+            | Self::ContactsFolder {
+                effective_rights, ..
+            }
+            | Self::Folder {
+                effective_rights, ..
+            }
+            | Self::SearchFolder {
+                effective_rights, ..
+            }
+            | Self::TasksFolder {
+                effective_rights, ..
+            } => effective_rights.as_ref(),
+        }
+    }
+
+    /// Gets the retention policy tag applied to the folder, if it was included
+    /// in the response.
+    pub fn policy_tag(&self) -> Option<&RetentionTag> {
+        match self {
+            Self::CalendarFolder { policy_tag, .. }
+            | Self::ContactsFolder { policy_tag, .. }
+            | Self::Folder { policy_tag, .. }
+            | Self::SearchFolder { policy_tag, .. }
+            | Self::TasksFolder { policy_tag, .. } => policy_tag.as_ref(),
+        }
+    }
+
+    /// Gets the archive policy tag applied to the folder, if it was included
+    /// in the response.
+    pub fn archive_tag(&self) -> Option<&RetentionTag> {
+        match self {
+            Self::CalendarFolder { archive_tag, .. }
+            | Self::ContactsFolder { archive_tag, .. }
+            | Self::Folder { archive_tag, .. }
+            | Self::SearchFolder { archive_tag, .. }
+            | Self::TasksFolder { archive_tag, .. } => archive_tag.as_ref(),
+        }
+    }
+
+    /// Whether the folder has a permission set, of either kind.
+    fn has_permission_set(&self) -> bool {
+        match self {
+            Self::CalendarFolder { permission_set, .. } => permission_set.is_some(),
+            Self::ContactsFolder { permission_set, .. }
+            | Self::Folder { permission_set, .. }
+            | Self::SearchFolder { permission_set, .. }
+            | Self::TasksFolder { permission_set, .. } => permission_set.is_some(),
+        }
+    }
+
+    fn effective_rights_mut(&mut self) -> Option<&mut EffectiveRights> {
+        match self {
+            Self::CalendarFolder {
+                effective_rights, ..
+            }
+            | Self::ContactsFolder {
+                effective_rights, ..
+            }
+            | Self::Folder {
+                effective_rights, ..
+            }
+            | Self::SearchFolder {
+                effective_rights, ..
+            }
+            | Self::TasksFolder {
+                effective_rights, ..
+            } => effective_rights.as_mut(),
+        }
+    }
+
+    /// The name of the XML element representing this folder.
+    fn element_name(&self) -> &'static str {
+        match self {
+            Self::CalendarFolder { .. } => "CalendarFolder",
+            Self::ContactsFolder { .. } => "ContactsFolder",
+            Self::Folder { .. } => "Folder",
+            Self::SearchFolder { .. } => "SearchFolder",
+            Self::TasksFolder { .. } => "TasksFolder",
+        }
+    }
+}
+
+/// The fields of [`Folder`] which require a minimum server version.
+///
+/// The remaining fields, including `SearchParameters`, are supported by all
+/// versions. None of these fields can be removed without changing the meaning
+/// of the request.
+const FOLDER_VERSIONED_FIELDS: &[VersionedField<Folder>] = {
+    use ExchangeServerVersion::{Exchange2007_SP1, Exchange2010_SP1};
+
+    &[
+        VersionedField::new("PermissionSet", Exchange2007_SP1, |folder| {
+            folder.has_permission_set()
+        }),
+        VersionedField::new("PolicyTag", Exchange2010_SP1, |folder| {
+            folder.policy_tag().is_some()
+        }),
+        VersionedField::new("ArchiveTag", Exchange2010_SP1, |folder| {
+            folder.archive_tag().is_some()
+        }),
+    ]
+};
+
+impl VersionRequirements for Folder {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        let path = format!("{path}/{}", self.element_name());
+        check_fields(FOLDER_VERSIONED_FIELDS, self, version, &path, unsupported);
+
+        if let Some(effective_rights) = self.effective_rights() {
+            effective_rights.check_version(
+                version,
+                &format!("{path}/EffectiveRights"),
+                unsupported,
+            );
+        }
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        strip_fields(FOLDER_VERSIONED_FIELDS, self, version);
+
+        if let Some(effective_rights) = self.effective_rights_mut() {
+            effective_rights.strip_unsupported(version);
+        }
+    }
+}
+
+/// The conditions defining which items a search folder contains.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/searchparameters>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchParameters {
+    /// Whether to search the subfolders of the base folders.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Traversal")]
+    pub traversal: Option<SearchFolderTraversal>,
+
+    /// The condition which items must meet.
+    #[xml_struct(ns_prefix = "t")]
+    pub restriction: Restriction,
+
+    /// The folders to search.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/basefolderids>
+    #[xml_struct(ns_prefix = "t")]
+    pub base_folder_ids: BaseFolderIds,
+}
+
+/// Whether a search folder includes items in the subfolders of its base
+/// folders.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/searchparameters#attributes>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum SearchFolderTraversal {
+    /// Search only the base folders.
+    Shallow,
+
+    /// Search the base folders and all of their subfolders.
+    Deep,
+}
+
+/// A list of folder identifiers.
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct BaseFolderIds {
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value")]
+    pub inner: Vec<BaseFolderId>,
+}
+
 /// An array of items.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct Items {
@@ -582,6 +1286,25 @@ impl RealItem {
         }
     }
 
+    /// Return a mutable reference to the [`Message`] object contained within
+    /// this [`RealItem`].
+    pub fn inner_message_mut(&mut self) -> &mut Message {
+        use RealItem::*;
+        match self {
+            Item(message)
+            | Message(message)
+            | CalendarItem(message)
+            | Contact(message)
+            | DistributionList(message)
+            | MeetingMessage(message)
+            | MeetingRequest(message)
+            | MeetingResponse(message)
+            | MeetingCancellation(message)
+            | Task(message)
+            | PostItem(message) => message,
+        }
+    }
+
     /// Take ownership of the inner [`Message`].
     pub fn into_inner_message(self) -> Message {
         use RealItem::*;
@@ -599,6 +1322,41 @@ impl RealItem {
             | PostItem(message) => message,
         }
     }
+
+    /// The name of the XML element representing this item.
+    fn element_name(&self) -> &'static str {
+        use RealItem::*;
+        match self {
+            Item(_) => "Item",
+            Message(_) => "Message",
+            CalendarItem(_) => "CalendarItem",
+            Contact(_) => "Contact",
+            DistributionList(_) => "DistributionList",
+            MeetingMessage(_) => "MeetingMessage",
+            MeetingRequest(_) => "MeetingRequest",
+            MeetingResponse(_) => "MeetingResponse",
+            MeetingCancellation(_) => "MeetingCancellation",
+            Task(_) => "Task",
+            PostItem(_) => "PostItem",
+        }
+    }
+}
+
+impl VersionRequirements for RealItem {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        let path = format!("{path}/{}", self.element_name());
+        self.inner_message()
+            .check_version(version, &path, unsupported);
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        self.inner_message_mut().strip_unsupported(version);
+    }
 }
 
 /// A date and time with second precision.
@@ -647,6 +1405,204 @@ impl XmlSerialize for DateTime {
     }
 }
 
+/// A signed length of time, represented in XML as an `xs:duration`, e.g.
+/// `-PT8H` or `P1DT2H30M`.
+///
+/// Only durations which can be expressed in days or smaller units are
+/// supported, as years and months do not have a fixed length.
+///
+/// See <https://www.w3.org/TR/xmlschema-2/#duration>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Duration(pub time::Duration);
+
+impl std::str::FromStr for Duration {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid duration `{value}`");
+
+        let (negative, rest) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value),
+        };
+        let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+        if rest.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut duration = time::Duration::ZERO;
+        let mut has_component = false;
+        let mut has_time_component = false;
+        let mut in_time = false;
+        let mut number = String::new();
+        for c in rest.chars() {
+            match c {
+                '0'..='9' | '.' => number.push(c),
+                'T' if !in_time && number.is_empty() => in_time = true,
+                _ => {
+                    if number.is_empty() {
+                        return Err(invalid());
+                    }
+
+                    let seconds_per_unit: i64 = match (in_time, c) {
+                        (false, 'W') => 7 * 24 * 60 * 60,
+                        (false, 'D') => 24 * 60 * 60,
+                        (true, 'H') => 60 * 60,
+                        (true, 'M') => 60,
+                        (true, 'S') => 1,
+                        (false, 'Y' | 'M') => {
+                            return Err(format!(
+                            "duration `{value}` uses years or months, which have no fixed length"
+                        ))
+                        }
+                        _ => return Err(invalid()),
+                    };
+
+                    let component = if seconds_per_unit == 1 {
+                        // Seconds are the only component which may be
+                        // fractional.
+                        time::Duration::checked_seconds_f64(number.parse().map_err(|_| invalid())?)
+                            .ok_or_else(invalid)?
+                    } else {
+                        number
+                            .parse::<i64>()
+                            .ok()
+                            .and_then(|count| count.checked_mul(seconds_per_unit))
+                            .map(time::Duration::seconds)
+                            .ok_or_else(invalid)?
+                    };
+
+                    duration = duration.checked_add(component).ok_or_else(invalid)?;
+                    has_component = true;
+                    has_time_component |= in_time;
+                    number.clear();
+                }
+            }
+        }
+
+        // A `T` designator must be followed by at least one time component.
+        if !number.is_empty() || !has_component || (in_time && !has_time_component) {
+            return Err(invalid());
+        }
+
+        Ok(Self(if negative { -duration } else { duration }))
+    }
+}
+
+impl std::fmt::Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let duration = self.0.abs();
+        if self.0.is_negative() {
+            f.write_str("-")?;
+        }
+        f.write_str("P")?;
+
+        let days = duration.whole_days();
+        if days != 0 {
+            write!(f, "{days}D")?;
+        }
+
+        let hours = duration.whole_hours() % 24;
+        let minutes = duration.whole_minutes() % 60;
+        let seconds = duration.whole_seconds() % 60;
+        let nanoseconds = duration.subsec_nanoseconds();
+        if hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+            // A duration must contain at least one component.
+            return if days == 0 {
+                f.write_str("T0S")
+            } else {
+                Ok(())
+            };
+        }
+
+        f.write_str("T")?;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes != 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if nanoseconds != 0 {
+            let fraction = format!("{nanoseconds:09}");
+            write!(f, "{seconds}.{}S", fraction.trim_end_matches('0'))?;
+        } else if seconds != 0 {
+            write!(f, "{seconds}S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.trim().parse().map_err(de::Error::custom)
+    }
+}
+
+impl XmlSerialize for Duration {
+    /// Serializes a `Duration` as an XML text content node in `xs:duration`
+    /// format.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.to_string().serialize_child_nodes(writer)
+    }
+}
+
+impl XmlSerializeAttr for Duration {
+    /// Serializes a `Duration` as an XML attribute value in `xs:duration`
+    /// format.
+    fn serialize_as_attribute(&self, start_tag: &mut quick_xml::events::BytesStart, name: &str) {
+        self.to_string().serialize_as_attribute(start_tag, name)
+    }
+}
+
+/// Arbitrary binary data, represented in XML as an `xs:base64Binary`.
+///
+/// See <https://www.w3.org/TR/xmlschema-2/#base64Binary>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Base64Binary(pub Vec<u8>);
+
+impl<'de> Deserialize<'de> for Base64Binary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        // Whitespace is allowed anywhere in the lexical representation.
+        let value: String = value.split_ascii_whitespace().collect();
+
+        BASE64_STANDARD
+            .decode(value)
+            .map(Self)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl XmlSerialize for Base64Binary {
+    /// Serializes binary data as base64-encoded XML text content.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        BASE64_STANDARD
+            .encode(&self.0)
+            .serialize_child_nodes(writer)
+    }
+}
+
 /// An email message.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/message-ex15websvcsotherref>
@@ -836,6 +1792,169 @@ pub struct Message {
     /// This element was introduced in Exchange 2013.
     #[xml_struct(ns_prefix = "t")]
     pub flag: Option<Flag>,
+
+    /// The retention policy applied to the item, which determines when it is
+    /// deleted.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/policytag>
+    ///
+    /// This element was introduced in Exchange 2010 SP1.
+    #[xml_struct(ns_prefix = "t")]
+    pub policy_tag: Option<RetentionTag>,
+
+    /// The archive policy applied to the item, which determines when it is
+    /// moved to the archive mailbox.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+    ///
+    /// This element was introduced in Exchange 2010 SP1.
+    #[xml_struct(ns_prefix = "t")]
+    pub archive_tag: Option<RetentionTag>,
+
+    /// The date and time at which the item will be deleted or archived
+    /// according to its policies. This is read-only.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/retentiondate>
+    ///
+    /// This element was introduced in Exchange 2010 SP1.
+    #[xml_struct(ns_prefix = "t")]
+    pub retention_date: Option<DateTime>,
+
+    /// The part of the body which is unique to the item within its
+    /// conversation, i.e. without quoted earlier messages. This is read-only.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/uniquebody>
+    ///
+    /// This element was introduced in Exchange 2010.
+    #[xml_struct(ns_prefix = "t")]
+    pub unique_body: Option<Body>,
+
+    /// The body of the item, normalized to well-formed HTML or plain text.
+    /// This is read-only.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/normalizedbody>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub normalized_body: Option<Body>,
+
+    /// The body of the item as plain text. This is read-only.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/textbody>
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub text_body: Option<Body>,
+
+    /// The time zone of a calendar item, in the format used by Exchange 2007.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/meetingtimezone>
+    #[xml_struct(ns_prefix = "t")]
+    pub meeting_time_zone: Option<MeetingTimeZone>,
+
+    /// The time zone of the start of a calendar item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/starttimezone>
+    ///
+    /// This element was introduced in Exchange 2010.
+    #[xml_struct(ns_prefix = "t")]
+    pub start_time_zone: Option<TimeZoneDefinition>,
+
+    /// The time zone of the end of a calendar item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/endtimezone>
+    ///
+    /// This element was introduced in Exchange 2010.
+    #[xml_struct(ns_prefix = "t")]
+    pub end_time_zone: Option<TimeZoneDefinition>,
+}
+
+/// The fields of [`Message`] which require a minimum server version.
+///
+/// Only read-only fields are removed by
+/// [`VersionRequirements::strip_unsupported`]; the server ignores them in
+/// requests, whereas removing other fields would change the meaning of the
+/// request.
+const MESSAGE_VERSIONED_FIELDS: &[VersionedField<Message>] = {
+    use ExchangeServerVersion::{Exchange2010, Exchange2010_SP1, Exchange2013};
+
+    &[
+        VersionedField::removable(
+            "Preview",
+            Exchange2013,
+            |message| message.preview.is_some(),
+            |message| message.preview = None,
+        ),
+        VersionedField::new("Flag", Exchange2013, |message| message.flag.is_some()),
+        VersionedField::new("PolicyTag", Exchange2010_SP1, |message| {
+            message.policy_tag.is_some()
+        }),
+        VersionedField::new("ArchiveTag", Exchange2010_SP1, |message| {
+            message.archive_tag.is_some()
+        }),
+        VersionedField::removable(
+            "RetentionDate",
+            Exchange2010_SP1,
+            |message| message.retention_date.is_some(),
+            |message| message.retention_date = None,
+        ),
+        VersionedField::removable(
+            "UniqueBody",
+            Exchange2010,
+            |message| message.unique_body.is_some(),
+            |message| message.unique_body = None,
+        ),
+        VersionedField::removable(
+            "NormalizedBody",
+            Exchange2013,
+            |message| message.normalized_body.is_some(),
+            |message| message.normalized_body = None,
+        ),
+        VersionedField::removable(
+            "TextBody",
+            Exchange2013,
+            |message| message.text_body.is_some(),
+            |message| message.text_body = None,
+        ),
+        VersionedField::new("StartTimeZone", Exchange2010, |message| {
+            message.start_time_zone.is_some()
+        }),
+        VersionedField::new("EndTimeZone", Exchange2010, |message| {
+            message.end_time_zone.is_some()
+        }),
+    ]
+};
+
+impl VersionRequirements for Message {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        check_fields(MESSAGE_VERSIONED_FIELDS, self, version, path, unsupported);
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        strip_fields(MESSAGE_VERSIONED_FIELDS, self, version);
+    }
+}
+
+/// A retention or archive policy tag applied to an item or folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/archivetag>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct RetentionTag {
+    /// Whether the tag was applied to the item or folder directly, rather than
+    /// inherited from its parent folder.
+    #[serde(rename = "@IsExplicit")]
+    #[xml_struct(attribute)]
+    pub is_explicit: bool,
+
+    /// The GUID identifying the policy tag.
+    #[serde(rename = "$text")]
+    #[xml_struct(flatten)]
+    pub retention_id: String,
 }
 
 /// An extended MAPI property of an Exchange item or folder.
@@ -951,18 +2070,85 @@ pub struct Mailbox {
     /// possible values, it also appears that `SYSTEM` is a value that sometimes
     /// occurs. Since the documentation isn't clear, this is a free-form string
     /// field.
+    #[xml_struct(ns_prefix = "t")]
     pub routing_type: Option<String>,
 
     /// The type of sender/recipient represented by this mailbox.
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxtype>
+    #[xml_struct(ns_prefix = "t")]
     pub mailbox_type: Option<MailboxType>,
 
     /// An identifier for a contact or list of contacts corresponding to this
     /// mailbox.
+    #[xml_struct(ns_prefix = "t")]
     pub item_id: Option<ItemId>,
 }
 
+/// The address of a mailbox, as used by availability and out-of-office
+/// operations.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailbox-availability>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EmailAddress {
+    /// The name of the mailbox's user.
+    #[xml_struct(ns_prefix = "t")]
+    pub name: Option<String>,
+
+    /// The SMTP address of the mailbox.
+    #[xml_struct(ns_prefix = "t")]
+    pub address: String,
+
+    /// The protocol used in routing to the mailbox, e.g. `SMTP`.
+    #[xml_struct(ns_prefix = "t")]
+    pub routing_type: Option<String>,
+}
+
+/// An identifier for a user, e.g. a delegate or the subject of a folder
+/// permission.
+///
+/// At least one of the fields must be set.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userid>
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserId {
+    /// The security identifier of the user's account, e.g. `S-1-5-21-...`.
+    #[xml_struct(ns_prefix = "t")]
+    pub SID: Option<String>,
+
+    /// The primary SMTP address of the user.
+    #[xml_struct(ns_prefix = "t")]
+    pub primary_smtp_address: Option<String>,
+
+    /// The display name of the user.
+    #[xml_struct(ns_prefix = "t")]
+    pub display_name: Option<String>,
+
+    /// A special user which is not identified by an account.
+    #[xml_struct(ns_prefix = "t")]
+    pub distinguished_user: Option<DistinguishedUser>,
+
+    /// The identity of a user outside the organization.
+    #[xml_struct(ns_prefix = "t")]
+    pub external_user_identity: Option<String>,
+}
+
+/// A special user which is not identified by an account.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distinguisheduser>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DistinguishedUser {
+    /// Any user who is not otherwise granted permissions.
+    Default,
+
+    /// Any unauthenticated user.
+    Anonymous,
+}
+
 /// The type of sender or recipient a mailbox represents.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxtype>
@@ -1000,6 +2186,56 @@ pub struct StringElement {
     pub string: String,
 }
 
+/// A list of string values.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/string>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ArrayOfStrings {
+    #[serde(rename = "String", default)]
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for ArrayOfStrings {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for value in &self.inner {
+            value.serialize_as_element(writer, "t:String")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A list of email addresses.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/address-emailaddresstype>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ArrayOfEmailAddresses {
+    #[serde(rename = "Address", default)]
+    pub inner: Vec<Mailbox>,
+}
+
+impl XmlSerialize for ArrayOfEmailAddresses {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for address in &self.inner {
+            address.serialize_as_element(writer, "t:Address")?;
+        }
+
+        Ok(())
+    }
+}
+
 /// The sensitivity of the contents of an item.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sensitivity>
@@ -1557,6 +2793,22 @@ mod tests {
         assert_deserialized_content(content, expected);
     }
 
+    #[test]
+    fn test_archive_tag_round_trip() {
+        let message = Message {
+            archive_tag: Some(RetentionTag {
+                is_explicit: true,
+                retention_id: "9c4a1f2b-6e3d-4c8a-b7f0-1d2e3f4a5b6c".to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let expected = r#"<Message><t:ArchiveTag IsExplicit="true">9c4a1f2b-6e3d-4c8a-b7f0-1d2e3f4a5b6c</t:ArchiveTag></Message>"#;
+        assert_serialized_content(&message, "Message", expected);
+
+        assert_deserialized_content(expected, message);
+    }
+
     #[test]
     fn test_deserialize_empty_header() {
         let content = r#"
@@ -0,0 +1,181 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ResponseMessages, UserId};
+
+/// A user granted delegate access to a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegateuser>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegateUser {
+    /// The delegate.
+    #[xml_struct(ns_prefix = "t")]
+    pub user_id: UserId,
+
+    /// The access the delegate has to each of the mailbox's default folders.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegatepermissions>
+    #[xml_struct(ns_prefix = "t")]
+    pub delegate_permissions: Option<DelegatePermissions>,
+
+    /// Whether the delegate receives copies of meeting-related messages sent
+    /// to the mailbox.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/receivecopiesofmeetingmessages>
+    #[xml_struct(ns_prefix = "t")]
+    pub receive_copies_of_meeting_messages: Option<bool>,
+
+    /// Whether the delegate can view items marked as private.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/viewprivateitems>
+    #[xml_struct(ns_prefix = "t")]
+    pub view_private_items: Option<bool>,
+}
+
+/// The access a delegate has to each of a mailbox's default folders.
+///
+/// Folders which are omitted are not changed when updating a delegate.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegatepermissions>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegatePermissions {
+    #[xml_struct(ns_prefix = "t")]
+    pub calendar_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub tasks_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub inbox_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contacts_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub notes_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub journal_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+}
+
+/// The access a delegate has to a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarfolderpermissionlevel>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DelegateFolderPermissionLevel {
+    /// No access.
+    None,
+
+    /// Can read, create and modify items.
+    Editor,
+
+    /// Can read items.
+    Reviewer,
+
+    /// Can read and create items.
+    Author,
+
+    /// Permissions which do not match any of the other levels.
+    Custom,
+}
+
+/// The recipients of meeting requests sent to a mailbox with delegates.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delivermeetingrequests>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DeliverMeetingRequests {
+    /// Meeting requests are sent to the delegates only.
+    DelegatesOnly,
+
+    /// Meeting requests are sent to the delegates and the mailbox owner.
+    DelegatesAndMe,
+
+    /// Meeting requests are sent to the delegates, and the mailbox owner
+    /// receives a notification.
+    DelegatesAndSendInformationToMe,
+
+    /// Meeting requests are not forwarded to delegates.
+    NoForward,
+}
+
+/// A list of users to grant delegate access to or to update.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegateusers>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DelegateUsers {
+    pub inner: Vec<DelegateUser>,
+}
+
+impl XmlSerialize for DelegateUsers {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for user in &self.inner {
+            user.serialize_as_element(writer, "t:DelegateUser")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A list of identifiers of delegates.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userids>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserIds {
+    pub inner: Vec<UserId>,
+}
+
+impl XmlSerialize for UserIds {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for user_id in &self.inner {
+            user_id.serialize_as_element(writer, "t:UserId")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The contents of a successful response to a delegate management operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getdelegateresponse>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegateResponseMessage {
+    /// The outcome of the operation for each delegate.
+    #[serde(default)]
+    pub response_messages: ResponseMessages<DelegateUserResponseMessage>,
+
+    /// The recipients of meeting requests sent to the mailbox.
+    ///
+    /// This is only included in responses to `GetDelegate` requests.
+    pub deliver_meeting_requests: Option<DeliverMeetingRequests>,
+}
+
+/// The outcome of a delegate management operation for a single delegate.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegateuserresponsemessagetype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegateUserResponseMessage {
+    /// The delegate, omitted in responses to `RemoveDelegate` requests.
+    pub delegate_user: Option<DelegateUser>,
+}
@@ -0,0 +1,358 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Local decoding of item and folder identifiers.
+//!
+//! Identifiers can be converted between all formats by the server with the
+//! [`ConvertId`] operation. The EWS and entry ID formats can however be
+//! decoded locally, which avoids a round-trip e.g. when looking up a message
+//! by the entry ID given by an Outlook add-in or a MAPI tool.
+//!
+//! [`ConvertId`]: crate::convert_id::ConvertId
+
+use base64::prelude::{Engine, BASE64_STANDARD, BASE64_URL_SAFE};
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::Error;
+
+/// The format of an item or folder identifier.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/alternateid>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum IdFormat {
+    /// The identifier format used by Exchange 2007 EWS.
+    EwsLegacyId,
+
+    /// The identifier format used by EWS, e.g. in [`ItemId`].
+    ///
+    /// [`ItemId`]: crate::ItemId
+    EwsId,
+
+    /// A MAPI entry ID, encoded as base64.
+    EntryId,
+
+    /// A MAPI entry ID, encoded as hexadecimal.
+    HexEntryId,
+
+    /// The identifier format used by Exchange store APIs.
+    StoreId,
+
+    /// The identifier format used by Outlook Web Access.
+    OwaId,
+}
+
+/// A MAPI entry ID, the identifier used for items and folders by Outlook and
+/// MAPI-based tools.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryId(pub Vec<u8>);
+
+impl EntryId {
+    /// Decodes an entry ID in the [`IdFormat::EntryId`] format.
+    pub fn from_base64(id: &str) -> Result<Self, Error> {
+        BASE64_STANDARD
+            .decode(id)
+            .map(Self)
+            .map_err(|err| Error::InvalidId(format!("invalid base64 in entry ID: {err}")))
+    }
+
+    /// Decodes an entry ID in the [`IdFormat::HexEntryId`] format.
+    pub fn from_hex(id: &str) -> Result<Self, Error> {
+        if !id.len().is_multiple_of(2) {
+            return Err(Error::InvalidId(
+                "hex entry ID has an odd number of digits".to_string(),
+            ));
+        }
+
+        (0..id.len())
+            .step_by(2)
+            .map(|start| {
+                id.get(start..start + 2)
+                    .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                    .ok_or_else(|| {
+                        Error::InvalidId(format!("invalid hex digits in entry ID at {start}"))
+                    })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    /// Encodes the entry ID in the [`IdFormat::EntryId`] format.
+    pub fn to_base64(&self) -> String {
+        BASE64_STANDARD.encode(&self.0)
+    }
+
+    /// Encodes the entry ID in the [`IdFormat::HexEntryId`] format, as used
+    /// by Outlook add-ins and MAPI tools.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02X}")).collect()
+    }
+}
+
+/// An identifier in the [`IdFormat::EwsId`] format, decoded into its parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EwsId {
+    /// What the identifier refers to and how its mailbox is identified.
+    pub storage_type: IdStorageType,
+
+    /// The mailbox containing the item or folder, either as a GUID or as an
+    /// SMTP address depending on the storage type.
+    pub moniker: String,
+
+    /// How the identifier relates to a recurring series, if at all.
+    pub processing_instruction: IdProcessingInstruction,
+
+    /// The identifier of the object in the mailbox store.
+    ///
+    /// For items and folders, this is their MAPI entry ID.
+    pub store_id: Vec<u8>,
+}
+
+impl EwsId {
+    /// Decodes an identifier as returned by EWS, e.g. the `id` of an
+    /// [`ItemId`].
+    ///
+    /// Both standard and URL-safe base64, as used in Outlook Web Access links,
+    /// are accepted.
+    ///
+    /// [`ItemId`]: crate::ItemId
+    pub fn decode(id: &str) -> Result<Self, Error> {
+        let bytes = if id.contains(['-', '_']) {
+            BASE64_URL_SAFE.decode(id)
+        } else {
+            BASE64_STANDARD.decode(id)
+        }
+        .map_err(|err| Error::InvalidId(format!("invalid base64 in EWS ID: {err}")))?;
+
+        let (&compression, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| Error::InvalidId("empty EWS ID".to_string()))?;
+
+        let bytes = match compression {
+            0 => bytes.to_vec(),
+            1 => decompress(bytes)?,
+            _ => {
+                return Err(Error::InvalidId(format!(
+                    "unsupported EWS ID compression {compression}"
+                )))
+            }
+        };
+
+        let mut reader = Reader(&bytes);
+
+        let storage_type = match reader.read_u8()? {
+            0 => IdStorageType::MailboxItemSmtpAddressBased,
+            3 => IdStorageType::MailboxItemMailboxGuidBased,
+            4 => IdStorageType::ConversationIdMailboxGuidBased,
+            storage_type => {
+                return Err(Error::InvalidId(format!(
+                    "unsupported EWS ID storage type {storage_type}"
+                )))
+            }
+        };
+
+        let moniker = reader.read_sized()?;
+        let moniker = String::from_utf8(moniker.to_vec())
+            .map_err(|_| Error::InvalidId("invalid mailbox moniker in EWS ID".to_string()))?;
+
+        let processing_instruction = match reader.read_u8()? {
+            0 => IdProcessingInstruction::Normal,
+            1 => IdProcessingInstruction::Recurrence,
+            2 => IdProcessingInstruction::Series,
+            instruction => {
+                return Err(Error::InvalidId(format!(
+                    "unknown EWS ID processing instruction {instruction}"
+                )))
+            }
+        };
+
+        let store_id = reader.read_sized()?.to_vec();
+
+        Ok(Self {
+            storage_type,
+            moniker,
+            processing_instruction,
+            store_id,
+        })
+    }
+
+    /// Gets the GUID of the mailbox containing the item or folder, if the
+    /// identifier includes it.
+    pub fn mailbox_guid(&self) -> Option<&str> {
+        match self.storage_type {
+            IdStorageType::MailboxItemMailboxGuidBased
+            | IdStorageType::ConversationIdMailboxGuidBased => Some(&self.moniker),
+            IdStorageType::MailboxItemSmtpAddressBased => None,
+        }
+    }
+
+    /// Gets the MAPI entry ID of the item or folder, if the identifier refers
+    /// to one.
+    pub fn entry_id(&self) -> Option<EntryId> {
+        match self.storage_type {
+            IdStorageType::MailboxItemSmtpAddressBased
+            | IdStorageType::MailboxItemMailboxGuidBased => Some(EntryId(self.store_id.clone())),
+            IdStorageType::ConversationIdMailboxGuidBased => None,
+        }
+    }
+}
+
+/// What an [`EwsId`] refers to and how its mailbox is identified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdStorageType {
+    /// An item or folder in a mailbox identified by its SMTP address, as used
+    /// by Exchange 2007.
+    MailboxItemSmtpAddressBased,
+
+    /// An item or folder in a mailbox identified by its GUID.
+    MailboxItemMailboxGuidBased,
+
+    /// A conversation in a mailbox identified by its GUID.
+    ConversationIdMailboxGuidBased,
+}
+
+/// How an [`EwsId`] relates to a recurring series.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdProcessingInstruction {
+    /// The identifier refers to a single object.
+    Normal,
+
+    /// The identifier refers to an occurrence of a recurring series.
+    Recurrence,
+
+    /// The identifier refers to the master of a recurring series.
+    Series,
+}
+
+/// Reverses the run-length encoding of compressed EWS IDs, in which any two
+/// consecutive identical bytes are followed by the number of further
+/// repetitions of that byte.
+fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::with_capacity(bytes.len() * 2);
+
+    let mut bytes = bytes.iter().copied().peekable();
+    while let Some(byte) = bytes.next() {
+        output.push(byte);
+
+        if bytes.next_if_eq(&byte).is_some() {
+            let count = bytes.next().ok_or_else(|| {
+                Error::InvalidId("truncated run in compressed EWS ID".to_string())
+            })?;
+
+            output.resize(output.len() + 1 + usize::from(count), byte);
+        }
+    }
+
+    Ok(output)
+}
+
+/// A cursor over the binary contents of an EWS ID.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        let (&byte, rest) = self
+            .0
+            .split_first()
+            .ok_or_else(|| Error::InvalidId("truncated EWS ID".to_string()))?;
+        self.0 = rest;
+
+        Ok(byte)
+    }
+
+    /// Reads a byte string preceded by its length as a little-endian 16-bit
+    /// integer.
+    fn read_sized(&mut self) -> Result<&'a [u8], Error> {
+        let length = u16::from_le_bytes([self.read_u8()?, self.read_u8()?]);
+        let length = usize::from(length);
+
+        if self.0.len() < length {
+            return Err(Error::InvalidId("truncated EWS ID".to_string()));
+        }
+
+        let (value, rest) = self.0.split_at(length);
+        self.0 = rest;
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_ews_id() {
+        let id = EwsId::decode(
+            "AAMkAGZhN2IxYTA0LWNiNzItNDFjNy05Njc0LWUwYjY3YzA1NzY3YgBGAAAAAADCK5fj4tX4S5QH7d0T9DhVBwDSFR9yJ2aGSbiB0hgrs4OBAAAAjbp8AADSFR9yJ2aGSbiB0hgrs4OBAAAAjbrTAAA=",
+        )
+        .unwrap();
+
+        assert_eq!(id.storage_type, IdStorageType::MailboxItemMailboxGuidBased);
+        assert_eq!(
+            id.mailbox_guid(),
+            Some("fa7b1a04-cb72-41c7-9674-e0b67c05767b")
+        );
+        assert_eq!(id.processing_instruction, IdProcessingInstruction::Normal);
+        assert_eq!(
+            id.entry_id().unwrap().to_hex(),
+            "00000000C22B97E3E2D5F84B9407EDDD13F438550700D2151F7227668649B881D2182BB383810000008DBA7C0000D2151F7227668649B881D2182BB383810000008DBAD30000"
+        );
+    }
+
+    #[test]
+    fn decode_compressed_ews_id() {
+        let id = EwsId::decode(
+            "AQMkADAwATM0MDAAMS1iNWQ2LTE4ZDgtMDACLTAwCgAuAAADeS1ONaGyw9Tl9gcYKTpLXAEA0hUfcidmhkm4gdIYK7ODgQAAAY26fAAAAA==",
+        )
+        .unwrap();
+
+        assert_eq!(
+            id.mailbox_guid(),
+            Some("00034001-b5d6-18d8-0000-000000000000")
+        );
+        assert_eq!(
+            id.entry_id().unwrap().to_hex(),
+            "00000000792D4E35A1B2C3D4E5F60718293A4B5C0100D2151F7227668649B881D2182BB383810000008DBA7C0000"
+        );
+    }
+
+    #[test]
+    fn decode_url_safe_ews_id() {
+        let standard = EwsId::decode(
+            "AAMkAGZhN2IxYTA0LWNiNzItNDFjNy05Njc0LWUwYjY3YzA1NzY3YgAYAAAAAADCK5fj4tX4S5QH7d0T9DhVAQD7/w==",
+        )
+        .unwrap();
+        let url_safe = EwsId::decode(
+            "AAMkAGZhN2IxYTA0LWNiNzItNDFjNy05Njc0LWUwYjY3YzA1NzY3YgAYAAAAAADCK5fj4tX4S5QH7d0T9DhVAQD7_w==",
+        )
+        .unwrap();
+
+        assert_eq!(standard, url_safe);
+        assert_eq!(standard.store_id.last(), Some(&0xff));
+    }
+
+    #[test]
+    fn entry_id_round_trip() {
+        let hex = "00000000C22B97E3E2D5F84B9407EDDD13F438550100";
+        let entry_id = EntryId::from_hex(hex).unwrap();
+
+        assert_eq!(entry_id.to_hex(), hex);
+        assert_eq!(entry_id.to_base64(), "AAAAAMIrl+Pi1fhLlAft3RP0OFUBAA==");
+        assert_eq!(
+            EntryId::from_base64(&entry_id.to_base64()).unwrap(),
+            entry_id
+        );
+        assert_eq!(EntryId::from_hex(&hex.to_lowercase()).unwrap(), entry_id);
+    }
+
+    #[test]
+    fn reject_invalid_ids() {
+        assert!(matches!(EntryId::from_hex("0"), Err(Error::InvalidId(_))));
+        assert!(matches!(EntryId::from_hex("0G"), Err(Error::InvalidId(_))));
+        assert!(matches!(EwsId::decode(""), Err(Error::InvalidId(_))));
+        assert!(matches!(EwsId::decode("AAMkAD"), Err(Error::InvalidId(_))));
+    }
+}
@@ -0,0 +1,433 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    ArrayOfEmailAddresses, ArrayOfStrings, BaseFolderId, DateTime, Importance, ItemId, Sensitivity,
+};
+
+/// A server-side rule applied to messages arriving in a mailbox's Inbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/rule-ruletype>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Rule {
+    /// The identifier of the rule.
+    ///
+    /// This must be omitted when creating a rule.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ruleid>
+    #[xml_struct(ns_prefix = "t")]
+    pub rule_id: Option<String>,
+
+    /// The name of the rule.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/displayname-string>
+    #[xml_struct(ns_prefix = "t")]
+    pub display_name: String,
+
+    /// The order in which the rule is applied, starting at 1.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/priority>
+    #[xml_struct(ns_prefix = "t")]
+    pub priority: u32,
+
+    /// Whether the rule is applied to incoming messages.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isenabled>
+    #[xml_struct(ns_prefix = "t")]
+    pub is_enabled: bool,
+
+    /// Whether the rule cannot be modified with EWS, e.g. because it was
+    /// created by Outlook with conditions EWS does not support.
+    ///
+    /// This is set by the server and must be omitted in requests.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isnotsupported>
+    #[xml_struct(ns_prefix = "t")]
+    pub is_not_supported: Option<bool>,
+
+    /// Whether the rule is in an error condition.
+    ///
+    /// This is set by the server and must be omitted in requests.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isinerror>
+    #[xml_struct(ns_prefix = "t")]
+    pub is_in_error: Option<bool>,
+
+    /// The conditions which, when met, trigger the rule.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conditions>
+    #[xml_struct(ns_prefix = "t")]
+    pub conditions: Option<RulePredicates>,
+
+    /// The conditions which, when met, prevent the rule from being applied.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/exceptions>
+    #[xml_struct(ns_prefix = "t")]
+    pub exceptions: Option<RulePredicates>,
+
+    /// The actions taken when the rule is applied.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/actions>
+    #[xml_struct(ns_prefix = "t")]
+    pub actions: Option<RuleActions>,
+}
+
+/// A set of conditions on a message, used as either the conditions or the
+/// exceptions of a [`Rule`].
+///
+/// All present conditions must be met for the set to match.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conditions>
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RulePredicates {
+    /// Categories of which the message has at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub categories: Option<ArrayOfStrings>,
+
+    /// Strings of which the message body contains at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_body_strings: Option<ArrayOfStrings>,
+
+    /// Strings of which the message headers contain at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_header_strings: Option<ArrayOfStrings>,
+
+    /// Strings of which the recipient addresses contain at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_recipient_strings: Option<ArrayOfStrings>,
+
+    /// Strings of which the sender address contains at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_sender_strings: Option<ArrayOfStrings>,
+
+    /// Strings of which the subject or body contains at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_subject_or_body_strings: Option<ArrayOfStrings>,
+
+    /// Strings of which the subject contains at least one.
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_subject_strings: Option<ArrayOfStrings>,
+
+    /// The follow-up action with which the message is flagged.
+    #[xml_struct(ns_prefix = "t")]
+    pub flagged_for_action: Option<FlaggedForAction>,
+
+    /// Addresses of which the sender is one.
+    #[xml_struct(ns_prefix = "t")]
+    pub from_addresses: Option<ArrayOfEmailAddresses>,
+
+    /// Connected accounts through which the message was received.
+    #[xml_struct(ns_prefix = "t")]
+    pub from_connected_accounts: Option<ArrayOfStrings>,
+
+    /// Whether the message has attachments.
+    #[xml_struct(ns_prefix = "t")]
+    pub has_attachments: Option<bool>,
+
+    /// The importance of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub importance: Option<Importance>,
+
+    /// Whether the message is an approval request.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_approval_request: Option<bool>,
+
+    /// Whether the message was forwarded automatically.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_automatic_forward: Option<bool>,
+
+    /// Whether the message is an automatic reply.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_automatic_reply: Option<bool>,
+
+    /// Whether the message is encrypted.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_encrypted: Option<bool>,
+
+    /// Whether the message is a meeting request.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_meeting_request: Option<bool>,
+
+    /// Whether the message is a response to a meeting request.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_meeting_response: Option<bool>,
+
+    /// Whether the message is a non-delivery report.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_NDR: Option<bool>,
+
+    /// Whether the message is protected by Information Rights Management.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_permission_controlled: Option<bool>,
+
+    /// Whether the message is a read receipt.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_read_receipt: Option<bool>,
+
+    /// Whether the message is digitally signed.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_signed: Option<bool>,
+
+    /// Whether the message is a voice mail.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_voicemail: Option<bool>,
+
+    /// Item classes of which the message has one, e.g. `IPM.Note`.
+    #[xml_struct(ns_prefix = "t")]
+    pub item_classes: Option<ArrayOfStrings>,
+
+    /// Message classifications of which the message has one.
+    #[xml_struct(ns_prefix = "t")]
+    pub message_classifications: Option<ArrayOfStrings>,
+
+    /// Whether the mailbox owner is not a recipient of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub not_sent_to_me: Option<bool>,
+
+    /// Whether the mailbox owner is a Cc recipient of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_cc_me: Option<bool>,
+
+    /// Whether the mailbox owner is the only recipient of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_only_to_me: Option<bool>,
+
+    /// Addresses of which at least one is a recipient of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_to_addresses: Option<ArrayOfEmailAddresses>,
+
+    /// Whether the mailbox owner is a To recipient of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_to_me: Option<bool>,
+
+    /// Whether the mailbox owner is a To or Cc recipient of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_to_or_cc_me: Option<bool>,
+
+    /// The sensitivity of the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub sensitivity: Option<Sensitivity>,
+
+    /// The range of dates within which the message was received.
+    #[xml_struct(ns_prefix = "t")]
+    pub within_date_range: Option<RulePredicateDateRange>,
+
+    /// The range of sizes, in kilobytes, within which the message falls.
+    #[xml_struct(ns_prefix = "t")]
+    pub within_size_range: Option<RulePredicateSizeRange>,
+}
+
+/// The actions taken when a [`Rule`] is applied to a message.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/actions>
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleActions {
+    /// Categories to assign to the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub assign_categories: Option<ArrayOfStrings>,
+
+    /// The folder to copy the message to.
+    #[xml_struct(ns_prefix = "t")]
+    pub copy_to_folder: Option<TargetFolderId>,
+
+    /// Whether to move the message to the Deleted Items folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub delete: Option<bool>,
+
+    /// Addresses to forward the message to as an attachment.
+    #[xml_struct(ns_prefix = "t")]
+    pub forward_as_attachment_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    /// Addresses to forward the message to.
+    #[xml_struct(ns_prefix = "t")]
+    pub forward_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    /// The importance to set on the message.
+    #[xml_struct(ns_prefix = "t")]
+    pub mark_importance: Option<Importance>,
+
+    /// Whether to mark the message as read.
+    #[xml_struct(ns_prefix = "t")]
+    pub mark_as_read: Option<bool>,
+
+    /// The folder to move the message to.
+    #[xml_struct(ns_prefix = "t")]
+    pub move_to_folder: Option<TargetFolderId>,
+
+    /// Whether to delete the message permanently.
+    #[xml_struct(ns_prefix = "t")]
+    pub permanent_delete: Option<bool>,
+
+    /// Addresses to redirect the message to.
+    #[xml_struct(ns_prefix = "t")]
+    pub redirect_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    /// Addresses to send an SMS alert to.
+    #[xml_struct(ns_prefix = "t")]
+    pub send_SMS_alert_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    /// The template message to reply to the message with.
+    #[xml_struct(ns_prefix = "t")]
+    pub server_reply_with_message: Option<ItemId>,
+
+    /// Whether to skip any subsequent rules.
+    #[xml_struct(ns_prefix = "t")]
+    pub stop_processing_rules: Option<bool>,
+}
+
+/// The folder targeted by a rule action.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/movetofolder>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct TargetFolderId {
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value")]
+    pub folder_id: BaseFolderId,
+}
+
+/// A follow-up action with which a message may be flagged.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/flaggedforaction>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum FlaggedForAction {
+    Any,
+    Call,
+    DoNotForward,
+    FollowUp,
+    #[allow(clippy::upper_case_acronyms)]
+    FYI,
+    Forward,
+    NoResponseNecessary,
+    Read,
+    Reply,
+    ReplyToAll,
+    Review,
+}
+
+/// A range of dates within which a message was received.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/withindaterange>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RulePredicateDateRange {
+    /// The start of the range, exclusive.
+    #[xml_struct(ns_prefix = "t")]
+    pub start_date_time: Option<DateTime>,
+
+    /// The end of the range, exclusive.
+    #[xml_struct(ns_prefix = "t")]
+    pub end_date_time: Option<DateTime>,
+}
+
+/// A range of message sizes, in kilobytes.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/withinsizerange>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RulePredicateSizeRange {
+    /// The minimum size, inclusive.
+    #[xml_struct(ns_prefix = "t")]
+    pub minimum_size: Option<u32>,
+
+    /// The maximum size, inclusive.
+    #[xml_struct(ns_prefix = "t")]
+    pub maximum_size: Option<u32>,
+}
+
+/// The errors of the rule operations in a failed `UpdateInboxRules` request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ruleoperationerrors>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct RuleOperationErrors {
+    #[serde(rename = "RuleOperationError", default)]
+    pub inner: Vec<RuleOperationError>,
+}
+
+/// The errors of a single rule operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ruleoperationerror>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleOperationError {
+    /// The index of the failed operation within the request.
+    pub operation_index: usize,
+
+    /// The reasons the operation failed.
+    pub validation_errors: RuleValidationErrors,
+}
+
+/// A list of rule validation errors.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/validationerrors>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct RuleValidationErrors {
+    #[serde(rename = "Error", default)]
+    pub inner: Vec<RuleValidationError>,
+}
+
+/// An error in the validation of a rule.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/error-ruleerrortype>
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleValidationError {
+    /// The rule field which caused the error, e.g. `MoveToFolder`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fielduri-ruleerrortype>
+    pub field_URI: String,
+
+    /// The nature of the error.
+    pub error_code: RuleValidationErrorCode,
+
+    /// A human-readable description of the error.
+    pub error_message: String,
+
+    /// The value of the field which caused the error.
+    pub field_value: String,
+}
+
+/// The nature of a rule validation error.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/errorcode-ruleerrortype>
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum RuleValidationErrorCode {
+    ADOperationFailure,
+    ConnectedAccountNotFound,
+    CreateWithRuleId,
+    EmptyValueFound,
+    DuplicatedPriority,
+    DuplicatedOperationOnTheSameRule,
+    FolderDoesNotExist,
+    InvalidAddress,
+    InvalidDateRange,
+    InvalidFolderId,
+    InvalidSizeRange,
+    InvalidValue,
+    MessageClassificationNotFound,
+    MissingAction,
+    MissingParameter,
+    MissingRangeValue,
+    NotSettable,
+    RecipientDoesNotExist,
+    RuleNotFound,
+    SizeLessThanZero,
+    StringValueTooBig,
+    UnsupportedAddress,
+    UnexpectedError,
+    UnsupportedRule,
+
+    /// An error code not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
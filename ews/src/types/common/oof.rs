@@ -0,0 +1,108 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::DateTime;
+
+/// The out-of-office (OOF) settings of a mailbox, i.e. whether and how it
+/// automatically replies to incoming messages.
+///
+/// This is represented by a `UserOofSettings` element in requests and an
+/// `OofSettings` element in responses.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/useroofsettings>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserOofSettings {
+    /// Whether automatic replies are sent.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/oofstate>
+    #[xml_struct(ns_prefix = "t")]
+    pub oof_state: OofState,
+
+    /// The senders outside the organization who receive the external reply.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/externalaudience>
+    #[xml_struct(ns_prefix = "t")]
+    pub external_audience: ExternalAudience,
+
+    /// The period during which automatic replies are sent, if `oof_state` is
+    /// [`OofState::Scheduled`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/duration>
+    #[xml_struct(ns_prefix = "t")]
+    pub duration: Option<OofDuration>,
+
+    /// The reply sent to senders inside the organization.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/internalreply>
+    #[xml_struct(ns_prefix = "t")]
+    pub internal_reply: Option<ReplyBody>,
+
+    /// The reply sent to senders outside the organization.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/externalreply>
+    #[xml_struct(ns_prefix = "t")]
+    pub external_reply: Option<ReplyBody>,
+}
+
+/// Whether automatic replies are sent.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/oofstate>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum OofState {
+    /// Automatic replies are sent until disabled.
+    Enabled,
+
+    /// No automatic replies are sent.
+    Disabled,
+
+    /// Automatic replies are sent during the configured duration.
+    Scheduled,
+}
+
+/// A set of senders outside the organization.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/externalaudience>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ExternalAudience {
+    /// No senders outside the organization.
+    None,
+
+    /// Senders outside the organization who are in the mailbox's contacts.
+    Known,
+
+    /// All senders outside the organization.
+    All,
+}
+
+/// The period during which automatic replies are sent.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/duration>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct OofDuration {
+    #[xml_struct(ns_prefix = "t")]
+    pub start_time: DateTime,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub end_time: DateTime,
+}
+
+/// The contents of an automatic reply.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/internalreply>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplyBody {
+    /// The text of the reply, which may be HTML.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/message-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    pub message: Option<String>,
+}
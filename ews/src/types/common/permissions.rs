@@ -0,0 +1,398 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    server_version::{
+        check_fields, strip_fields, ExchangeServerVersion, UnsupportedElement, VersionRequirements,
+        VersionedField,
+    },
+    UserId,
+};
+
+/// The permissions granted to users on a folder other than a calendar folder.
+///
+/// When updating a folder, the permission set replaces the existing one in its
+/// entirety.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-permissionsettype>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PermissionSet {
+    /// The permissions of each user.
+    #[xml_struct(ns_prefix = "t")]
+    #[serde(default)]
+    pub permissions: Permissions,
+
+    /// Permission entries whose user could not be resolved, e.g. because the
+    /// account was deleted.
+    ///
+    /// This is set by the server and must be omitted in requests.
+    #[xml_struct(ns_prefix = "t")]
+    pub unknown_entries: Option<UnknownEntries>,
+}
+
+/// A list of folder permissions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissions>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Permissions {
+    #[serde(rename = "Permission", default)]
+    pub inner: Vec<Permission>,
+}
+
+impl XmlSerialize for Permissions {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for permission in &self.inner {
+            permission.serialize_as_element(writer, "t:Permission")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The permissions granted to a user on a folder.
+///
+/// When `permission_level` is anything other than [`PermissionLevel::Custom`],
+/// the individual permissions are implied by the level and may be omitted.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permission>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Permission {
+    /// The user granted the permissions.
+    #[xml_struct(ns_prefix = "t")]
+    pub user_id: UserId,
+
+    /// Whether the user can create items in the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_items: Option<bool>,
+
+    /// Whether the user can create subfolders of the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_sub_folders: Option<bool>,
+
+    /// Whether the user owns the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_owner: Option<bool>,
+
+    /// Whether the folder is visible to the user.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_visible: Option<bool>,
+
+    /// Whether the user is a contact for the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_contact: Option<bool>,
+
+    /// The items the user can edit.
+    #[xml_struct(ns_prefix = "t")]
+    pub edit_items: Option<PermissionActionType>,
+
+    /// The items the user can delete.
+    #[xml_struct(ns_prefix = "t")]
+    pub delete_items: Option<PermissionActionType>,
+
+    /// Whether the user can read items.
+    #[xml_struct(ns_prefix = "t")]
+    pub read_items: Option<PermissionReadAccess>,
+
+    /// The predefined level of the permissions.
+    #[xml_struct(ns_prefix = "t")]
+    pub permission_level: PermissionLevel,
+}
+
+/// The permissions granted to users on a calendar folder.
+///
+/// When updating a folder, the permission set replaces the existing one in its
+/// entirety.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-calendarpermissionsettype>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CalendarPermissionSet {
+    /// The permissions of each user.
+    #[xml_struct(ns_prefix = "t")]
+    #[serde(default)]
+    pub calendar_permissions: CalendarPermissions,
+
+    /// Permission entries whose user could not be resolved, e.g. because the
+    /// account was deleted.
+    ///
+    /// This is set by the server and must be omitted in requests.
+    #[xml_struct(ns_prefix = "t")]
+    pub unknown_entries: Option<UnknownEntries>,
+}
+
+/// A list of calendar folder permissions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarpermissions>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct CalendarPermissions {
+    #[serde(rename = "CalendarPermission", default)]
+    pub inner: Vec<CalendarPermission>,
+}
+
+impl XmlSerialize for CalendarPermissions {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for permission in &self.inner {
+            permission.serialize_as_element(writer, "t:CalendarPermission")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The permissions granted to a user on a calendar folder.
+///
+/// When `calendar_permission_level` is anything other than
+/// [`CalendarPermissionLevel::Custom`], the individual permissions are implied
+/// by the level and may be omitted.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarpermission>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CalendarPermission {
+    /// The user granted the permissions.
+    #[xml_struct(ns_prefix = "t")]
+    pub user_id: UserId,
+
+    /// Whether the user can create items in the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_items: Option<bool>,
+
+    /// Whether the user can create subfolders of the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_sub_folders: Option<bool>,
+
+    /// Whether the user owns the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_owner: Option<bool>,
+
+    /// Whether the folder is visible to the user.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_visible: Option<bool>,
+
+    /// Whether the user is a contact for the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_contact: Option<bool>,
+
+    /// The items the user can edit.
+    #[xml_struct(ns_prefix = "t")]
+    pub edit_items: Option<PermissionActionType>,
+
+    /// The items the user can delete.
+    #[xml_struct(ns_prefix = "t")]
+    pub delete_items: Option<PermissionActionType>,
+
+    /// The level of detail of calendar items the user can read.
+    #[xml_struct(ns_prefix = "t")]
+    pub read_items: Option<CalendarPermissionReadAccess>,
+
+    /// The predefined level of the permissions.
+    #[xml_struct(ns_prefix = "t")]
+    pub calendar_permission_level: CalendarPermissionLevel,
+}
+
+/// The items of a folder a user can edit or delete.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/edititems>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PermissionActionType {
+    /// No items.
+    None,
+
+    /// Items created by the user.
+    Owned,
+
+    /// All items.
+    All,
+}
+
+/// Whether a user can read the items of a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/readitems>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PermissionReadAccess {
+    None,
+    FullDetails,
+}
+
+/// The level of detail of calendar items a user can read.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/readitems>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum CalendarPermissionReadAccess {
+    /// No access.
+    None,
+
+    /// The free/busy time of items.
+    TimeOnly,
+
+    /// The free/busy time, subject and location of items.
+    TimeAndSubjectAndLocation,
+
+    /// All properties of items.
+    FullDetails,
+}
+
+/// A predefined set of folder permissions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionlevel>
+#[derive(Clone, Copy, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PermissionLevel {
+    #[default]
+    None,
+    Owner,
+    PublishingEditor,
+    Editor,
+    PublishingAuthor,
+    Author,
+    NoneditingAuthor,
+    Reviewer,
+    Contributor,
+
+    /// Permissions which do not match any predefined level.
+    Custom,
+}
+
+/// A predefined set of calendar folder permissions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarpermissionlevel>
+#[derive(Clone, Copy, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum CalendarPermissionLevel {
+    #[default]
+    None,
+    Owner,
+    PublishingEditor,
+    Editor,
+    PublishingAuthor,
+    Author,
+    NoneditingAuthor,
+    Reviewer,
+    Contributor,
+
+    /// The user can view the free/busy time of items.
+    FreeBusyTimeOnly,
+
+    /// The user can view the free/busy time, subject and location of items.
+    FreeBusyTimeAndSubjectAndLocation,
+
+    /// Permissions which do not match any predefined level.
+    Custom,
+}
+
+/// A list of permission entries whose user could not be resolved.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/unknownentries>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct UnknownEntries {
+    #[serde(rename = "UnknownEntry", default)]
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for UnknownEntries {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for entry in &self.inner {
+            entry.serialize_as_element(writer, "t:UnknownEntry")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The actions the authenticated user may perform on an item or folder.
+///
+/// This is set by the server and must be omitted in requests.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/effectiverights>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EffectiveRights {
+    /// Whether the user can create associated (hidden) items in the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub create_associated: bool,
+
+    /// Whether the user can create items in the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub create_contents: bool,
+
+    /// Whether the user can create subfolders of the folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub create_hierarchy: bool,
+
+    /// Whether the user can delete the item or folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub delete: bool,
+
+    /// Whether the user can modify the item or folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub modify: bool,
+
+    /// Whether the user can read the item or folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub read: bool,
+
+    /// Whether the user can view private items.
+    ///
+    /// This element was introduced in Exchange 2010 SP1.
+    #[xml_struct(ns_prefix = "t")]
+    pub view_private_items: Option<bool>,
+}
+
+/// The fields of [`EffectiveRights`] which require a minimum server version.
+///
+/// As effective rights are set by the server, these can always be removed.
+const EFFECTIVE_RIGHTS_VERSIONED_FIELDS: &[VersionedField<EffectiveRights>] =
+    &[VersionedField::removable(
+        "ViewPrivateItems",
+        ExchangeServerVersion::Exchange2010_SP1,
+        |rights| rights.view_private_items.is_some(),
+        |rights| rights.view_private_items = None,
+    )];
+
+impl VersionRequirements for EffectiveRights {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        check_fields(
+            EFFECTIVE_RIGHTS_VERSIONED_FIELDS,
+            self,
+            version,
+            path,
+            unsupported,
+        );
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        strip_fields(EFFECTIVE_RIGHTS_VERSIONED_FIELDS, self, version);
+    }
+}
@@ -0,0 +1,130 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+
+use crate::{ArrayOfEmailAddresses, DateTime, ItemId, Mailbox};
+
+/// A person, aggregating the information about them from contacts and the
+/// directory.
+///
+/// Which fields are included depends on the operation and the requested shape.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/persona>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Persona {
+    /// The identifier of the persona.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/personaid>
+    pub persona_id: Option<ItemId>,
+
+    /// The kind of persona, e.g. `Person` or `DistributionList`.
+    pub persona_type: Option<String>,
+
+    pub creation_time: Option<DateTime>,
+
+    pub display_name: Option<String>,
+
+    pub display_name_first_last: Option<String>,
+
+    pub display_name_last_first: Option<String>,
+
+    pub file_as: Option<String>,
+
+    pub given_name: Option<String>,
+
+    pub surname: Option<String>,
+
+    pub company_name: Option<String>,
+
+    /// The primary email address of the persona.
+    pub email_address: Option<Mailbox>,
+
+    /// All email addresses of the persona.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/emailaddresses-arrayofemailaddressestype>
+    pub email_addresses: Option<ArrayOfEmailAddresses>,
+
+    /// The primary phone number of the persona.
+    pub phone_number: Option<PersonaPhoneNumber>,
+
+    pub im_address: Option<String>,
+
+    pub title: Option<String>,
+
+    pub department: Option<String>,
+
+    /// How relevant the persona is to the search which returned it, the
+    /// highest value being the most relevant.
+    pub relevance_score: Option<i64>,
+
+    /// The display names of the persona, with their sources.
+    pub display_names: Option<AttributedValues<String>>,
+
+    /// The first email addresses of the contacts aggregated in the persona,
+    /// with their sources.
+    pub emails1: Option<AttributedValues<Mailbox>>,
+
+    /// The second email addresses of the contacts aggregated in the persona,
+    /// with their sources.
+    pub emails2: Option<AttributedValues<Mailbox>>,
+
+    /// The third email addresses of the contacts aggregated in the persona,
+    /// with their sources.
+    pub emails3: Option<AttributedValues<Mailbox>>,
+
+    /// The business phone numbers of the persona, with their sources.
+    pub business_phone_numbers: Option<AttributedValues<PersonaPhoneNumber>>,
+
+    /// The mobile phone numbers of the persona, with their sources.
+    pub mobile_phones: Option<AttributedValues<PersonaPhoneNumber>>,
+
+    /// The home phone numbers of the persona, with their sources.
+    pub home_phones: Option<AttributedValues<PersonaPhoneNumber>>,
+}
+
+/// A phone number of a persona.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/phonenumber-persona>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PersonaPhoneNumber {
+    pub number: String,
+
+    /// The kind of phone number, e.g. `Business` or `Mobile`.
+    #[serde(rename = "Type")]
+    pub phone_type: String,
+}
+
+/// A list of values of a persona property, each with the sources from which
+/// it was aggregated.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct AttributedValues<T> {
+    #[serde(rename = "$value", default = "Vec::new")]
+    pub inner: Vec<AttributedValue<T>>,
+}
+
+/// A value of a persona property with the sources from which it was
+/// aggregated.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/stringattributedvalue>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttributedValue<T> {
+    pub value: T,
+
+    /// The identifiers of the sources of the value, as listed in the
+    /// `Attributions` of the persona.
+    pub attributions: Attributions,
+}
+
+/// A list of identifiers of sources of a persona property.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attributions>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Attributions {
+    #[serde(rename = "Attribution", default)]
+    pub inner: Vec<String>,
+}
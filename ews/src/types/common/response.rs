@@ -88,6 +88,74 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for ResponseClass<T> {
     }
 }
 
+/// Deserializes a response which reports its outcome in a single
+/// `ResponseMessage` element, followed by the contents of the response, e.g.
+/// `GetUserOofSettingsResponse`.
+///
+/// The contents are only deserialized if the response was not an error.
+pub(crate) fn deserialize_response_message<'de, D, T>(
+    deserializer: D,
+) -> Result<ResponseClass<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    /// The contents of a `ResponseMessage` element which did not report an
+    /// error, which carry no information beyond the response code.
+    #[derive(Deserialize)]
+    struct Status {}
+
+    struct ResponseMessageVisitor<T> {
+        marker: PhantomData<fn() -> ResponseClass<T>>,
+    }
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for ResponseMessageVisitor<T> {
+        type Value = ResponseClass<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a map with a ResponseMessage element")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            while let Some(key) = map.next_key::<String>()? {
+                if key != "ResponseMessage" {
+                    // Skip any attributes of the response element.
+                    map.next_value::<de::IgnoredAny>()?;
+                    continue;
+                }
+
+                let status: ResponseClass<Status> = map.next_value()?;
+                return match status {
+                    ResponseClass::Error(err) => {
+                        while map
+                            .next_entry::<de::IgnoredAny, de::IgnoredAny>()?
+                            .is_some()
+                        {}
+                        Ok(ResponseClass::Error(err))
+                    }
+                    ResponseClass::Success(_) | ResponseClass::Warning(_) => {
+                        let de = de::value::MapAccessDeserializer::new(map);
+                        let contents = T::deserialize(de)?;
+                        Ok(match status {
+                            ResponseClass::Warning(_) => ResponseClass::Warning(contents),
+                            _ => ResponseClass::Success(contents),
+                        })
+                    }
+                };
+            }
+
+            Err(A::Error::missing_field("ResponseMessage"))
+        }
+    }
+
+    deserializer.deserialize_map(ResponseMessageVisitor {
+        marker: PhantomData,
+    })
+}
+
 /// Information available when an operation responded with an Error.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
@@ -0,0 +1,486 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::fmt;
+
+use serde::{
+    de::{
+        self, value::EnumAccessDeserializer, DeserializeSeed, EnumAccess, IntoDeserializer,
+        VariantAccess, Visitor,
+    },
+    Deserialize, Deserializer,
+};
+use xml_struct::XmlSerialize;
+
+use crate::PathToElement;
+
+/// A condition which items must meet, e.g. to be included in a search folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/restriction>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct Restriction {
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value")]
+    pub expression: SearchExpression,
+}
+
+/// An expression in a [`Restriction`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/searchexpression>
+// Elements whose content is a property path followed by another element can't
+// be deserialized directly, as quick-xml considers the path a duplicate of the
+// other element, so they are deserialized through `RawSearchExpression`.
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(try_from = "RawSearchExpression")]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum SearchExpression {
+    /// Matches items which match all of the expressions.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/and>
+    And {
+        #[xml_struct(flatten)]
+        expressions: Vec<SearchExpression>,
+    },
+
+    /// Matches items which match any of the expressions.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/or>
+    Or {
+        #[xml_struct(flatten)]
+        expressions: Vec<SearchExpression>,
+    },
+
+    /// Matches items which do not match the expression.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/not>
+    Not {
+        #[xml_struct(flatten)]
+        expression: Box<SearchExpression>,
+    },
+
+    /// Matches items on which the property is set.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/exists>
+    Exists {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+    },
+
+    /// Matches items whose string property contains the constant.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contains>
+    Contains {
+        /// Which part of the property must match the constant.
+        #[xml_struct(attribute)]
+        containment_mode: Option<ContainmentMode>,
+
+        /// How strictly the property must match the constant.
+        #[xml_struct(attribute)]
+        containment_comparison: Option<ContainmentComparison>,
+
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        constant: Constant,
+    },
+
+    /// Matches items whose integer property has none of the bits of the
+    /// bitmask set.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/excludes>
+    Excludes {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        bitmask: Constant,
+    },
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isequalto>
+    #[allow(non_snake_case)]
+    IsEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isnotequalto>
+    #[allow(non_snake_case)]
+    IsNotEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isgreaterthan>
+    #[allow(non_snake_case)]
+    IsGreaterThan {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isgreaterthanorequalto>
+    #[allow(non_snake_case)]
+    IsGreaterThanOrEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/islessthan>
+    #[allow(non_snake_case)]
+    IsLessThan {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/islessthanorequalto>
+    #[allow(non_snake_case)]
+    IsLessThanOrEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+}
+
+/// The representation of a [`SearchExpression`] as deserialized, in which
+/// the children of comparisons are not yet told apart.
+#[derive(Deserialize)]
+enum RawSearchExpression {
+    And {
+        #[serde(rename = "$value", default)]
+        expressions: Vec<SearchExpression>,
+    },
+    Or {
+        #[serde(rename = "$value", default)]
+        expressions: Vec<SearchExpression>,
+    },
+    Not {
+        #[serde(rename = "$value")]
+        expression: Box<SearchExpression>,
+    },
+    Exists {
+        #[serde(rename = "$value")]
+        path: PathToElement,
+    },
+    Contains {
+        #[serde(rename = "@ContainmentMode")]
+        containment_mode: Option<ContainmentMode>,
+
+        #[serde(rename = "@ContainmentComparison")]
+        containment_comparison: Option<ContainmentComparison>,
+
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    Excludes {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    IsEqualTo {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    IsNotEqualTo {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    IsGreaterThan {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    IsGreaterThanOrEqualTo {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    IsLessThan {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+    IsLessThanOrEqualTo {
+        #[serde(rename = "$value")]
+        operands: Vec<Operand>,
+    },
+}
+
+/// A child element of a comparison in a [`RawSearchExpression`].
+enum Operand {
+    Constant(Constant),
+    Bitmask(Constant),
+    FieldURIOrConstant(FieldURIOrConstant),
+    Path(PathToElement),
+}
+
+impl<'de> Deserialize<'de> for Operand {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OperandVisitor;
+
+        impl<'de> Visitor<'de> for OperandVisitor {
+            type Value = Operand;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a property path or a value")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, variant): (String, _) = data.variant()?;
+
+                match name.as_str() {
+                    "Constant" => variant.newtype_variant().map(Operand::Constant),
+                    "Bitmask" => variant.newtype_variant().map(Operand::Bitmask),
+                    "FieldURIOrConstant" => {
+                        variant.newtype_variant().map(Operand::FieldURIOrConstant)
+                    }
+                    _ => deserialize_path(name, variant).map(Operand::Path),
+                }
+            }
+        }
+
+        deserializer.deserialize_enum("Operand", OPERAND_ELEMENTS, OperandVisitor)
+    }
+}
+
+/// The names of the elements which may appear in a comparison.
+const OPERAND_ELEMENTS: &[&str] = &[
+    "Constant",
+    "Bitmask",
+    "FieldURIOrConstant",
+    "FieldURI",
+    "IndexedFieldURI",
+    "ExtendedFieldURI",
+];
+
+/// Deserializes a [`PathToElement`] from an element whose name has already
+/// been read.
+fn deserialize_path<'de, A>(name: String, variant: A) -> Result<PathToElement, A::Error>
+where
+    A: VariantAccess<'de>,
+{
+    struct NamedVariant<A> {
+        name: String,
+        variant: A,
+    }
+
+    impl<'de, A> EnumAccess<'de> for NamedVariant<A>
+    where
+        A: VariantAccess<'de>,
+    {
+        type Error = A::Error;
+        type Variant = A;
+
+        fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            let value = seed.deserialize(self.name.into_deserializer())?;
+
+            Ok((value, self.variant))
+        }
+    }
+
+    PathToElement::deserialize(EnumAccessDeserializer::new(NamedVariant { name, variant }))
+}
+
+/// Splits the children of a comparison into the property path and the
+/// element it is compared to.
+fn split_operands(operands: Vec<Operand>) -> Result<(PathToElement, Operand), String> {
+    let mut operands = operands.into_iter();
+
+    match (operands.next(), operands.next(), operands.next()) {
+        (Some(Operand::Path(path)), Some(operand), None) => Ok((path, operand)),
+        _ => Err("expected a property path followed by a value".to_string()),
+    }
+}
+
+impl TryFrom<RawSearchExpression> for SearchExpression {
+    type Error = String;
+
+    fn try_from(raw: RawSearchExpression) -> Result<Self, Self::Error> {
+        let comparison = |operands| match split_operands(operands)? {
+            (path, Operand::FieldURIOrConstant(value)) => Ok((path, value)),
+            _ => Err("expected a FieldURIOrConstant element".to_string()),
+        };
+
+        let expression = match raw {
+            RawSearchExpression::And { expressions } => Self::And { expressions },
+            RawSearchExpression::Or { expressions } => Self::Or { expressions },
+            RawSearchExpression::Not { expression } => Self::Not { expression },
+            RawSearchExpression::Exists { path } => Self::Exists { path },
+            RawSearchExpression::Contains {
+                containment_mode,
+                containment_comparison,
+                operands,
+            } => match split_operands(operands)? {
+                (path, Operand::Constant(constant)) => Self::Contains {
+                    containment_mode,
+                    containment_comparison,
+                    path,
+                    constant,
+                },
+                _ => return Err("expected a Constant element".to_string()),
+            },
+            RawSearchExpression::Excludes { operands } => match split_operands(operands)? {
+                (path, Operand::Bitmask(bitmask)) => Self::Excludes { path, bitmask },
+                _ => return Err("expected a Bitmask element".to_string()),
+            },
+            RawSearchExpression::IsEqualTo { operands } => {
+                let (path, value) = comparison(operands)?;
+                Self::IsEqualTo {
+                    path,
+                    field_URI_or_constant: value,
+                }
+            }
+            RawSearchExpression::IsNotEqualTo { operands } => {
+                let (path, value) = comparison(operands)?;
+                Self::IsNotEqualTo {
+                    path,
+                    field_URI_or_constant: value,
+                }
+            }
+            RawSearchExpression::IsGreaterThan { operands } => {
+                let (path, value) = comparison(operands)?;
+                Self::IsGreaterThan {
+                    path,
+                    field_URI_or_constant: value,
+                }
+            }
+            RawSearchExpression::IsGreaterThanOrEqualTo { operands } => {
+                let (path, value) = comparison(operands)?;
+                Self::IsGreaterThanOrEqualTo {
+                    path,
+                    field_URI_or_constant: value,
+                }
+            }
+            RawSearchExpression::IsLessThan { operands } => {
+                let (path, value) = comparison(operands)?;
+                Self::IsLessThan {
+                    path,
+                    field_URI_or_constant: value,
+                }
+            }
+            RawSearchExpression::IsLessThanOrEqualTo { operands } => {
+                let (path, value) = comparison(operands)?;
+                Self::IsLessThanOrEqualTo {
+                    path,
+                    field_URI_or_constant: value,
+                }
+            }
+        };
+
+        Ok(expression)
+    }
+}
+
+/// The value a property is compared to: either a constant or another
+/// property of the same item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fielduriorconstant>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct FieldURIOrConstant {
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value")]
+    pub value: ConstantOrPath,
+}
+
+/// The contents of a [`FieldURIOrConstant`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConstantOrPath {
+    /// A constant value, e.g. `false` or `2024-07-01T00:00:00Z`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/constant>
+    Constant(Constant),
+
+    /// Another property of the item.
+    Path(PathToElement),
+}
+
+impl<'de> Deserialize<'de> for ConstantOrPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Operand::deserialize(deserializer)? {
+            Operand::Constant(constant) => Ok(Self::Constant(constant)),
+            Operand::Path(path) => Ok(Self::Path(path)),
+            _ => Err(de::Error::custom("expected a Constant or a property path")),
+        }
+    }
+}
+
+impl XmlSerialize for ConstantOrPath {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        match self {
+            Self::Constant(constant) => constant.serialize_as_element(writer, "t:Constant"),
+            Self::Path(path) => path.serialize_child_nodes(writer),
+        }
+    }
+}
+
+/// A constant value in a search expression.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/constant>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct Constant {
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Value")]
+    pub value: String,
+}
+
+/// Which part of a string property must match the constant of a
+/// [`SearchExpression::Contains`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contains#containmentmode-attribute>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ContainmentMode {
+    FullString,
+    Prefixed,
+    Substring,
+    PrefixOnWords,
+    ExactPhrase,
+}
+
+/// How strictly a string property must match the constant of a
+/// [`SearchExpression::Contains`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contains#containmentcomparison-attribute>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ContainmentComparison {
+    Exact,
+    IgnoreCase,
+    IgnoreNonSpacingCharacters,
+    Loose,
+    IgnoreCaseAndNonSpacingCharacters,
+    LooseAndIgnoreCase,
+    LooseAndIgnoreNonSpace,
+    LooseAndIgnoreCaseAndIgnoreNonSpace,
+}
@@ -0,0 +1,620 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{DateTime, Duration};
+
+mod rules;
+pub use self::rules::{Observance, RuleDay, RuleSet, TimeZoneRules, YearlyRule};
+mod windows_ids;
+pub use self::windows_ids::{iana_to_windows, windows_to_iana};
+
+/// A time zone, defined as a set of periods with a given offset from UTC and
+/// the transitions between them.
+///
+/// This is the time zone model used by Exchange 2010 and later, both in the
+/// `TimeZoneContext` SOAP header and in time zone properties of calendar items.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/timezonedefinition>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeZoneDefinition {
+    /// The identifier of the time zone, e.g. "Pacific Standard Time".
+    ///
+    /// This is a Windows time zone identifier. When used in a request, only
+    /// this field needs to be set for the server to use its own definition of
+    /// the time zone.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Id")]
+    pub id: Option<String>,
+
+    /// The descriptive name of the time zone.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Name")]
+    pub name: Option<String>,
+
+    /// The periods during which a given offset from UTC applies.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/periods>
+    #[xml_struct(ns_prefix = "t")]
+    pub periods: Option<Periods>,
+
+    /// Groups of transitions between periods, typically describing the yearly
+    /// switches between standard and daylight saving time under a given set of
+    /// rules.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/transitionsgroups>
+    #[xml_struct(ns_prefix = "t")]
+    pub transitions_groups: Option<TransitionsGroups>,
+
+    /// The transitions between groups (or periods) over the lifetime of the
+    /// time zone, e.g. when the daylight saving rules of a region change.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/transitions>
+    #[xml_struct(ns_prefix = "t")]
+    pub transitions: Option<Transitions>,
+}
+
+/// A list of [`Period`]s.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/periods>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Periods {
+    #[serde(rename = "$value", default)]
+    pub inner: Vec<Period>,
+}
+
+impl XmlSerialize for Periods {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for period in &self.inner {
+            period.serialize_as_element(writer, "t:Period")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A period of time during which a given offset from UTC applies.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/period>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct Period {
+    /// The offset to add to local time in this period to obtain UTC time.
+    ///
+    /// Note that this is the inverse of the usual notion of a UTC offset, e.g.
+    /// the bias for UTC-08:00 is `PT8H`.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Bias")]
+    pub bias: Duration,
+
+    /// The descriptive name of the period, e.g. "Standard" or "Daylight".
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    /// The identifier used to reference this period in transitions.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Id")]
+    pub id: String,
+}
+
+/// A list of [`TransitionsGroup`]s.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/transitionsgroups>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct TransitionsGroups {
+    #[serde(rename = "$value", default)]
+    pub inner: Vec<TransitionsGroup>,
+}
+
+impl XmlSerialize for TransitionsGroups {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for group in &self.inner {
+            group.serialize_as_element(writer, "t:TransitionsGroup")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A group of transitions which apply together, e.g. the switches to and from
+/// daylight saving time for a given set of rules.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/transitionsgroup>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct TransitionsGroup {
+    /// The identifier used to reference this group in transitions.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Id")]
+    pub id: String,
+
+    /// The transitions in this group.
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value", default)]
+    pub transitions: Vec<Transition>,
+}
+
+/// A list of [`Transition`]s between groups or periods.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/transitions>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct Transitions {
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value", default)]
+    pub inner: Vec<Transition>,
+}
+
+/// A transition to a period or a group of transitions.
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum Transition {
+    /// An unconditional transition, e.g. the initial period or group of a
+    /// time zone.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/transition>
+    #[serde(rename_all = "PascalCase")]
+    Transition {
+        #[xml_struct(ns_prefix = "t")]
+        to: TransitionTarget,
+    },
+
+    /// A transition occurring at a fixed point in time.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/absolutedatetransition>
+    #[serde(rename_all = "PascalCase")]
+    AbsoluteDateTransition {
+        #[xml_struct(ns_prefix = "t")]
+        to: TransitionTarget,
+
+        /// The point in time at which the transition occurs.
+        #[xml_struct(ns_prefix = "t")]
+        date_time: DateTime,
+    },
+
+    /// A yearly transition occurring on a given weekday of a month, e.g. the
+    /// last Sunday of October.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/recurringdaytransition>
+    #[serde(rename_all = "PascalCase")]
+    RecurringDayTransition {
+        #[xml_struct(ns_prefix = "t")]
+        to: TransitionTarget,
+
+        /// The local time of day, relative to midnight, at which the
+        /// transition occurs.
+        #[xml_struct(ns_prefix = "t")]
+        time_offset: Duration,
+
+        /// The month of the transition, between 1 and 12.
+        #[xml_struct(ns_prefix = "t")]
+        month: u8,
+
+        /// The weekday of the transition.
+        #[xml_struct(ns_prefix = "t")]
+        day_of_week: DayOfWeek,
+
+        /// The occurrence of the weekday within the month, between 1 and 4,
+        /// or -1 for the last occurrence.
+        #[xml_struct(ns_prefix = "t")]
+        occurrence: i8,
+    },
+
+    /// A yearly transition occurring on a fixed day of a month.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/recurringdatetransition>
+    #[serde(rename_all = "PascalCase")]
+    RecurringDateTransition {
+        #[xml_struct(ns_prefix = "t")]
+        to: TransitionTarget,
+
+        /// The local time of day, relative to midnight, at which the
+        /// transition occurs.
+        #[xml_struct(ns_prefix = "t")]
+        time_offset: Duration,
+
+        /// The month of the transition, between 1 and 12.
+        #[xml_struct(ns_prefix = "t")]
+        month: u8,
+
+        /// The day of the month of the transition.
+        #[xml_struct(ns_prefix = "t")]
+        day: u8,
+    },
+}
+
+impl Transition {
+    /// The period or group which applies after this transition.
+    pub fn to(&self) -> &TransitionTarget {
+        match self {
+            Transition::Transition { to }
+            | Transition::AbsoluteDateTransition { to, .. }
+            | Transition::RecurringDayTransition { to, .. }
+            | Transition::RecurringDateTransition { to, .. } => to,
+        }
+    }
+}
+
+/// The period or group which applies after a transition.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/to>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct TransitionTarget {
+    /// Whether the target is a period or a group.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Kind")]
+    pub kind: TransitionTargetKind,
+
+    /// The identifier of the target period or group.
+    #[xml_struct(flatten)]
+    #[serde(rename = "$text")]
+    pub id: String,
+}
+
+/// The kind of entity targeted by a transition.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/to>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum TransitionTargetKind {
+    Period,
+    Group,
+}
+
+/// A day of the week, or a class of days.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dayofweek>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DayOfWeek {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Day,
+    Weekday,
+    WeekendDay,
+}
+
+/// A time zone in the format used by Exchange 2007, consisting of a base
+/// offset and optional yearly changes to and from daylight saving time.
+///
+/// This has been superseded by [`TimeZoneDefinition`] in Exchange 2010 and
+/// later, but is still returned for calendar items by older servers.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/meetingtimezone>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MeetingTimeZone {
+    /// The name of the time zone, e.g. "Pacific Standard Time".
+    #[xml_struct(attribute)]
+    #[serde(rename = "@TimeZoneName")]
+    pub time_zone_name: Option<String>,
+
+    /// The offset to add to local standard time to obtain UTC time.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/baseoffset>
+    #[xml_struct(ns_prefix = "t")]
+    pub base_offset: Option<Duration>,
+
+    /// The yearly change to standard time.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/standard>
+    #[xml_struct(ns_prefix = "t")]
+    pub standard: Option<TimeChange>,
+
+    /// The yearly change to daylight saving time.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/daylight>
+    #[xml_struct(ns_prefix = "t")]
+    pub daylight: Option<TimeChange>,
+}
+
+/// A yearly change of offset within a [`MeetingTimeZone`].
+///
+/// Exactly one of `relative_yearly_recurrence` and `absolute_date` is expected
+/// to be set.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/standard>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TimeChange {
+    /// The name of the period starting with this change, e.g. "Daylight".
+    #[xml_struct(attribute)]
+    #[serde(rename = "@TimeZoneName")]
+    pub time_zone_name: Option<String>,
+
+    /// The offset to add to the base offset of the time zone during the period
+    /// starting with this change.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/offset>
+    #[xml_struct(ns_prefix = "t")]
+    pub offset: Duration,
+
+    /// A change occurring on a given weekday of a month.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/relativeyearlyrecurrence>
+    #[xml_struct(ns_prefix = "t")]
+    pub relative_yearly_recurrence: Option<RelativeYearlyRecurrence>,
+
+    /// A change occurring on a fixed date, as an `xs:date`, e.g. `2024-03-31`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/absolutedate>
+    #[xml_struct(ns_prefix = "t")]
+    pub absolute_date: Option<String>,
+
+    /// The local time of day at which the change occurs, as an `xs:time`,
+    /// e.g. `02:00:00`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/time>
+    #[xml_struct(ns_prefix = "t")]
+    pub time: String,
+}
+
+/// A yearly recurrence on a given weekday of a month, e.g. the last Sunday of
+/// October.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/relativeyearlyrecurrence>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RelativeYearlyRecurrence {
+    /// The weekday of the recurrence.
+    #[xml_struct(ns_prefix = "t")]
+    pub days_of_week: DayOfWeek,
+
+    /// The occurrence of the weekday within the month.
+    #[xml_struct(ns_prefix = "t")]
+    pub day_of_week_index: DayOfWeekIndex,
+
+    /// The month of the recurrence.
+    #[xml_struct(ns_prefix = "t")]
+    pub month: MonthName,
+}
+
+/// The occurrence of a weekday within a month.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dayofweekindex>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DayOfWeekIndex {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Last,
+}
+
+/// A month of the year.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/month>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum MonthName {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    use super::*;
+    use crate::test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml};
+
+    /// The definition of the Pacific time zone, as returned by Exchange in
+    /// response to a `GetServerTimeZones` request.
+    fn pacific_time_zone() -> TimeZoneDefinition {
+        let to = |kind, id: &str| TransitionTarget {
+            kind,
+            id: id.to_string(),
+        };
+
+        TimeZoneDefinition {
+            id: Some("Pacific Standard Time".to_string()),
+            name: Some("(UTC-08:00) Pacific Time (US & Canada)".to_string()),
+            periods: Some(Periods {
+                inner: vec![
+                    Period {
+                        bias: Duration(time::Duration::hours(8)),
+                        name: "Standard".to_string(),
+                        id: "Std".to_string(),
+                    },
+                    Period {
+                        bias: Duration(time::Duration::hours(7)),
+                        name: "Daylight".to_string(),
+                        id: "Dlt/2007".to_string(),
+                    },
+                ],
+            }),
+            transitions_groups: Some(TransitionsGroups {
+                inner: vec![TransitionsGroup {
+                    id: "0".to_string(),
+                    transitions: vec![
+                        Transition::RecurringDayTransition {
+                            to: to(TransitionTargetKind::Period, "Dlt/2007"),
+                            time_offset: Duration(time::Duration::hours(2)),
+                            month: 3,
+                            day_of_week: DayOfWeek::Sunday,
+                            occurrence: 2,
+                        },
+                        Transition::RecurringDayTransition {
+                            to: to(TransitionTargetKind::Period, "Std"),
+                            time_offset: Duration(time::Duration::hours(2)),
+                            month: 11,
+                            day_of_week: DayOfWeek::Sunday,
+                            occurrence: 1,
+                        },
+                    ],
+                }],
+            }),
+            transitions: Some(Transitions {
+                inner: vec![
+                    Transition::Transition {
+                        to: to(TransitionTargetKind::Group, "0"),
+                    },
+                    Transition::AbsoluteDateTransition {
+                        to: to(TransitionTargetKind::Group, "0"),
+                        date_time: DateTime(
+                            OffsetDateTime::parse("2007-01-01T00:00:00Z", &Iso8601::DEFAULT)
+                                .unwrap(),
+                        ),
+                    },
+                ],
+            }),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_time_zone_definition() {
+        let content = r#"
+            <t:TimeZoneDefinition Name="(UTC-08:00) Pacific Time (US &amp; Canada)" Id="Pacific Standard Time">
+              <t:Periods>
+                <t:Period Bias="PT8H" Name="Standard" Id="Std" />
+                <t:Period Bias="PT7H" Name="Daylight" Id="Dlt/2007" />
+              </t:Periods>
+              <t:TransitionsGroups>
+                <t:TransitionsGroup Id="0">
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Dlt/2007</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>3</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>2</t:Occurrence>
+                  </t:RecurringDayTransition>
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Std</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>11</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>1</t:Occurrence>
+                  </t:RecurringDayTransition>
+                </t:TransitionsGroup>
+              </t:TransitionsGroups>
+              <t:Transitions>
+                <t:Transition>
+                  <t:To Kind="Group">0</t:To>
+                </t:Transition>
+                <t:AbsoluteDateTransition>
+                  <t:To Kind="Group">0</t:To>
+                  <t:DateTime>2007-01-01T00:00:00</t:DateTime>
+                </t:AbsoluteDateTransition>
+              </t:Transitions>
+            </t:TimeZoneDefinition>"#;
+
+        assert_deserialized_content(content, pacific_time_zone());
+    }
+
+    #[test]
+    fn test_serialize_time_zone_definition() {
+        let expected = minify_xml(
+            r#"
+            <t:TimeZoneDefinition Id="Pacific Standard Time" Name="(UTC-08:00) Pacific Time (US &amp; Canada)">
+              <t:Periods>
+                <t:Period Bias="PT8H" Name="Standard" Id="Std"/>
+                <t:Period Bias="PT7H" Name="Daylight" Id="Dlt/2007"/>
+              </t:Periods>
+              <t:TransitionsGroups>
+                <t:TransitionsGroup Id="0">
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Dlt/2007</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>3</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>2</t:Occurrence>
+                  </t:RecurringDayTransition>
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Std</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>11</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>1</t:Occurrence>
+                  </t:RecurringDayTransition>
+                </t:TransitionsGroup>
+              </t:TransitionsGroups>
+              <t:Transitions>
+                <t:Transition>
+                  <t:To Kind="Group">0</t:To>
+                </t:Transition>
+                <t:AbsoluteDateTransition>
+                  <t:To Kind="Group">0</t:To>
+                  <t:DateTime>2007-01-01T00:00:00.000000000Z</t:DateTime>
+                </t:AbsoluteDateTransition>
+              </t:Transitions>
+            </t:TimeZoneDefinition>"#,
+        );
+
+        assert_serialized_content(&pacific_time_zone(), "t:TimeZoneDefinition", &expected);
+    }
+
+    #[test]
+    fn test_duration_round_trip() {
+        let cases = [
+            ("PT8H", time::Duration::hours(8)),
+            ("-PT1H", time::Duration::hours(-1)),
+            ("PT5H30M", time::Duration::minutes(330)),
+            ("P1DT2H", time::Duration::hours(26)),
+            ("PT0S", time::Duration::ZERO),
+            ("PT1.5S", time::Duration::milliseconds(1500)),
+        ];
+
+        for (text, duration) in cases {
+            let parsed: Duration = text.parse().unwrap();
+            assert_eq!(parsed, Duration(duration), "parsing {text}");
+            assert_eq!(parsed.to_string(), text, "formatting {text}");
+        }
+
+        assert_eq!(
+            "-P0DT1H".parse::<Duration>().unwrap(),
+            Duration(time::Duration::hours(-1))
+        );
+        assert!("P1Y".parse::<Duration>().is_err());
+        assert!("PT".parse::<Duration>().is_err());
+        assert!("8H".parse::<Duration>().is_err());
+    }
+
+    #[test]
+    fn test_duration_rejects_trailing_time_designator() {
+        assert!("P1DT".parse::<Duration>().is_err());
+        assert!("-P1DT".parse::<Duration>().is_err());
+    }
+
+    #[test]
+    fn test_duration_rejects_overflow() {
+        assert!("PT9999999999999999H".parse::<Duration>().is_err());
+        assert!("P9999999999999999W".parse::<Duration>().is_err());
+        assert!("PT99999999999999999999S".parse::<Duration>().is_err());
+
+        // Components which are individually in range but overflow when summed.
+        let max_days = i64::MAX / (24 * 60 * 60);
+        assert!(format!("P{max_days}DT{max_days}H")
+            .parse::<Duration>()
+            .is_err());
+    }
+}
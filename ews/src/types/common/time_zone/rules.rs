@@ -0,0 +1,1138 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+use crate::{Duration, Error};
+
+use super::{
+    DayOfWeek, DayOfWeekIndex, MeetingTimeZone, MonthName, TimeChange, TimeZoneDefinition,
+    Transition, TransitionTargetKind,
+};
+
+/// The offset rules of a time zone, which can be used to convert between UTC
+/// and local time without access to a time zone database.
+///
+/// Rules can be obtained from either a [`TimeZoneDefinition`] or a legacy
+/// [`MeetingTimeZone`] using [`TryFrom`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimeZoneRules {
+    // Invariant: there is always at least one rule set, and rule sets are
+    // sorted by start.
+    rule_sets: Vec<RuleSet>,
+}
+
+/// A set of rules which applies to a time zone from a given point in time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleSet {
+    /// The point in time from which these rules apply, or `None` if they have
+    /// always applied.
+    pub start: Option<OffsetDateTime>,
+
+    /// The observance in effect outside of daylight saving time.
+    ///
+    /// If the rule set has no yearly rules, this observance applies
+    /// throughout. Otherwise, it is the observance with the smallest offset.
+    pub standard: Observance,
+
+    /// The yearly changes of observance, typically to and from daylight
+    /// saving time.
+    pub rules: Vec<YearlyRule>,
+}
+
+/// A named offset from UTC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Observance {
+    /// The name of the observance, e.g. "Standard" or "Daylight".
+    pub name: String,
+
+    /// The offset from UTC of local time during the observance.
+    pub offset: UtcOffset,
+}
+
+/// A change to a given observance which occurs every year.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YearlyRule {
+    /// The observance in effect after the change.
+    pub observance: Observance,
+
+    /// The month in which the change occurs.
+    pub month: Month,
+
+    /// The day of the month on which the change occurs.
+    pub day: RuleDay,
+
+    /// The local time of day, in the observance preceding the change, at
+    /// which the change occurs.
+    pub time: Time,
+}
+
+/// The day of the month on which a [`YearlyRule`] applies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleDay {
+    /// The given occurrence of a weekday, between 1 and 4, or -1 for the last
+    /// occurrence in the month.
+    Weekday { weekday: Weekday, occurrence: i8 },
+
+    /// A fixed day of the month.
+    Date(u8),
+}
+
+/// A single change of observance within a given year.
+struct Change<'a> {
+    rule: &'a YearlyRule,
+    local: PrimitiveDateTime,
+    instant: OffsetDateTime,
+    offset_before: UtcOffset,
+}
+
+impl TimeZoneRules {
+    /// Creates rules for a time zone with a fixed offset from UTC.
+    pub fn fixed(name: impl Into<String>, offset: UtcOffset) -> Self {
+        Self {
+            rule_sets: vec![RuleSet {
+                start: None,
+                standard: Observance {
+                    name: name.into(),
+                    offset,
+                },
+                rules: vec![],
+            }],
+        }
+    }
+
+    /// The sets of rules which have applied over the lifetime of the time
+    /// zone, in chronological order.
+    pub fn rule_sets(&self) -> &[RuleSet] {
+        &self.rule_sets
+    }
+
+    /// Gets the observance in effect at the given point in time.
+    pub fn observance_at(&self, instant: OffsetDateTime) -> &Observance {
+        self.rule_set_at(instant).observance_at(instant)
+    }
+
+    /// Gets the offset from UTC of local time at the given point in time.
+    pub fn offset_at(&self, instant: OffsetDateTime) -> UtcOffset {
+        self.observance_at(instant).offset
+    }
+
+    /// Converts a point in time to local time in this time zone.
+    pub fn to_local(&self, instant: OffsetDateTime) -> OffsetDateTime {
+        instant.to_offset(self.offset_at(instant))
+    }
+
+    /// Converts a local date and time in this time zone to a point in time.
+    ///
+    /// Local times which occur twice, i.e. when changing back to standard
+    /// time, resolve to the earlier point in time. Local times which are
+    /// skipped, i.e. when changing to daylight saving time, are interpreted
+    /// using the offset in effect before the change, as specified by RFC 5545.
+    pub fn from_local(&self, local: PrimitiveDateTime) -> OffsetDateTime {
+        let mut offsets: Vec<UtcOffset> = self
+            .rule_sets
+            .iter()
+            .flat_map(|set| {
+                std::iter::once(set.standard.offset)
+                    .chain(set.rules.iter().map(|rule| rule.observance.offset))
+            })
+            .collect();
+
+        // Try the largest offsets first, as they result in earlier instants.
+        offsets.sort_unstable_by(|a, b| b.cmp(a));
+        offsets.dedup();
+
+        for offset in offsets {
+            let instant = local.assume_offset(offset);
+            if self.offset_at(instant) == offset {
+                return instant;
+            }
+        }
+
+        // No offset produces this local time, so it falls in a gap. Offsets are
+        // bounded by a day, so this is safely before the change. A gap within a
+        // day of the earliest representable time can only have been preceded by
+        // the earliest standard offset.
+        let before = local
+            .assume_utc()
+            .checked_sub(time::Duration::DAY)
+            .map(|instant| self.offset_at(instant))
+            .unwrap_or(self.rule_sets[0].standard.offset);
+
+        local.assume_offset(before)
+    }
+
+    /// Generates an iCalendar `VTIMEZONE` component describing these rules, as
+    /// specified by RFC 5545.
+    ///
+    /// Lines are terminated with CRLF and folded at 75 octets.
+    ///
+    /// See <https://www.rfc-editor.org/rfc/rfc5545#section-3.6.5>
+    pub fn to_vtimezone(&self, tzid: &str) -> String {
+        let mut lines = vec![
+            "BEGIN:VTIMEZONE".to_string(),
+            format!("TZID:{}", escape_text(tzid)),
+        ];
+
+        for (index, set) in self.rule_sets.iter().enumerate() {
+            let end = self.rule_sets.get(index + 1).and_then(|next| next.start);
+
+            if set.rules.is_empty() {
+                let offset_from = set
+                    .start
+                    .map(|start| self.offset_at(start - time::Duration::SECOND))
+                    .unwrap_or(set.standard.offset);
+                let dtstart = set
+                    .start
+                    .map(|start| start.to_offset(offset_from))
+                    .map(|local| PrimitiveDateTime::new(local.date(), local.time()))
+                    .unwrap_or_else(ical_epoch);
+
+                lines.extend(observance_lines(
+                    "STANDARD",
+                    dtstart,
+                    offset_from,
+                    &set.standard,
+                    None,
+                ));
+
+                continue;
+            }
+
+            let first_year = set.start.map(|start| start.year()).unwrap_or(1601);
+
+            for rule in &set.rules {
+                let first = (first_year..=first_year + 1)
+                    .flat_map(|year| set.changes_in_year(year))
+                    .filter(|change| std::ptr::eq(change.rule, rule))
+                    .find(|change| set.start.is_none_or(|start| change.instant >= start));
+
+                let Some(first) = first else {
+                    continue;
+                };
+
+                let until = match end {
+                    Some(end) => {
+                        let last = (first.local.year()..=end.year() + 1)
+                            .rev()
+                            .flat_map(|year| set.changes_in_year(year))
+                            .filter(|change| std::ptr::eq(change.rule, rule))
+                            .find(|change| change.instant < end);
+
+                        match last {
+                            Some(last) if last.instant >= first.instant => Some(last.instant),
+
+                            // The rule set ends before this rule ever applies.
+                            _ => continue,
+                        }
+                    }
+                    None => None,
+                };
+
+                let component = if rule.observance.offset > set.standard.offset {
+                    "DAYLIGHT"
+                } else {
+                    "STANDARD"
+                };
+
+                lines.extend(observance_lines(
+                    component,
+                    first.local,
+                    first.offset_before,
+                    &rule.observance,
+                    Some(rrule(rule, until)),
+                ));
+            }
+        }
+
+        lines.push("END:VTIMEZONE".to_string());
+
+        lines
+            .iter()
+            .map(|line| fold_line(line))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    fn rule_set_at(&self, instant: OffsetDateTime) -> &RuleSet {
+        self.rule_sets
+            .iter()
+            .rev()
+            .find(|set| set.start.is_none_or(|start| start <= instant))
+            .unwrap_or(&self.rule_sets[0])
+    }
+}
+
+impl RuleSet {
+    fn observance_at(&self, instant: OffsetDateTime) -> &Observance {
+        // Instants within a day of the limits of `time` may not be
+        // representable in UTC, in which case their local year is close enough.
+        let year = instant
+            .checked_to_offset(UtcOffset::UTC)
+            .unwrap_or(instant)
+            .year();
+
+        // The most recent change may have occurred in the previous year, and
+        // changes late in the year may fall in the next year in UTC.
+        (year - 1..=year + 1)
+            .flat_map(|year| self.changes_in_year(year))
+            .filter(|change| change.instant <= instant)
+            .max_by_key(|change| change.instant)
+            .map(|change| &change.rule.observance)
+            .unwrap_or(&self.standard)
+    }
+
+    /// Computes the changes of observance in the given year, in chronological
+    /// order.
+    fn changes_in_year(&self, year: i32) -> Vec<Change<'_>> {
+        let mut changes: Vec<_> = self
+            .rules
+            .iter()
+            .filter_map(|rule| Some((rule, rule.date_in_year(year)?.with_time(rule.time))))
+            .collect();
+        changes.sort_by_key(|(_, local)| *local);
+
+        (0..changes.len())
+            .map(|index| {
+                let (rule, local) = changes[index];
+
+                // Each change is expressed in the local time of the observance
+                // established by the previous change, wrapping around the year.
+                let previous = (index + changes.len() - 1) % changes.len();
+                let offset_before = changes[previous].0.observance.offset;
+
+                Change {
+                    rule,
+                    local,
+                    instant: local.assume_offset(offset_before),
+                    offset_before,
+                }
+            })
+            .collect()
+    }
+}
+
+impl YearlyRule {
+    fn date_in_year(&self, year: i32) -> Option<Date> {
+        let days_in_month = time::util::days_in_year_month(year, self.month);
+
+        match self.day {
+            RuleDay::Date(day) => {
+                Date::from_calendar_date(year, self.month, day.min(days_in_month)).ok()
+            }
+
+            RuleDay::Weekday {
+                weekday,
+                occurrence,
+            } if occurrence > 0 => {
+                let first = Date::from_calendar_date(year, self.month, 1).ok()?;
+                let delta = (weekday.number_days_from_monday() + 7
+                    - first.weekday().number_days_from_monday())
+                    % 7;
+
+                Date::from_calendar_date(year, self.month, 1 + delta + 7 * (occurrence as u8 - 1))
+                    .ok()
+            }
+
+            RuleDay::Weekday { weekday, .. } => {
+                let last = Date::from_calendar_date(year, self.month, days_in_month).ok()?;
+                let delta = (last.weekday().number_days_from_monday() + 7
+                    - weekday.number_days_from_monday())
+                    % 7;
+
+                Date::from_calendar_date(year, self.month, days_in_month - delta).ok()
+            }
+        }
+    }
+}
+
+impl TryFrom<&TimeZoneDefinition> for TimeZoneRules {
+    type Error = Error;
+
+    fn try_from(definition: &TimeZoneDefinition) -> Result<Self, Self::Error> {
+        let periods = definition
+            .periods
+            .iter()
+            .flat_map(|periods| &periods.inner)
+            .map(|period| {
+                let observance = Observance {
+                    name: period.name.clone(),
+                    offset: offset_from_bias(period.bias)?,
+                };
+
+                Ok((period.id.as_str(), observance))
+            })
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+
+        let groups: HashMap<_, _> = definition
+            .transitions_groups
+            .iter()
+            .flat_map(|groups| &groups.inner)
+            .map(|group| (group.id.as_str(), group.transitions.as_slice()))
+            .collect();
+
+        let period = |id: &str| {
+            periods
+                .get(id)
+                .cloned()
+                .ok_or_else(|| invalid(format!("unknown period {id:?}")))
+        };
+
+        let mut targets = definition
+            .transitions
+            .iter()
+            .flat_map(|transitions| &transitions.inner)
+            .map(|transition| match transition {
+                Transition::Transition { to } => Ok((None, to.kind, to.id.as_str())),
+                Transition::AbsoluteDateTransition { to, date_time } => {
+                    Ok((Some(date_time.0), to.kind, to.id.as_str()))
+                }
+                _ => Err(invalid(
+                    "recurring transitions must be part of a group".to_string(),
+                )),
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Definitions with a single group or period need not list transitions.
+        if targets.is_empty() {
+            if let [(id, _)] = groups.iter().collect::<Vec<_>>().as_slice() {
+                targets.push((None, TransitionTargetKind::Group, id));
+            } else if let [(id, _)] = periods.iter().collect::<Vec<_>>().as_slice() {
+                targets.push((None, TransitionTargetKind::Period, id));
+            } else {
+                return Err(invalid("no transitions".to_string()));
+            }
+        }
+
+        let mut rule_sets = targets
+            .into_iter()
+            .map(|(start, kind, id)| {
+                let transitions = match kind {
+                    TransitionTargetKind::Period => {
+                        return Ok(RuleSet {
+                            start,
+                            standard: period(id)?,
+                            rules: vec![],
+                        });
+                    }
+                    TransitionTargetKind::Group => groups
+                        .get(id)
+                        .ok_or_else(|| invalid(format!("unknown group {id:?}")))?,
+                };
+
+                if let [Transition::Transition { to }] = transitions {
+                    return Ok(RuleSet {
+                        start,
+                        standard: period(&to.id)?,
+                        rules: vec![],
+                    });
+                }
+
+                let rules = transitions
+                    .iter()
+                    .map(|transition| yearly_rule_from_transition(transition, &period))
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                rule_set_from_rules(start, rules)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        rule_sets.sort_by_key(|set| set.start);
+
+        Ok(Self { rule_sets })
+    }
+}
+
+impl TryFrom<&MeetingTimeZone> for TimeZoneRules {
+    type Error = Error;
+
+    fn try_from(zone: &MeetingTimeZone) -> Result<Self, Self::Error> {
+        let base = zone.base_offset.unwrap_or_default();
+
+        match (&zone.standard, &zone.daylight) {
+            (Some(standard), Some(daylight)) if standard.offset != daylight.offset => {
+                let rules = vec![
+                    yearly_rule_from_time_change(base, daylight, "Daylight")?,
+                    yearly_rule_from_time_change(base, standard, "Standard")?,
+                ];
+
+                Ok(Self {
+                    rule_sets: vec![rule_set_from_rules(None, rules)?],
+                })
+            }
+
+            (standard, _) => {
+                let offset = standard.as_ref().map(|change| change.offset.0);
+                let name = standard
+                    .as_ref()
+                    .and_then(|change| change.time_zone_name.clone())
+                    .unwrap_or_else(|| "Standard".to_string());
+
+                Ok(Self::fixed(
+                    name,
+                    offset_from_bias(Duration(base.0 + offset.unwrap_or_default()))?,
+                ))
+            }
+        }
+    }
+}
+
+fn rule_set_from_rules(
+    start: Option<OffsetDateTime>,
+    rules: Vec<YearlyRule>,
+) -> Result<RuleSet, Error> {
+    let standard = rules
+        .iter()
+        .map(|rule| &rule.observance)
+        .min_by_key(|observance| observance.offset)
+        .cloned()
+        .ok_or_else(|| invalid("empty transitions group".to_string()))?;
+
+    Ok(RuleSet {
+        start,
+        standard,
+        rules,
+    })
+}
+
+fn yearly_rule_from_transition(
+    transition: &Transition,
+    period: &impl Fn(&str) -> Result<Observance, Error>,
+) -> Result<YearlyRule, Error> {
+    let (to, time_offset, month, day) = match transition {
+        Transition::RecurringDayTransition {
+            to,
+            time_offset,
+            month,
+            day_of_week,
+            occurrence,
+        } => {
+            let day = RuleDay::Weekday {
+                weekday: weekday(*day_of_week)?,
+                occurrence: validate_occurrence(*occurrence)?,
+            };
+
+            (to, time_offset, month, day)
+        }
+
+        Transition::RecurringDateTransition {
+            to,
+            time_offset,
+            month,
+            day,
+        } => {
+            if !(1..=31).contains(day) {
+                return Err(invalid(format!("invalid day of month {day}")));
+            }
+
+            (to, time_offset, month, RuleDay::Date(*day))
+        }
+
+        _ => {
+            return Err(invalid(
+                "transitions groups may only contain recurring transitions".to_string(),
+            ))
+        }
+    };
+
+    if to.kind != TransitionTargetKind::Period {
+        return Err(invalid(
+            "recurring transitions must target a period".to_string(),
+        ));
+    }
+
+    Ok(YearlyRule {
+        observance: period(&to.id)?,
+        month: Month::try_from(*month).map_err(|_| invalid(format!("invalid month {month}")))?,
+        day,
+        time: time_of_day(time_offset.0)?,
+    })
+}
+
+fn yearly_rule_from_time_change(
+    base: Duration,
+    change: &TimeChange,
+    default_name: &str,
+) -> Result<YearlyRule, Error> {
+    let (month, day) = match (&change.relative_yearly_recurrence, &change.absolute_date) {
+        (Some(recurrence), _) => {
+            let occurrence = match recurrence.day_of_week_index {
+                DayOfWeekIndex::First => 1,
+                DayOfWeekIndex::Second => 2,
+                DayOfWeekIndex::Third => 3,
+                DayOfWeekIndex::Fourth => 4,
+                DayOfWeekIndex::Last => -1,
+            };
+
+            let day = RuleDay::Weekday {
+                weekday: weekday(recurrence.days_of_week)?,
+                occurrence,
+            };
+
+            (month(recurrence.month), day)
+        }
+
+        (None, Some(date)) => {
+            let date = parse_date(date)?;
+
+            (date.month(), RuleDay::Date(date.day()))
+        }
+
+        (None, None) => return Err(invalid("time change has no recurrence".to_string())),
+    };
+
+    Ok(YearlyRule {
+        observance: Observance {
+            name: change
+                .time_zone_name
+                .clone()
+                .unwrap_or_else(|| default_name.to_string()),
+            offset: offset_from_bias(Duration(base.0 + change.offset.0))?,
+        },
+        month,
+        day,
+        time: parse_time(&change.time)?,
+    })
+}
+
+fn invalid(reason: String) -> Error {
+    Error::InvalidTimeZone(reason)
+}
+
+/// Converts an Exchange bias, i.e. the value to add to local time to obtain
+/// UTC, to a UTC offset.
+fn offset_from_bias(bias: Duration) -> Result<UtcOffset, Error> {
+    i32::try_from(bias.0.whole_seconds())
+        .ok()
+        .and_then(|seconds| UtcOffset::from_whole_seconds(-seconds).ok())
+        .ok_or_else(|| invalid(format!("bias {bias} is out of range")))
+}
+
+fn time_of_day(offset: time::Duration) -> Result<Time, Error> {
+    if offset.is_negative() || offset >= time::Duration::DAY {
+        return Err(invalid(format!(
+            "time of day {} is out of range",
+            Duration(offset)
+        )));
+    }
+
+    Ok(Time::MIDNIGHT + offset)
+}
+
+fn validate_occurrence(occurrence: i8) -> Result<i8, Error> {
+    match occurrence {
+        1..=4 | -1 => Ok(occurrence),
+
+        // Windows uses 5 to denote the last occurrence.
+        5 => Ok(-1),
+        _ => Err(invalid(format!("invalid occurrence {occurrence}"))),
+    }
+}
+
+fn weekday(day: DayOfWeek) -> Result<Weekday, Error> {
+    match day {
+        DayOfWeek::Sunday => Ok(Weekday::Sunday),
+        DayOfWeek::Monday => Ok(Weekday::Monday),
+        DayOfWeek::Tuesday => Ok(Weekday::Tuesday),
+        DayOfWeek::Wednesday => Ok(Weekday::Wednesday),
+        DayOfWeek::Thursday => Ok(Weekday::Thursday),
+        DayOfWeek::Friday => Ok(Weekday::Friday),
+        DayOfWeek::Saturday => Ok(Weekday::Saturday),
+        DayOfWeek::Day | DayOfWeek::Weekday | DayOfWeek::WeekendDay => Err(invalid(format!(
+            "{day:?} is not a valid day for a transition"
+        ))),
+    }
+}
+
+fn month(month: MonthName) -> Month {
+    match month {
+        MonthName::January => Month::January,
+        MonthName::February => Month::February,
+        MonthName::March => Month::March,
+        MonthName::April => Month::April,
+        MonthName::May => Month::May,
+        MonthName::June => Month::June,
+        MonthName::July => Month::July,
+        MonthName::August => Month::August,
+        MonthName::September => Month::September,
+        MonthName::October => Month::October,
+        MonthName::November => Month::November,
+        MonthName::December => Month::December,
+    }
+}
+
+/// Parses an `xs:date`, ignoring any time zone suffix.
+fn parse_date(value: &str) -> Result<Date, Error> {
+    let error = || invalid(format!("invalid date {value:?}"));
+
+    let mut parts = value.get(..10).ok_or_else(error)?.splitn(3, '-');
+    let mut next = || parts.next().and_then(|part| part.parse::<i32>().ok());
+    let (Some(year), Some(month), Some(day)) = (next(), next(), next()) else {
+        return Err(error());
+    };
+
+    let month = u8::try_from(month)
+        .ok()
+        .and_then(|month| Month::try_from(month).ok())
+        .ok_or_else(error)?;
+    let day = u8::try_from(day).map_err(|_| error())?;
+
+    Date::from_calendar_date(year, month, day).map_err(|_| error())
+}
+
+/// Parses an `xs:time`, ignoring fractional seconds and any time zone suffix.
+fn parse_time(value: &str) -> Result<Time, Error> {
+    let error = || invalid(format!("invalid time {value:?}"));
+
+    let mut parts = value.get(..8).ok_or_else(error)?.splitn(3, ':');
+    let mut next = || parts.next().and_then(|part| part.parse::<u8>().ok());
+    let (Some(hour), Some(minute), Some(second)) = (next(), next(), next()) else {
+        return Err(error());
+    };
+
+    Time::from_hms(hour, minute, second).map_err(|_| error())
+}
+
+/// The conventional start of observances which have always applied.
+fn ical_epoch() -> PrimitiveDateTime {
+    PrimitiveDateTime::new(
+        Date::from_calendar_date(1601, Month::January, 1).unwrap(),
+        Time::MIDNIGHT,
+    )
+}
+
+fn observance_lines(
+    component: &str,
+    dtstart: PrimitiveDateTime,
+    offset_from: UtcOffset,
+    observance: &Observance,
+    rrule: Option<String>,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("BEGIN:{component}"),
+        format!("DTSTART:{}", format_local(dtstart)),
+    ];
+    lines.extend(rrule);
+    lines.extend([
+        format!("TZOFFSETFROM:{}", format_offset(offset_from)),
+        format!("TZOFFSETTO:{}", format_offset(observance.offset)),
+        format!("TZNAME:{}", escape_text(&observance.name)),
+        format!("END:{component}"),
+    ]);
+
+    lines
+}
+
+fn rrule(rule: &YearlyRule, until: Option<OffsetDateTime>) -> String {
+    let day = match rule.day {
+        RuleDay::Weekday {
+            weekday,
+            occurrence,
+        } => {
+            let weekday = match weekday {
+                Weekday::Monday => "MO",
+                Weekday::Tuesday => "TU",
+                Weekday::Wednesday => "WE",
+                Weekday::Thursday => "TH",
+                Weekday::Friday => "FR",
+                Weekday::Saturday => "SA",
+                Weekday::Sunday => "SU",
+            };
+
+            format!("BYDAY={occurrence}{weekday}")
+        }
+        RuleDay::Date(day) => format!("BYMONTHDAY={day}"),
+    };
+
+    let mut rrule = format!("RRULE:FREQ=YEARLY;BYMONTH={};{day}", u8::from(rule.month));
+    if let Some(until) = until {
+        let until = until.to_offset(UtcOffset::UTC);
+        rrule.push_str(&format!(
+            ";UNTIL={}Z",
+            format_local(PrimitiveDateTime::new(until.date(), until.time()))
+        ));
+    }
+
+    rrule
+}
+
+fn format_local(date_time: PrimitiveDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}",
+        date_time.year(),
+        u8::from(date_time.month()),
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second()
+    )
+}
+
+fn format_offset(offset: UtcOffset) -> String {
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    let (hours, minutes, seconds) = offset.as_hms();
+    let (hours, minutes, seconds) = (hours.abs(), minutes.abs(), seconds.abs());
+
+    if seconds == 0 {
+        format!("{sign}{hours:02}{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}{minutes:02}{seconds:02}")
+    }
+}
+
+/// Escapes a value of type TEXT as specified by RFC 5545.
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line at 75 octets and terminates it with CRLF, as specified
+/// by RFC 5545.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut line_len = 0;
+
+    for c in line.chars() {
+        // Continuation lines begin with a space, which counts towards their
+        // length.
+        if line_len + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_len = 1;
+        }
+
+        folded.push(c);
+        line_len += c.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use time::format_description::well_known::Iso8601;
+
+    use super::*;
+
+    fn instant(value: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(value, &Iso8601::DEFAULT).unwrap()
+    }
+
+    fn local(value: &str) -> PrimitiveDateTime {
+        PrimitiveDateTime::parse(value, &Iso8601::DEFAULT).unwrap()
+    }
+
+    fn offset(hours: i8) -> UtcOffset {
+        UtcOffset::from_hms(hours, 0, 0).unwrap()
+    }
+
+    /// The Pacific time zone, including the change of daylight saving rules
+    /// in 2007.
+    fn pacific_rules() -> TimeZoneRules {
+        let definition: TimeZoneDefinition = quick_xml::de::from_str(
+            r#"
+            <t:TimeZoneDefinition Name="(UTC-08:00) Pacific Time (US &amp; Canada)" Id="Pacific Standard Time">
+              <t:Periods>
+                <t:Period Bias="PT8H" Name="Standard" Id="Std" />
+                <t:Period Bias="PT7H" Name="Daylight" Id="Dlt/2006" />
+                <t:Period Bias="PT7H" Name="Daylight" Id="Dlt/2007" />
+              </t:Periods>
+              <t:TransitionsGroups>
+                <t:TransitionsGroup Id="0">
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Dlt/2006</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>4</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>1</t:Occurrence>
+                  </t:RecurringDayTransition>
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Std</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>10</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>-1</t:Occurrence>
+                  </t:RecurringDayTransition>
+                </t:TransitionsGroup>
+                <t:TransitionsGroup Id="1">
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Dlt/2007</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>3</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>2</t:Occurrence>
+                  </t:RecurringDayTransition>
+                  <t:RecurringDayTransition>
+                    <t:To Kind="Period">Std</t:To>
+                    <t:TimeOffset>PT2H</t:TimeOffset>
+                    <t:Month>11</t:Month>
+                    <t:DayOfWeek>Sunday</t:DayOfWeek>
+                    <t:Occurrence>1</t:Occurrence>
+                  </t:RecurringDayTransition>
+                </t:TransitionsGroup>
+              </t:TransitionsGroups>
+              <t:Transitions>
+                <t:Transition>
+                  <t:To Kind="Group">0</t:To>
+                </t:Transition>
+                <t:AbsoluteDateTransition>
+                  <t:To Kind="Group">1</t:To>
+                  <t:DateTime>2007-01-01T00:00:00</t:DateTime>
+                </t:AbsoluteDateTransition>
+              </t:Transitions>
+            </t:TimeZoneDefinition>"#,
+        )
+        .unwrap();
+
+        TimeZoneRules::try_from(&definition).unwrap()
+    }
+
+    #[test]
+    fn offsets_follow_transitions_from_definition() {
+        let rules = pacific_rules();
+
+        let cases = [
+            // Rules in effect since 2007.
+            ("2023-01-15T12:00:00Z", -8),
+            ("2023-03-12T09:59:59Z", -8),
+            ("2023-03-12T10:00:00Z", -7),
+            ("2023-11-05T08:59:59Z", -7),
+            ("2023-11-05T09:00:00Z", -8),
+            ("2023-12-31T23:00:00Z", -8),
+            // Rules in effect until 2007.
+            ("2006-03-12T12:00:00Z", -8),
+            ("2006-04-02T09:59:59Z", -8),
+            ("2006-04-02T10:00:00Z", -7),
+            ("2006-10-29T08:59:59Z", -7),
+            ("2006-10-29T09:00:00Z", -8),
+            ("1990-07-01T00:00:00Z", -7),
+        ];
+
+        for (value, hours) in cases {
+            assert_eq!(rules.offset_at(instant(value)), offset(hours), "at {value}");
+        }
+
+        assert_eq!(
+            rules.observance_at(instant("2023-07-01T00:00:00Z")).name,
+            "Daylight"
+        );
+    }
+
+    #[test]
+    fn converts_between_local_and_utc() {
+        let rules = pacific_rules();
+
+        assert_eq!(
+            rules.to_local(instant("2023-07-01T12:00:00Z")),
+            instant("2023-07-01T05:00:00-07:00")
+        );
+
+        assert_eq!(
+            rules.from_local(local("2023-01-15T09:30:00")),
+            instant("2023-01-15T17:30:00Z")
+        );
+
+        // Ambiguous times resolve to the earlier instant.
+        assert_eq!(
+            rules.from_local(local("2023-11-05T01:30:00")),
+            instant("2023-11-05T08:30:00Z")
+        );
+
+        // Skipped times use the offset from before the change.
+        assert_eq!(
+            rules.from_local(local("2023-03-12T02:30:00")),
+            instant("2023-03-12T10:30:00Z")
+        );
+    }
+
+    #[test]
+    fn converts_skipped_time_at_earliest_date() {
+        let standard = Observance {
+            name: "Standard".to_string(),
+            offset: offset(0),
+        };
+        let daylight = Observance {
+            name: "Daylight".to_string(),
+            offset: offset(1),
+        };
+
+        // Daylight saving time starts at the very beginning of the year, so the
+        // first hour of the earliest representable date is skipped.
+        let rules = TimeZoneRules {
+            rule_sets: vec![RuleSet {
+                start: None,
+                standard: standard.clone(),
+                rules: vec![
+                    YearlyRule {
+                        observance: daylight,
+                        month: Month::January,
+                        day: RuleDay::Date(1),
+                        time: Time::MIDNIGHT,
+                    },
+                    YearlyRule {
+                        observance: standard,
+                        month: Month::July,
+                        day: RuleDay::Date(1),
+                        time: Time::MIDNIGHT,
+                    },
+                ],
+            }],
+        };
+
+        assert_eq!(
+            rules.from_local(PrimitiveDateTime::MIN),
+            PrimitiveDateTime::MIN.assume_utc()
+        );
+    }
+
+    #[test]
+    fn converts_meeting_time_zone() {
+        let zone: MeetingTimeZone = quick_xml::de::from_str(
+            r#"
+            <t:MeetingTimeZone TimeZoneName="Pacific Standard Time">
+              <t:BaseOffset>PT8H</t:BaseOffset>
+              <t:Standard TimeZoneName="Standard">
+                <t:Offset>PT0M</t:Offset>
+                <t:RelativeYearlyRecurrence>
+                  <t:DaysOfWeek>Sunday</t:DaysOfWeek>
+                  <t:DayOfWeekIndex>First</t:DayOfWeekIndex>
+                  <t:Month>November</t:Month>
+                </t:RelativeYearlyRecurrence>
+                <t:Time>02:00:00</t:Time>
+              </t:Standard>
+              <t:Daylight TimeZoneName="Daylight">
+                <t:Offset>-PT1H</t:Offset>
+                <t:RelativeYearlyRecurrence>
+                  <t:DaysOfWeek>Sunday</t:DaysOfWeek>
+                  <t:DayOfWeekIndex>Second</t:DayOfWeekIndex>
+                  <t:Month>March</t:Month>
+                </t:RelativeYearlyRecurrence>
+                <t:Time>02:00:00</t:Time>
+              </t:Daylight>
+            </t:MeetingTimeZone>"#,
+        )
+        .unwrap();
+
+        let rules = TimeZoneRules::try_from(&zone).unwrap();
+
+        assert_eq!(rules.offset_at(instant("2023-03-12T09:59:59Z")), offset(-8));
+        assert_eq!(rules.offset_at(instant("2023-03-12T10:00:00Z")), offset(-7));
+        assert_eq!(rules.offset_at(instant("2023-11-05T09:00:00Z")), offset(-8));
+
+        let fixed = MeetingTimeZone {
+            time_zone_name: Some("Tokyo Standard Time".to_string()),
+            base_offset: Some(Duration(time::Duration::hours(-9))),
+            ..Default::default()
+        };
+        let rules = TimeZoneRules::try_from(&fixed).unwrap();
+        assert_eq!(rules, TimeZoneRules::fixed("Standard", offset(9)));
+    }
+
+    #[test]
+    fn rejects_invalid_definitions() {
+        let definition: TimeZoneDefinition = quick_xml::de::from_str(
+            r#"
+            <t:TimeZoneDefinition Id="Broken">
+              <t:Periods>
+                <t:Period Bias="PT0H" Name="Standard" Id="Std" />
+              </t:Periods>
+              <t:Transitions>
+                <t:Transition>
+                  <t:To Kind="Period">Missing</t:To>
+                </t:Transition>
+              </t:Transitions>
+            </t:TimeZoneDefinition>"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            TimeZoneRules::try_from(&definition),
+            Err(Error::InvalidTimeZone(_))
+        ));
+    }
+
+    #[test]
+    fn generates_vtimezone() {
+        let expected = [
+            "BEGIN:VTIMEZONE",
+            "TZID:America/Los_Angeles",
+            "BEGIN:DAYLIGHT",
+            "DTSTART:16010401T020000",
+            "RRULE:FREQ=YEARLY;BYMONTH=4;BYDAY=1SU;UNTIL=20060402T100000Z",
+            "TZOFFSETFROM:-0800",
+            "TZOFFSETTO:-0700",
+            "TZNAME:Daylight",
+            "END:DAYLIGHT",
+            "BEGIN:STANDARD",
+            "DTSTART:16011028T020000",
+            "RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU;UNTIL=20061029T090000Z",
+            "TZOFFSETFROM:-0700",
+            "TZOFFSETTO:-0800",
+            "TZNAME:Standard",
+            "END:STANDARD",
+            "BEGIN:DAYLIGHT",
+            "DTSTART:20070311T020000",
+            "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+            "TZOFFSETFROM:-0800",
+            "TZOFFSETTO:-0700",
+            "TZNAME:Daylight",
+            "END:DAYLIGHT",
+            "BEGIN:STANDARD",
+            "DTSTART:20071104T020000",
+            "RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU",
+            "TZOFFSETFROM:-0700",
+            "TZOFFSETTO:-0800",
+            "TZNAME:Standard",
+            "END:STANDARD",
+            "END:VTIMEZONE",
+            "",
+        ]
+        .join("\r\n");
+
+        assert_eq!(
+            pacific_rules().to_vtimezone("America/Los_Angeles"),
+            expected
+        );
+    }
+
+    #[test]
+    fn generates_vtimezone_for_fixed_offset() {
+        let rules = TimeZoneRules::fixed(
+            "India Standard Time",
+            UtcOffset::from_hms(5, 30, 0).unwrap(),
+        );
+
+        let expected = [
+            "BEGIN:VTIMEZONE",
+            "TZID:Asia/Kolkata",
+            "BEGIN:STANDARD",
+            "DTSTART:16010101T000000",
+            "TZOFFSETFROM:+0530",
+            "TZOFFSETTO:+0530",
+            "TZNAME:India Standard Time",
+            "END:STANDARD",
+            "END:VTIMEZONE",
+            "",
+        ]
+        .join("\r\n");
+
+        assert_eq!(rules.to_vtimezone("Asia/Kolkata"), expected);
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let folded = fold_line(&format!("TZID:{}", "x".repeat(80)));
+
+        assert_eq!(
+            folded,
+            format!("TZID:{}\r\n {}\r\n", "x".repeat(70), "x".repeat(10))
+        );
+    }
+}
@@ -0,0 +1,211 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Base64Binary, BaseFolderId, ItemId};
+
+pub mod category_list;
+pub use self::category_list::CategoryList;
+
+/// A user configuration object, a collection of application settings stored
+/// as an associated item in a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userconfiguration>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserConfiguration {
+    /// The name of the configuration object and the folder containing it.
+    #[xml_struct(ns_prefix = "t")]
+    pub user_configuration_name: UserConfigurationName,
+
+    /// The identifier of the item storing the configuration object.
+    ///
+    /// This is only included in responses.
+    #[xml_struct(ns_prefix = "t")]
+    pub item_id: Option<ItemId>,
+
+    /// The settings stored as key-value pairs.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dictionary>
+    #[xml_struct(ns_prefix = "t")]
+    pub dictionary: Option<Dictionary>,
+
+    /// The settings stored as an XML document.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/xmldata>
+    #[xml_struct(ns_prefix = "t")]
+    pub xml_data: Option<Base64Binary>,
+
+    /// The settings stored as arbitrary binary data.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/binarydata>
+    #[xml_struct(ns_prefix = "t")]
+    pub binary_data: Option<Base64Binary>,
+}
+
+/// The name of a user configuration object and the folder containing it.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userconfigurationname>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct UserConfigurationName {
+    /// The name of the configuration object, e.g. `CategoryList`.
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    /// The folder containing the configuration object.
+    #[xml_struct(flatten)]
+    #[serde(rename = "$value")]
+    pub folder_id: BaseFolderId,
+}
+
+/// The parts of a user configuration object to retrieve.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userconfigurationproperties>
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UserConfigurationProperties(pub Vec<UserConfigurationProperty>);
+
+impl XmlSerialize for UserConfigurationProperties {
+    /// Serializes the properties as a whitespace-separated list.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.0
+            .iter()
+            .map(|property| property.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .serialize_child_nodes(writer)
+    }
+}
+
+/// A part of a user configuration object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserConfigurationProperty {
+    /// The identifier of the item storing the configuration object.
+    Id,
+
+    /// The settings stored as key-value pairs.
+    Dictionary,
+
+    /// The settings stored as an XML document.
+    XmlData,
+
+    /// The settings stored as arbitrary binary data.
+    BinaryData,
+
+    /// All of the above.
+    All,
+}
+
+impl UserConfigurationProperty {
+    /// Gets the representation of the property used in EWS requests.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "Id",
+            Self::Dictionary => "Dictionary",
+            Self::XmlData => "XmlData",
+            Self::BinaryData => "BinaryData",
+            Self::All => "All",
+        }
+    }
+}
+
+/// A collection of key-value pairs in a user configuration object.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dictionary>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Dictionary {
+    #[serde(rename = "DictionaryEntry", default)]
+    pub inner: Vec<DictionaryEntry>,
+}
+
+impl XmlSerialize for Dictionary {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for entry in &self.inner {
+            entry.serialize_as_element(writer, "t:DictionaryEntry")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A key-value pair in a user configuration object.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dictionaryentry>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DictionaryEntry {
+    #[xml_struct(ns_prefix = "t")]
+    pub dictionary_key: DictionaryObject,
+
+    /// The value of the entry, omitted if the value is null.
+    #[xml_struct(ns_prefix = "t")]
+    pub dictionary_value: Option<DictionaryObject>,
+}
+
+/// A typed key or value in a user configuration dictionary.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dictionarykey>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct DictionaryObject {
+    /// The type of the object.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/type-dictionaryobjecttypestype>
+    #[serde(rename = "Type")]
+    pub object_type: DictionaryObjectType,
+
+    /// The value of the object as text, e.g. base64 for a byte array.
+    ///
+    /// Only arrays have more than one value.
+    #[serde(rename = "Value", default)]
+    pub values: Vec<String>,
+}
+
+impl XmlSerialize for DictionaryObject {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.object_type.serialize_as_element(writer, "t:Type")?;
+        for value in &self.values {
+            value.serialize_as_element(writer, "t:Value")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The type of a key or value in a user configuration dictionary.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/type-dictionaryobjecttypestype>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DictionaryObjectType {
+    DateTime,
+    Boolean,
+    Byte,
+    String,
+    Integer32,
+    UnsignedInteger32,
+    Integer64,
+    UnsignedInteger64,
+    StringArray,
+    ByteArray,
+}
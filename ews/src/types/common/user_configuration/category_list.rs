@@ -0,0 +1,404 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The master category list of a mailbox.
+//!
+//! The names, colours and keyboard shortcuts of the categories which can be
+//! assigned to items are stored as an XML document in the `XmlData` of the
+//! `CategoryList` user configuration object in the calendar folder. Items
+//! reference categories by name only, e.g. in [`Message::categories`].
+//!
+//! [`Message::categories`]: crate::Message::categories
+
+use quick_xml::{
+    events::{BytesDecl, BytesEnd, BytesStart, Event},
+    Reader, Writer,
+};
+
+use crate::{
+    Base64Binary, BaseFolderId, DistinguishedFolderName, Error, UserConfiguration,
+    UserConfigurationName,
+};
+
+/// The name of the user configuration object storing the category list.
+pub const CATEGORY_LIST_CONFIGURATION_NAME: &str = "CategoryList";
+
+/// The namespace of the category list XML document.
+const CATEGORY_LIST_NS: &str = "CategoryList.xsd";
+
+/// The master category list of a mailbox.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CategoryList {
+    /// The name of the category assigned by default, e.g. by the "Quick Click"
+    /// action in Outlook.
+    pub default_category: Option<String>,
+
+    /// The categories in the list.
+    pub categories: Vec<Category>,
+
+    /// Any other attributes of the list, such as bookkeeping written by
+    /// Outlook, preserved so that they can be written back unchanged.
+    pub other_attributes: Vec<(String, String)>,
+}
+
+/// A category in the master category list.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Category {
+    /// The name of the category, as referenced by items.
+    pub name: String,
+
+    /// The colour of the category, or `None` if it is not coloured.
+    pub color: Option<CategoryColor>,
+
+    /// The keyboard shortcut assigning the category, if any.
+    pub keyboard_shortcut: Option<CategoryShortcut>,
+
+    /// The unique identifier of the category, a GUID in braces.
+    pub guid: String,
+
+    /// Any other attributes of the category, such as usage statistics written
+    /// by Outlook, preserved so that they can be written back unchanged.
+    pub other_attributes: Vec<(String, String)>,
+}
+
+/// The preset colour of a category.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CategoryColor {
+    Red,
+    Orange,
+    Peach,
+    Yellow,
+    Green,
+    Teal,
+    Olive,
+    Blue,
+    Purple,
+    Maroon,
+    Steel,
+    DarkSteel,
+    Gray,
+    DarkGray,
+    Black,
+    DarkRed,
+    DarkOrange,
+    DarkPeach,
+    DarkYellow,
+    DarkGreen,
+    DarkTeal,
+    DarkOlive,
+    DarkBlue,
+    DarkPurple,
+    DarkMaroon,
+}
+
+impl CategoryColor {
+    const ALL: [Self; 25] = [
+        Self::Red,
+        Self::Orange,
+        Self::Peach,
+        Self::Yellow,
+        Self::Green,
+        Self::Teal,
+        Self::Olive,
+        Self::Blue,
+        Self::Purple,
+        Self::Maroon,
+        Self::Steel,
+        Self::DarkSteel,
+        Self::Gray,
+        Self::DarkGray,
+        Self::Black,
+        Self::DarkRed,
+        Self::DarkOrange,
+        Self::DarkPeach,
+        Self::DarkYellow,
+        Self::DarkGreen,
+        Self::DarkTeal,
+        Self::DarkOlive,
+        Self::DarkBlue,
+        Self::DarkPurple,
+        Self::DarkMaroon,
+    ];
+
+    /// Gets the colour with the given index in the category list, where `-1`
+    /// denotes no colour.
+    pub fn from_index(index: i32) -> Result<Option<Self>, Error> {
+        if index == -1 {
+            return Ok(None);
+        }
+
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| Self::ALL.get(index))
+            .map(|color| Some(*color))
+            .ok_or_else(|| Error::InvalidCategoryList(format!("unknown color {index}")))
+    }
+
+    /// Gets the index of the colour in the category list.
+    pub fn index(&self) -> i32 {
+        *self as i32
+    }
+}
+
+/// A keyboard shortcut assigning a category, from Ctrl+F2 to Ctrl+F12.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CategoryShortcut(u8);
+
+impl CategoryShortcut {
+    /// Gets the shortcut for the given function key, from 2 to 12.
+    pub fn from_function_key(key: u8) -> Option<Self> {
+        (2..=12).contains(&key).then_some(Self(key))
+    }
+
+    /// Gets the number of the function key pressed with Ctrl.
+    pub fn function_key(&self) -> u8 {
+        self.0
+    }
+
+    /// Gets the shortcut with the given index in the category list, where `0`
+    /// denotes no shortcut.
+    fn from_index(index: i32) -> Result<Option<Self>, Error> {
+        match index {
+            0 => Ok(None),
+            1..=11 => Ok(Some(Self(index as u8 + 1))),
+            _ => Err(Error::InvalidCategoryList(format!(
+                "unknown keyboard shortcut {index}"
+            ))),
+        }
+    }
+
+    fn index(&self) -> i32 {
+        i32::from(self.0) - 1
+    }
+}
+
+impl CategoryList {
+    /// Gets the name of the user configuration object storing the category
+    /// list of the authenticated user's mailbox.
+    pub fn configuration_name() -> UserConfigurationName {
+        UserConfigurationName {
+            name: CATEGORY_LIST_CONFIGURATION_NAME.to_string(),
+            folder_id: BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Calendar,
+                change_key: None,
+                mailbox: None,
+            },
+        }
+    }
+
+    /// Parses the category list stored in a user configuration object.
+    pub fn from_user_configuration(configuration: &UserConfiguration) -> Result<Self, Error> {
+        let xml_data = configuration.xml_data.as_ref().ok_or_else(|| {
+            Error::InvalidCategoryList("user configuration has no XML data".to_string())
+        })?;
+
+        Self::from_xml(&xml_data.0)
+    }
+
+    /// Serializes the category list for storage in the `XmlData` of a user
+    /// configuration object.
+    pub fn to_xml_data(&self) -> Result<Base64Binary, Error> {
+        self.to_xml().map(Base64Binary)
+    }
+
+    /// Parses a category list XML document.
+    pub fn from_xml(xml: &[u8]) -> Result<Self, Error> {
+        let xml = xml.strip_prefix(b"\xef\xbb\xbf").unwrap_or(xml);
+        let mut reader = Reader::from_reader(xml);
+
+        let mut list = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) | Event::Empty(element) => match element.name().as_ref() {
+                    b"categories" if list.is_none() => {
+                        let mut categories = Self::default();
+                        for (key, value) in read_attributes(&element)? {
+                            match key.as_str() {
+                                "default" => categories.default_category = Some(value),
+                                "xmlns" => {}
+                                _ => categories.other_attributes.push((key, value)),
+                            }
+                        }
+
+                        list = Some(categories);
+                    }
+                    b"category" => {
+                        let list = list.as_mut().ok_or_else(|| {
+                            Error::InvalidCategoryList(
+                                "category outside of the category list".to_string(),
+                            )
+                        })?;
+
+                        list.categories.push(read_category(&element)?);
+                    }
+                    name => {
+                        return Err(Error::InvalidCategoryList(format!(
+                            "unexpected element `{}`",
+                            String::from_utf8_lossy(name)
+                        )))
+                    }
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        list.ok_or_else(|| Error::InvalidCategoryList("missing `categories` element".to_string()))
+    }
+
+    /// Serializes the category list as an XML document.
+    pub fn to_xml(&self) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", None, None)))?;
+
+        let mut start = BytesStart::new("categories");
+        if let Some(default_category) = &self.default_category {
+            start.push_attribute(("default", default_category.as_str()));
+        }
+        for (key, value) in &self.other_attributes {
+            start.push_attribute((key.as_str(), value.as_str()));
+        }
+        start.push_attribute(("xmlns", CATEGORY_LIST_NS));
+        writer.write_event(Event::Start(start))?;
+
+        for category in &self.categories {
+            let color = category.color.map_or(-1, |color| color.index()).to_string();
+            let keyboard_shortcut = category
+                .keyboard_shortcut
+                .map_or(0, |shortcut| shortcut.index())
+                .to_string();
+
+            let mut element = BytesStart::new("category");
+            element.push_attribute(("name", category.name.as_str()));
+            element.push_attribute(("color", color.as_str()));
+            element.push_attribute(("keyboardShortcut", keyboard_shortcut.as_str()));
+            element.push_attribute(("guid", category.guid.as_str()));
+            for (key, value) in &category.other_attributes {
+                element.push_attribute((key.as_str(), value.as_str()));
+            }
+            writer.write_event(Event::Empty(element))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("categories")))?;
+
+        Ok(writer.into_inner())
+    }
+}
+
+fn read_category(element: &BytesStart) -> Result<Category, Error> {
+    let mut name = None;
+    let mut category = Category::default();
+    for (key, value) in read_attributes(element)? {
+        match key.as_str() {
+            "name" => name = Some(value),
+            "color" => category.color = CategoryColor::from_index(parse_index(&key, &value)?)?,
+            "keyboardShortcut" => {
+                category.keyboard_shortcut =
+                    CategoryShortcut::from_index(parse_index(&key, &value)?)?
+            }
+            "guid" => category.guid = value,
+            _ => category.other_attributes.push((key, value)),
+        }
+    }
+
+    category.name =
+        name.ok_or_else(|| Error::InvalidCategoryList("category has no name".to_string()))?;
+
+    Ok(category)
+}
+
+fn read_attributes(element: &BytesStart) -> Result<Vec<(String, String)>, Error> {
+    element
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.map_err(quick_xml::Error::from)?;
+            let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+            let value = attribute.unescape_value()?.into_owned();
+
+            Ok((key, value))
+        })
+        .collect()
+}
+
+fn parse_index(key: &str, value: &str) -> Result<i32, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::InvalidCategoryList(format!("invalid {key} `{value}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATEGORY_LIST: &str = r#"<?xml version="1.0"?><categories default="Red category" lastSavedSession="1" xmlns="CategoryList.xsd"><category name="Red category" color="0" keyboardShortcut="0" guid="{6a2b6d6d-6c3b-4b2a-9d0e-9a8c1b3e6f01}" usageCount="3"/><category name="Travel &amp; leave" color="-1" keyboardShortcut="1" guid="{0f5e8d4e-2a3b-4c9d-8e7f-1a2b3c4d5e6f}"/><category name="Projects" color="24" keyboardShortcut="11" guid="{c3d9e1f2-0b1a-4f5e-9d8c-7b6a5f4e3d2c}" renameOnFirstUse="0"/></categories>"#;
+
+    fn expected_category_list() -> CategoryList {
+        CategoryList {
+            default_category: Some("Red category".to_string()),
+            categories: vec![
+                Category {
+                    name: "Red category".to_string(),
+                    color: Some(CategoryColor::Red),
+                    keyboard_shortcut: None,
+                    guid: "{6a2b6d6d-6c3b-4b2a-9d0e-9a8c1b3e6f01}".to_string(),
+                    other_attributes: vec![("usageCount".to_string(), "3".to_string())],
+                },
+                Category {
+                    name: "Travel & leave".to_string(),
+                    color: None,
+                    keyboard_shortcut: CategoryShortcut::from_function_key(2),
+                    guid: "{0f5e8d4e-2a3b-4c9d-8e7f-1a2b3c4d5e6f}".to_string(),
+                    other_attributes: vec![],
+                },
+                Category {
+                    name: "Projects".to_string(),
+                    color: Some(CategoryColor::DarkMaroon),
+                    keyboard_shortcut: CategoryShortcut::from_function_key(12),
+                    guid: "{c3d9e1f2-0b1a-4f5e-9d8c-7b6a5f4e3d2c}".to_string(),
+                    other_attributes: vec![("renameOnFirstUse".to_string(), "0".to_string())],
+                },
+            ],
+            other_attributes: vec![("lastSavedSession".to_string(), "1".to_string())],
+        }
+    }
+
+    #[test]
+    fn test_parse_category_list() {
+        let list = CategoryList::from_xml(CATEGORY_LIST.as_bytes()).unwrap();
+
+        assert_eq!(list, expected_category_list());
+    }
+
+    #[test]
+    fn test_write_category_list() {
+        let xml = expected_category_list().to_xml().unwrap();
+
+        assert_eq!(String::from_utf8(xml).unwrap(), CATEGORY_LIST);
+    }
+
+    #[test]
+    fn test_parse_category_list_from_user_configuration() {
+        let configuration = UserConfiguration {
+            user_configuration_name: CategoryList::configuration_name(),
+            item_id: None,
+            dictionary: None,
+            xml_data: Some(expected_category_list().to_xml_data().unwrap()),
+            binary_data: None,
+        };
+
+        let list = CategoryList::from_user_configuration(&configuration).unwrap();
+
+        assert_eq!(list, expected_category_list());
+    }
+
+    #[test]
+    fn test_reject_unknown_color() {
+        let xml = r#"<categories xmlns="CategoryList.xsd"><category name="Odd" color="25" guid="{}"/></categories>"#;
+
+        assert!(matches!(
+            CategoryList::from_xml(xml.as_bytes()),
+            Err(Error::InvalidCategoryList(_))
+        ));
+    }
+}
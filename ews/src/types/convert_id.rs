@@ -0,0 +1,252 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::{de, Deserialize, Deserializer};
+use xml_struct::XmlSerialize;
+
+use crate::{IdFormat, MESSAGES_NS_URI};
+
+/// A request to convert item and folder identifiers between formats.
+///
+/// Identifiers in the [`IdFormat::EwsId`] and [`IdFormat::EntryId`] formats
+/// can also be decoded locally with [`EwsId`] and [`EntryId`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/convertid-operation>
+///
+/// [`EwsId`]: crate::EwsId
+/// [`EntryId`]: crate::EntryId
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(ConvertIdResponseMessage, min_version = Exchange2007_SP1)]
+pub struct ConvertId {
+    /// The format to convert the identifiers to.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/convertid-operation#convertid-request-example>
+    #[xml_struct(attribute)]
+    pub destination_format: IdFormat,
+
+    /// The identifiers to convert.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sourceids>
+    pub source_ids: Vec<AlternateIdBase>,
+}
+
+/// An identifier of an item or folder in a given format.
+#[derive(Clone, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum AlternateIdBase {
+    /// An identifier of an item or folder in a mailbox.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/alternateid>
+    AlternateId {
+        #[xml_struct(attribute)]
+        format: IdFormat,
+
+        #[xml_struct(attribute)]
+        id: String,
+
+        /// The primary SMTP address of the mailbox containing the item or
+        /// folder.
+        #[xml_struct(attribute)]
+        mailbox: String,
+
+        /// Whether the item or folder is in the archive mailbox.
+        #[xml_struct(attribute)]
+        is_archive: Option<bool>,
+    },
+
+    /// An identifier of a public folder.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/alternatepublicfolderid>
+    AlternatePublicFolderId {
+        #[xml_struct(attribute)]
+        format: IdFormat,
+
+        #[xml_struct(attribute)]
+        folder_id: String,
+    },
+
+    /// An identifier of an item in a public folder.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/alternatepublicfolderitemid>
+    AlternatePublicFolderItemId {
+        #[xml_struct(attribute)]
+        format: IdFormat,
+
+        #[xml_struct(attribute)]
+        folder_id: String,
+
+        #[xml_struct(attribute)]
+        item_id: String,
+    },
+}
+
+impl<'de> Deserialize<'de> for AlternateIdBase {
+    /// Deserializes a converted identifier.
+    ///
+    /// Responses name the element `AlternateId` whatever its kind, with the
+    /// kind given by an `xsi:type` attribute, so it is instead determined from
+    /// the attributes present.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawAlternateId {
+            #[serde(rename = "@Format")]
+            format: IdFormat,
+
+            #[serde(rename = "@Id")]
+            id: Option<String>,
+
+            #[serde(rename = "@Mailbox")]
+            mailbox: Option<String>,
+
+            #[serde(rename = "@IsArchive")]
+            is_archive: Option<bool>,
+
+            #[serde(rename = "@FolderId")]
+            folder_id: Option<String>,
+
+            #[serde(rename = "@ItemId")]
+            item_id: Option<String>,
+        }
+
+        let raw = RawAlternateId::deserialize(deserializer)?;
+
+        match raw {
+            RawAlternateId {
+                format,
+                id: Some(id),
+                mailbox,
+                is_archive,
+                ..
+            } => Ok(Self::AlternateId {
+                format,
+                id,
+                mailbox: mailbox.unwrap_or_default(),
+                is_archive,
+            }),
+            RawAlternateId {
+                format,
+                folder_id: Some(folder_id),
+                item_id: Some(item_id),
+                ..
+            } => Ok(Self::AlternatePublicFolderItemId {
+                format,
+                folder_id,
+                item_id,
+            }),
+            RawAlternateId {
+                format,
+                folder_id: Some(folder_id),
+                ..
+            } => Ok(Self::AlternatePublicFolderId { format, folder_id }),
+            _ => Err(de::Error::custom(
+                "alternate ID has neither an Id nor a FolderId attribute",
+            )),
+        }
+    }
+}
+
+/// A response to a request for an individual identifier within a
+/// [`ConvertId`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/convertidresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConvertIdResponseMessage {
+    /// The converted identifier.
+    pub alternate_id: AlternateIdBase,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn test_serialize_convert_id() {
+        let convert_id = ConvertId {
+            destination_format: IdFormat::EwsId,
+            source_ids: vec![
+                AlternateIdBase::AlternateId {
+                    format: IdFormat::HexEntryId,
+                    id: "00000000C22B97E3E2D5F84B9407EDDD13F43855".to_string(),
+                    mailbox: "alice@example.com".to_string(),
+                    is_archive: None,
+                },
+                AlternateIdBase::AlternatePublicFolderId {
+                    format: IdFormat::EntryId,
+                    folder_id: "AAAAABoJ".to_string(),
+                },
+                AlternateIdBase::AlternatePublicFolderItemId {
+                    format: IdFormat::EntryId,
+                    folder_id: "AAAAABoJ".to_string(),
+                    item_id: "AAAAABoK".to_string(),
+                },
+            ],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <ConvertId xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" DestinationFormat="EwsId">
+              <SourceIds>
+                <t:AlternateId Format="HexEntryId" Id="00000000C22B97E3E2D5F84B9407EDDD13F43855" Mailbox="alice@example.com"/>
+                <t:AlternatePublicFolderId Format="EntryId" FolderId="AAAAABoJ"/>
+                <t:AlternatePublicFolderItemId Format="EntryId" FolderId="AAAAABoJ" ItemId="AAAAABoK"/>
+              </SourceIds>
+            </ConvertId>"#,
+        );
+
+        assert_serialized_content(&convert_id, "ConvertId", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_convert_id_response() {
+        let content = r#"
+            <m:ConvertIdResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                 xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"
+                                 xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+              <m:ResponseMessages>
+                <m:ConvertIdResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:AlternateId xsi:type="t:AlternateIdType" Format="EwsId" Id="AAMkAGZhN2IxYTA0" Mailbox="alice@example.com"/>
+                </m:ConvertIdResponseMessage>
+                <m:ConvertIdResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:AlternateId xsi:type="t:AlternatePublicFolderItemIdType" Format="EwsId" FolderId="AQEuAAAD" ItemId="AQEuAAAE"/>
+                </m:ConvertIdResponseMessage>
+              </m:ResponseMessages>
+            </m:ConvertIdResponse>"#;
+
+        let expected = ConvertIdResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![
+                    ResponseClass::Success(ConvertIdResponseMessage {
+                        alternate_id: AlternateIdBase::AlternateId {
+                            format: IdFormat::EwsId,
+                            id: "AAMkAGZhN2IxYTA0".to_string(),
+                            mailbox: "alice@example.com".to_string(),
+                            is_archive: None,
+                        },
+                    }),
+                    ResponseClass::Success(ConvertIdResponseMessage {
+                        alternate_id: AlternateIdBase::AlternatePublicFolderItemId {
+                            format: IdFormat::EwsId,
+                            folder_id: "AQEuAAAD".to_string(),
+                            item_id: "AQEuAAAE".to_string(),
+                        },
+                    }),
+                ],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
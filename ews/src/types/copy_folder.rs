@@ -23,8 +23,8 @@ mod test {
     use crate::{
         copy_folder::{CopyFolder, CopyFolderResponse},
         test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
-        BaseFolderId, CopyMoveFolderData, Folder, FolderId, FolderResponseMessage, Folders,
-        ResponseClass,
+        BaseFolderId, CopyMoveFolderData, DistinguishedFolderName, Folder, FolderId,
+        FolderResponseMessage, Folders, ResponseClass,
     };
 
     #[test]
@@ -32,8 +32,9 @@ mod test {
         let copy_folder = CopyFolder {
             inner: CopyMoveFolderData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "inbox".to_string(),
+                    id: DistinguishedFolderName::Inbox,
                     change_key: None,
+                    mailbox: None,
                 },
                 folder_ids: vec![
                     BaseFolderId::FolderId {
@@ -52,7 +53,7 @@ mod test {
             r#"
             <CopyFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
               <ToFolderId>
-                <t:DistinguishedFolderId Id="inbox"/>
+                <t:DistinguishedFolderId Id="inbox"></t:DistinguishedFolderId>
               </ToFolderId>
               <FolderIds>
                 <t:FolderId Id="AS4A=" ChangeKey="fsVU4=="/>
@@ -98,6 +99,10 @@ mod test {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: None,
                             unread_count: None,
                         }],
                     },
@@ -23,8 +23,8 @@ mod test {
     use crate::{
         copy_item::{CopyItem, CopyItemResponse},
         test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
-        BaseFolderId, BaseItemId, CopyMoveItemData, ItemId, ItemResponseMessage, Items, Message,
-        RealItem, ResponseClass, ResponseMessages,
+        BaseFolderId, BaseItemId, CopyMoveItemData, DistinguishedFolderName, ItemId,
+        ItemResponseMessage, Items, Message, RealItem, ResponseClass, ResponseMessages,
     };
 
     #[test]
@@ -32,8 +32,9 @@ mod test {
         let request = CopyItem {
             inner: CopyMoveItemData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "inbox".to_string(),
+                    id: DistinguishedFolderName::Inbox,
                     change_key: None,
+                    mailbox: None,
                 },
                 item_ids: vec![BaseItemId::ItemId {
                     id: "AS4AUnV=".to_string(),
@@ -47,7 +48,7 @@ mod test {
             r#"
             <CopyItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
               <ToFolderId>
-                <t:DistinguishedFolderId Id="inbox"/>
+                <t:DistinguishedFolderId Id="inbox"></t:DistinguishedFolderId>
               </ToFolderId>
               <ItemIds>
                 <t:ItemId Id="AS4AUnV="/>
@@ -5,6 +5,7 @@
 use ews_proc_macros::operation_response;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::{BaseFolderId, Folder, FolderResponseMessage, MESSAGES_NS_URI};
 
 /// A request to create a new folder.
@@ -12,8 +13,207 @@ use crate::{BaseFolderId, Folder, FolderResponseMessage, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createfolder>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(FolderResponseMessage)]
+#[operation_response(FolderResponseMessage, manual_version_requirements)]
 pub struct CreateFolder {
     pub parent_folder_id: BaseFolderId,
     pub folders: Vec<Folder>,
 }
+
+impl VersionRequirements for CreateFolder {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        let path = format!("{path}/Folders");
+        for folder in &self.folders {
+            folder.check_version(version, &path, unsupported);
+        }
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        for folder in &mut self.folders {
+            folder.strip_unsupported(version);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        restrictions::{Constant, ConstantOrPath, FieldURIOrConstant, SearchExpression},
+        test_utils::{assert_serialized_content, minify_xml},
+        BaseFolderIds, DistinguishedFolderName, EffectiveRights, PathToElement, PermissionSet,
+        Restriction, RetentionTag, SearchFolderTraversal, SearchParameters,
+    };
+
+    #[test]
+    fn test_serialize_create_search_folder() {
+        let field = |field_uri: &str| PathToElement::FieldURI {
+            field_URI: field_uri.to_string(),
+        };
+
+        let constant = |value: &str| FieldURIOrConstant {
+            value: ConstantOrPath::Constant(Constant {
+                value: value.to_string(),
+            }),
+        };
+
+        let create_folder = CreateFolder {
+            parent_folder_id: BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::SearchFolders,
+                change_key: None,
+                mailbox: None,
+            },
+            folders: vec![Folder::SearchFolder {
+                folder_id: None,
+                parent_folder_id: None,
+                folder_class: None,
+                display_name: Some("Flagged last 7 days".to_string()),
+                total_count: None,
+                child_folder_count: None,
+                extended_property: None,
+                effective_rights: None,
+                policy_tag: None,
+                archive_tag: None,
+                permission_set: None,
+                unread_count: None,
+                search_parameters: Some(Box::new(SearchParameters {
+                    traversal: Some(SearchFolderTraversal::Deep),
+                    restriction: Restriction {
+                        expression: SearchExpression::And {
+                            expressions: vec![
+                                SearchExpression::IsEqualTo {
+                                    path: field("item:Flag/FlagStatus"),
+                                    field_URI_or_constant: constant("Flagged"),
+                                },
+                                SearchExpression::IsGreaterThanOrEqualTo {
+                                    path: field("item:DateTimeReceived"),
+                                    field_URI_or_constant: constant("2024-07-01T00:00:00Z"),
+                                },
+                            ],
+                        },
+                    },
+                    base_folder_ids: BaseFolderIds {
+                        inner: vec![BaseFolderId::DistinguishedFolderId {
+                            id: DistinguishedFolderName::MsgFolderRoot,
+                            change_key: None,
+                            mailbox: None,
+                        }],
+                    },
+                })),
+            }],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <CreateFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <ParentFolderId>
+                <t:DistinguishedFolderId Id="searchfolders"></t:DistinguishedFolderId>
+              </ParentFolderId>
+              <Folders>
+                <t:SearchFolder>
+                  <t:DisplayName>Flagged last 7 days</t:DisplayName>
+                  <t:SearchParameters Traversal="Deep">
+                    <t:Restriction>
+                      <t:And>
+                        <t:IsEqualTo>
+                          <t:FieldURI FieldURI="item:Flag/FlagStatus"/>
+                          <t:FieldURIOrConstant>
+                            <t:Constant Value="Flagged"/>
+                          </t:FieldURIOrConstant>
+                        </t:IsEqualTo>
+                        <t:IsGreaterThanOrEqualTo>
+                          <t:FieldURI FieldURI="item:DateTimeReceived"/>
+                          <t:FieldURIOrConstant>
+                            <t:Constant Value="2024-07-01T00:00:00Z"/>
+                          </t:FieldURIOrConstant>
+                        </t:IsGreaterThanOrEqualTo>
+                      </t:And>
+                    </t:Restriction>
+                    <t:BaseFolderIds>
+                      <t:DistinguishedFolderId Id="msgfolderroot"></t:DistinguishedFolderId>
+                    </t:BaseFolderIds>
+                  </t:SearchParameters>
+                </t:SearchFolder>
+              </Folders>
+            </CreateFolder>"#,
+        );
+
+        assert_serialized_content(&create_folder, "CreateFolder", &expected);
+    }
+
+    #[test]
+    fn test_create_folder_version_requirements() {
+        let mut create_folder = CreateFolder {
+            parent_folder_id: BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Inbox,
+                change_key: None,
+                mailbox: None,
+            },
+            folders: vec![Folder::Folder {
+                folder_id: None,
+                parent_folder_id: None,
+                folder_class: None,
+                display_name: Some("Projects".to_string()),
+                total_count: None,
+                child_folder_count: None,
+                extended_property: None,
+                effective_rights: Some(EffectiveRights {
+                    read: true,
+                    view_private_items: Some(false),
+                    ..Default::default()
+                }),
+                policy_tag: None,
+                archive_tag: Some(RetentionTag {
+                    is_explicit: true,
+                    retention_id: "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d".to_string(),
+                }),
+                permission_set: Some(PermissionSet::default()),
+                unread_count: None,
+            }],
+        };
+
+        let mut unsupported = vec![];
+        create_folder.check_version(
+            &ExchangeServerVersion::Exchange2007,
+            "CreateFolder",
+            &mut unsupported,
+        );
+        let paths: Vec<_> = unsupported
+            .iter()
+            .map(|element| element.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "CreateFolder/Folders/Folder/PermissionSet",
+                "CreateFolder/Folders/Folder/ArchiveTag",
+                "CreateFolder/Folders/Folder/EffectiveRights/ViewPrivateItems",
+            ]
+        );
+
+        // Only the read-only effective rights can be removed.
+        create_folder.strip_unsupported(&ExchangeServerVersion::Exchange2007);
+
+        let mut unsupported = vec![];
+        create_folder.check_version(
+            &ExchangeServerVersion::Exchange2007,
+            "CreateFolder",
+            &mut unsupported,
+        );
+        let paths: Vec<_> = unsupported
+            .iter()
+            .map(|element| element.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "CreateFolder/Folders/Folder/PermissionSet",
+                "CreateFolder/Folders/Folder/ArchiveTag",
+            ]
+        );
+    }
+}
@@ -5,6 +5,7 @@
 use ews_proc_macros::operation_response;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::{BaseFolderId, ItemResponseMessage, MessageDisposition, RealItem, MESSAGES_NS_URI};
 
 /// A request to create (and optionally send) one or more Exchange items.
@@ -12,7 +13,7 @@ use crate::{BaseFolderId, ItemResponseMessage, MessageDisposition, RealItem, MES
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createitem>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(ItemResponseMessage)]
+#[operation_response(ItemResponseMessage, manual_version_requirements)]
 pub struct CreateItem {
     /// The action the Exchange server will take upon creating this item.
     ///
@@ -37,6 +38,26 @@ pub struct CreateItem {
     pub items: Vec<RealItem>,
 }
 
+impl VersionRequirements for CreateItem {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        let path = format!("{path}/Items");
+        for item in &self.items {
+            item.check_version(version, &path, unsupported);
+        }
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        for item in &mut self.items {
+            item.strip_unsupported(version);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
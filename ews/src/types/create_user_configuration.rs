@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{UserConfiguration, MESSAGES_NS_URI};
+
+/// A request to create a user configuration object in a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createuserconfiguration-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(CreateUserConfigurationResponseMessage, min_version = Exchange2010)]
+pub struct CreateUserConfiguration {
+    /// The configuration object to create.
+    pub user_configuration: UserConfiguration,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateUserConfigurationResponseMessage {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_serialized_content, minify_xml},
+        CategoryList,
+    };
+
+    #[test]
+    fn test_serialize_create_user_configuration() {
+        let create_user_configuration = CreateUserConfiguration {
+            user_configuration: UserConfiguration {
+                user_configuration_name: CategoryList::configuration_name(),
+                item_id: None,
+                dictionary: None,
+                xml_data: Some(CategoryList::default().to_xml_data().unwrap()),
+                binary_data: None,
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <CreateUserConfiguration xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <UserConfiguration>
+                <t:UserConfigurationName Name="CategoryList">
+                  <t:DistinguishedFolderId Id="calendar"></t:DistinguishedFolderId>
+                </t:UserConfigurationName>
+                <t:XmlData>PD94bWwgdmVyc2lvbj0iMS4wIj8+PGNhdGVnb3JpZXMgeG1sbnM9IkNhdGVnb3J5TGlzdC54c2QiPjwvY2F0ZWdvcmllcz4=</t:XmlData>
+              </UserConfiguration>
+            </CreateUserConfiguration>"#,
+        );
+
+        assert_serialized_content(
+            &create_user_configuration,
+            "CreateUserConfiguration",
+            &expected,
+        );
+    }
+}
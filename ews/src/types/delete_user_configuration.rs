@@ -0,0 +1,24 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{UserConfigurationName, MESSAGES_NS_URI};
+
+/// A request to delete a user configuration object from a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deleteuserconfiguration-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(DeleteUserConfigurationResponseMessage, min_version = Exchange2010)]
+pub struct DeleteUserConfiguration {
+    /// The name of the configuration object and the folder containing it.
+    pub user_configuration_name: UserConfigurationName,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteUserConfigurationResponseMessage {}
@@ -13,7 +13,7 @@ use crate::{BaseFolderId, DeleteType, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/emptyfolder>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(EmptyFolderResponseMessage)]
+#[operation_response(EmptyFolderResponseMessage, min_version = Exchange2010_SP1)]
 pub struct EmptyFolder {
     /// The method the EWS server will use to perform deletions.
     ///
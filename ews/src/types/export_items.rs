@@ -0,0 +1,103 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Base64Binary, BaseItemId, ItemId, MESSAGES_NS_URI};
+
+/// A request to export items as full-fidelity data streams, e.g. for backup or
+/// migration to another mailbox.
+///
+/// The exported data can be imported with [`UploadItems`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/exportitems-operation>
+///
+/// [`UploadItems`]: crate::upload_items::UploadItems
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(ExportItemsResponseMessage, min_version = Exchange2010_SP1)]
+pub struct ExportItems {
+    /// A list of IDs of the items to export.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemids>
+    pub item_ids: Vec<BaseItemId>,
+}
+
+/// A response to a request for an individual item within an [`ExportItems`]
+/// operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/exportitemsresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExportItemsResponseMessage {
+    /// The ID of the exported item.
+    pub item_id: ItemId,
+
+    /// The exported contents of the item, in an opaque format specific to
+    /// Exchange.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/data>
+    pub data: Base64Binary,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn test_serialize_export_items() {
+        let export_items = ExportItems {
+            item_ids: vec![BaseItemId::ItemId {
+                id: "AAMkADAwATM0MDAAMS1m".to_string(),
+                change_key: None,
+            }],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <ExportItems xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <ItemIds>
+                <t:ItemId Id="AAMkADAwATM0MDAAMS1m"/>
+              </ItemIds>
+            </ExportItems>"#,
+        );
+
+        assert_serialized_content(&export_items, "ExportItems", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_export_items_response() {
+        let content = r#"
+            <m:ExportItemsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                   xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:ExportItemsResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:ItemId Id="AAMkADAwATM0MDAAMS1m" ChangeKey="CQAAABYAAAAA"/>
+                  <m:Data>AAAAAAEAAAA=</m:Data>
+                </m:ExportItemsResponseMessage>
+              </m:ResponseMessages>
+            </m:ExportItemsResponse>"#;
+
+        let expected = ExportItemsResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(ExportItemsResponseMessage {
+                    item_id: ItemId {
+                        id: "AAMkADAwATM0MDAAMS1m".to_string(),
+                        change_key: Some("CQAAABYAAAAA".to_string()),
+                    },
+                    data: Base64Binary(vec![0, 0, 0, 0, 1, 0, 0, 0]),
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
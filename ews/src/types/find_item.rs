@@ -2,6 +2,7 @@ use ews_proc_macros::operation_response;
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::{BaseFolderId, Groups, ItemShape, Items, View, MESSAGES_NS_URI};
 
 /// Defines a request to find items in mailbox.
@@ -9,7 +10,7 @@ use crate::{BaseFolderId, Groups, ItemShape, Items, View, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/finditem>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(FindItemResponseMessage)]
+#[operation_response(FindItemResponseMessage, manual_version_requirements)]
 pub struct FindItem {
     #[xml_struct(attribute)]
     pub traversal: Traversal,
@@ -22,6 +23,22 @@ pub struct FindItem {
     pub parent_folder_ids: Vec<BaseFolderId>,
 }
 
+impl VersionRequirements for FindItem {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        self.item_shape
+            .check_version(version, &format!("{path}/ItemShape"), unsupported);
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        self.item_shape.strip_unsupported(version);
+    }
+}
+
 /// Defines whether the search finds items in folders or the folders' dumpsters.
 /// This attribute is required.
 #[derive(Clone, Debug, XmlSerialize)]
@@ -84,8 +101,8 @@ pub struct RootFolder {
 mod tests {
     use crate::{
         test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
-        BasePoint, BaseShape, Groups, ItemId, Items, Message, RealItem, ResponseClass,
-        ResponseMessages,
+        BasePoint, BaseShape, DistinguishedFolderName, Groups, ItemId, Items, Message, RealItem,
+        ResponseClass, ResponseMessages,
     };
 
     use super::*;
@@ -99,8 +116,9 @@ mod tests {
                 ..Default::default()
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "deleteditems".to_string(),
+                id: DistinguishedFolderName::DeletedItems,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::IndexedPageItemView {
                 max_entries_returned: Some(6),
@@ -117,7 +135,7 @@ mod tests {
               </ItemShape>
               <IndexedPageItemView MaxEntriesReturned="6" BasePoint="Beginning" Offset="0"/>
               <ParentFolderIds>
-                <t:DistinguishedFolderId Id="deleteditems"/>
+                <t:DistinguishedFolderId Id="deleteditems"></t:DistinguishedFolderId>
               </ParentFolderIds>
             </FindItem>"#,
         );
@@ -134,8 +152,9 @@ mod tests {
                 ..Default::default()
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "inbox".to_string(),
+                id: DistinguishedFolderName::Inbox,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::FractionalPageItemView {
                 max_entries_returned: Some(12),
@@ -152,7 +171,7 @@ mod tests {
               </ItemShape>
               <FractionalPageItemView MaxEntriesReturned="12" Numerator="2" Denominator="3"/>
               <ParentFolderIds>
-                <t:DistinguishedFolderId Id="inbox"/>
+                <t:DistinguishedFolderId Id="inbox"></t:DistinguishedFolderId>
               </ParentFolderIds>
             </FindItem>"#,
         );
@@ -168,8 +187,9 @@ mod tests {
                 ..Default::default()
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "calendar".to_string(),
+                id: DistinguishedFolderName::Calendar,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::CalendarView {
                 max_entries_returned: Some(2),
@@ -186,7 +206,7 @@ mod tests {
               </ItemShape>
               <CalendarView MaxEntriesReturned="2" StartDate="2006-05-18T00:00:00-08:00" EndDate="2006-05-19T00:00:00-08:00"/>
               <ParentFolderIds>
-                <t:DistinguishedFolderId Id="calendar"/>
+                <t:DistinguishedFolderId Id="calendar"></t:DistinguishedFolderId>
               </ParentFolderIds>
             </FindItem>"#,
         );
@@ -203,8 +223,9 @@ mod tests {
                 ..Default::default()
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "contacts".to_string(),
+                id: DistinguishedFolderName::Contacts,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::ContactsView {
                 max_entries_returned: Some(3),
@@ -221,7 +242,7 @@ mod tests {
               </ItemShape>
               <ContactsView MaxEntriesReturned="3" InitialName="Kelly Rollin"/>
               <ParentFolderIds>
-                <t:DistinguishedFolderId Id="contacts"/>
+                <t:DistinguishedFolderId Id="contacts"></t:DistinguishedFolderId>
               </ParentFolderIds>
             </FindItem>"#,
         );
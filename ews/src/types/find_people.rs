@@ -0,0 +1,202 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{BaseFolderId, BaseShape, PathToElement, Persona, View, MESSAGES_NS_URI};
+
+/// A request to search for people in the contacts of the authenticated user
+/// and in the directory, e.g. to suggest recipients as an address is typed.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/findpeople-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(FindPeopleResponseMessage, shape = ResponseClass, min_version = Exchange2013)]
+pub struct FindPeople {
+    /// The properties to include for each persona found.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/personashape>
+    pub persona_shape: Option<PersonaShape>,
+
+    /// The range of results to return.
+    ///
+    /// Only [`View::IndexedPageItemView`] is supported by this operation.
+    #[xml_struct(flatten)]
+    pub indexed_page_item_view: View,
+
+    /// The folder in which to search, e.g. a contacts folder or the
+    /// `directory` distinguished folder for the global address list.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/parentfolderid>
+    pub parent_folder_id: Option<BaseFolderId>,
+
+    /// The text to search for in names and email addresses.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/querystring-querystringtype>
+    pub query_string: Option<String>,
+}
+
+/// The persona properties which should be included in the response.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/personashape>
+#[derive(Clone, Debug, Default, XmlSerialize)]
+pub struct PersonaShape {
+    #[xml_struct(ns_prefix = "t")]
+    pub base_shape: BaseShape,
+
+    /// A list of properties which should be included in addition to those
+    /// of the base shape, e.g. `persona:EmailAddresses`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/additionalproperties>
+    #[xml_struct(ns_prefix = "t")]
+    pub additional_properties: Option<Vec<PathToElement>>,
+}
+
+/// The contents of a successful response to a [`FindPeople`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/findpeopleresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FindPeopleResponseMessage {
+    /// The personas found, in order of relevance.
+    pub people: People,
+
+    /// The total number of personas matching the search.
+    pub total_number_of_people_in_view: Option<usize>,
+
+    /// The index of the first persona matching the search.
+    pub first_matching_row_index: Option<usize>,
+
+    /// The index of the first persona included in the response.
+    pub first_loaded_row_index: Option<usize>,
+}
+
+/// A list of personas.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/people>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct People {
+    #[serde(rename = "Persona", default)]
+    pub inner: Vec<Persona>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        personas::PersonaPhoneNumber,
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        BasePoint, DistinguishedFolderName, ItemId, Mailbox, ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_find_people() {
+        let find_people = FindPeople {
+            persona_shape: Some(PersonaShape {
+                base_shape: BaseShape::Default,
+                additional_properties: Some(vec![PathToElement::FieldURI {
+                    field_URI: "persona:EmailAddresses".to_string(),
+                }]),
+            }),
+            indexed_page_item_view: View::IndexedPageItemView {
+                max_entries_returned: Some(10),
+                base_point: BasePoint::Beginning,
+                offset: 0,
+            },
+            parent_folder_id: Some(BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Directory,
+                change_key: None,
+                mailbox: None,
+            }),
+            query_string: Some("ali".to_string()),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <FindPeople xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <PersonaShape>
+                <t:BaseShape>Default</t:BaseShape>
+                <t:AdditionalProperties>
+                  <t:FieldURI FieldURI="persona:EmailAddresses"/>
+                </t:AdditionalProperties>
+              </PersonaShape>
+              <IndexedPageItemView MaxEntriesReturned="10" BasePoint="Beginning" Offset="0"/>
+              <ParentFolderId>
+                <t:DistinguishedFolderId Id="directory"></t:DistinguishedFolderId>
+              </ParentFolderId>
+              <QueryString>ali</QueryString>
+            </FindPeople>"#,
+        );
+
+        assert_serialized_content(&find_people, "FindPeople", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_find_people_response() {
+        let content = r#"
+            <m:FindPeopleResponse ResponseClass="Success"
+                                  xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                  xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:People>
+                <t:Persona>
+                  <t:PersonaId Id="AAUQAAAAAAA="/>
+                  <t:PersonaType>Person</t:PersonaType>
+                  <t:DisplayName>Alice Example</t:DisplayName>
+                  <t:GivenName>Alice</t:GivenName>
+                  <t:Surname>Example</t:Surname>
+                  <t:EmailAddress>
+                    <t:Name>Alice Example</t:Name>
+                    <t:EmailAddress>alice@example.com</t:EmailAddress>
+                    <t:RoutingType>SMTP</t:RoutingType>
+                  </t:EmailAddress>
+                  <t:PhoneNumber>
+                    <t:Number>+1 555 0100</t:Number>
+                    <t:Type>Business</t:Type>
+                  </t:PhoneNumber>
+                  <t:RelevanceScore>2147483647</t:RelevanceScore>
+                </t:Persona>
+              </m:People>
+              <m:TotalNumberOfPeopleInView>1</m:TotalNumberOfPeopleInView>
+              <m:FirstMatchingRowIndex>0</m:FirstMatchingRowIndex>
+              <m:FirstLoadedRowIndex>0</m:FirstLoadedRowIndex>
+            </m:FindPeopleResponse>"#;
+
+        let expected = FindPeopleResponse {
+            response_message: ResponseClass::Success(FindPeopleResponseMessage {
+                people: People {
+                    inner: vec![Persona {
+                        persona_id: Some(ItemId {
+                            id: "AAUQAAAAAAA=".to_string(),
+                            change_key: None,
+                        }),
+                        persona_type: Some("Person".to_string()),
+                        display_name: Some("Alice Example".to_string()),
+                        given_name: Some("Alice".to_string()),
+                        surname: Some("Example".to_string()),
+                        email_address: Some(Mailbox {
+                            name: Some("Alice Example".to_string()),
+                            email_address: Some("alice@example.com".to_string()),
+                            routing_type: Some("SMTP".to_string()),
+                            ..Default::default()
+                        }),
+                        phone_number: Some(PersonaPhoneNumber {
+                            number: "+1 555 0100".to_string(),
+                            phone_type: "Business".to_string(),
+                        }),
+                        relevance_score: Some(2147483647),
+                        ..Default::default()
+                    }],
+                },
+                total_number_of_people_in_view: Some(1),
+                first_matching_row_index: Some(0),
+                first_loaded_row_index: Some(0),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
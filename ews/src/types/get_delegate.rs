@@ -0,0 +1,153 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    delegates::{DelegateResponseMessage, UserIds},
+    Mailbox, MESSAGES_NS_URI,
+};
+
+/// A request to retrieve the delegates of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getdelegate-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    DelegateResponseMessage,
+    min_version = Exchange2007_SP1,
+    shape = ResponseClass
+)]
+pub struct GetDelegate {
+    /// Whether to include the permissions of each delegate in the response.
+    #[xml_struct(attribute)]
+    pub include_permissions: bool,
+
+    /// The mailbox whose delegates to retrieve.
+    pub mailbox: Mailbox,
+
+    /// The delegates to retrieve. All delegates are retrieved if omitted.
+    pub user_ids: Option<UserIds>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        delegates::{
+            DelegateFolderPermissionLevel, DelegatePermissions, DelegateResponseMessage,
+            DelegateUserResponseMessage, DeliverMeetingRequests, UserIds,
+        },
+        get_delegate::{GetDelegate, GetDelegateResponse},
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        DelegateUser, Mailbox, ResponseClass, ResponseMessages, UserId,
+    };
+
+    #[test]
+    fn test_serialize_get_delegate() {
+        let get_delegate = GetDelegate {
+            include_permissions: true,
+            mailbox: Mailbox {
+                email_address: Some("owner@example.com".to_string()),
+                ..Default::default()
+            },
+            user_ids: Some(UserIds {
+                inner: vec![UserId {
+                    primary_smtp_address: Some("assistant@example.com".to_string()),
+                    ..Default::default()
+                }],
+            }),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" IncludePermissions="true">
+              <Mailbox>
+                <t:EmailAddress>owner@example.com</t:EmailAddress>
+              </Mailbox>
+              <UserIds>
+                <t:UserId>
+                  <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                </t:UserId>
+              </UserIds>
+            </GetDelegate>"#,
+        );
+
+        assert_serialized_content(&get_delegate, "GetDelegate", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_delegate_response() {
+        let content = r#"<GetDelegateResponse ResponseClass="Success" xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseCode>NoError</ResponseCode>
+                  <ResponseMessages>
+                    <DelegateUserResponseMessageType ResponseClass="Success">
+                      <ResponseCode>NoError</ResponseCode>
+                      <DelegateUser>
+                        <UserId xmlns="http://schemas.microsoft.com/exchange/services/2006/types">
+                          <SID>S-1-5-21-1333220396-2200287332-232816053-1118</SID>
+                          <PrimarySmtpAddress>assistant@example.com</PrimarySmtpAddress>
+                          <DisplayName>Assistant</DisplayName>
+                        </UserId>
+                        <DelegatePermissions xmlns="http://schemas.microsoft.com/exchange/services/2006/types">
+                          <CalendarFolderPermissionLevel>Editor</CalendarFolderPermissionLevel>
+                          <TasksFolderPermissionLevel>Reviewer</TasksFolderPermissionLevel>
+                          <InboxFolderPermissionLevel>None</InboxFolderPermissionLevel>
+                          <ContactsFolderPermissionLevel>Author</ContactsFolderPermissionLevel>
+                          <NotesFolderPermissionLevel>None</NotesFolderPermissionLevel>
+                          <JournalFolderPermissionLevel>Custom</JournalFolderPermissionLevel>
+                        </DelegatePermissions>
+                        <ReceiveCopiesOfMeetingMessages xmlns="http://schemas.microsoft.com/exchange/services/2006/types">true</ReceiveCopiesOfMeetingMessages>
+                        <ViewPrivateItems xmlns="http://schemas.microsoft.com/exchange/services/2006/types">false</ViewPrivateItems>
+                      </DelegateUser>
+                    </DelegateUserResponseMessageType>
+                  </ResponseMessages>
+                  <DeliverMeetingRequests>DelegatesAndMe</DeliverMeetingRequests>
+                </GetDelegateResponse>"#;
+
+        let expected = GetDelegateResponse {
+            response_message: ResponseClass::Success(DelegateResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![ResponseClass::Success(DelegateUserResponseMessage {
+                        delegate_user: Some(DelegateUser {
+                            user_id: UserId {
+                                SID: Some(
+                                    "S-1-5-21-1333220396-2200287332-232816053-1118".to_string(),
+                                ),
+                                primary_smtp_address: Some("assistant@example.com".to_string()),
+                                display_name: Some("Assistant".to_string()),
+                                ..Default::default()
+                            },
+                            delegate_permissions: Some(DelegatePermissions {
+                                calendar_folder_permission_level: Some(
+                                    DelegateFolderPermissionLevel::Editor,
+                                ),
+                                tasks_folder_permission_level: Some(
+                                    DelegateFolderPermissionLevel::Reviewer,
+                                ),
+                                inbox_folder_permission_level: Some(
+                                    DelegateFolderPermissionLevel::None,
+                                ),
+                                contacts_folder_permission_level: Some(
+                                    DelegateFolderPermissionLevel::Author,
+                                ),
+                                notes_folder_permission_level: Some(
+                                    DelegateFolderPermissionLevel::None,
+                                ),
+                                journal_folder_permission_level: Some(
+                                    DelegateFolderPermissionLevel::Custom,
+                                ),
+                            }),
+                            receive_copies_of_meeting_messages: Some(true),
+                            view_private_items: Some(false),
+                        }),
+                    })],
+                },
+                deliver_meeting_requests: Some(DeliverMeetingRequests::DelegatesAndMe),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -32,3 +32,569 @@ pub struct GetFolderResponseMessage {
     /// A collection of the retrieved folders.
     pub folders: Folders,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        permissions::{
+            CalendarPermission, CalendarPermissionLevel, CalendarPermissionReadAccess,
+            CalendarPermissions, PermissionActionType, UnknownEntries,
+        },
+        restrictions::{
+            Constant, ConstantOrPath, ContainmentComparison, ContainmentMode, FieldURIOrConstant,
+            SearchExpression,
+        },
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        BaseFolderIds, BaseShape, CalendarPermissionSet, DistinguishedFolderName,
+        DistinguishedUser, EffectiveRights, ExtendedFieldURI, ExtendedProperty, Folder, FolderId,
+        Mailbox, PathToElement, PropertyType, ResponseClass, ResponseMessages, Restriction,
+        SearchFolderTraversal, SearchParameters, UserId,
+    };
+
+    #[test]
+    fn test_serialize_get_folder_in_other_mailbox() {
+        let get_folder = GetFolder {
+            folder_shape: FolderShape {
+                base_shape: BaseShape::IdOnly,
+                additional_properties: None,
+            },
+            folder_ids: vec![
+                BaseFolderId::DistinguishedFolderId {
+                    id: DistinguishedFolderName::Inbox,
+                    change_key: None,
+                    mailbox: Some(Mailbox {
+                        email_address: Some("shared@example.com".to_string()),
+                        routing_type: Some("SMTP".to_string()),
+                        ..Default::default()
+                    }),
+                },
+                BaseFolderId::DistinguishedFolderId {
+                    id: DistinguishedFolderName::ArchiveRecoverableItemsDeletions,
+                    change_key: None,
+                    mailbox: Some(Mailbox {
+                        email_address: Some("delegator@example.com".to_string()),
+                        ..Default::default()
+                    }),
+                },
+            ],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <FolderShape>
+                <t:BaseShape>IdOnly</t:BaseShape>
+              </FolderShape>
+              <FolderIds>
+                <t:DistinguishedFolderId Id="inbox">
+                  <t:Mailbox>
+                    <t:EmailAddress>shared@example.com</t:EmailAddress>
+                    <t:RoutingType>SMTP</t:RoutingType>
+                  </t:Mailbox>
+                </t:DistinguishedFolderId>
+                <t:DistinguishedFolderId Id="archiverecoverableitemsdeletions">
+                  <t:Mailbox>
+                    <t:EmailAddress>delegator@example.com</t:EmailAddress>
+                  </t:Mailbox>
+                </t:DistinguishedFolderId>
+              </FolderIds>
+            </GetFolder>"#,
+        );
+
+        assert_serialized_content(&get_folder, "GetFolder", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_folder_in_other_mailbox_response() {
+        // A search folder whose search scope is a folder in a delegated
+        // mailbox, referenced by its distinguished name.
+        let content = r#"
+            <m:GetFolderResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                 xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetFolderResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:Folders>
+                    <t:SearchFolder>
+                      <t:FolderId Id="AAMkADAwATM0MDAAMS1i" ChangeKey="AQAAABYAAAAA"/>
+                      <t:SearchParameters Traversal="Shallow">
+                        <t:Restriction>
+                          <t:Exists>
+                            <t:FieldURI FieldURI="item:Categories"/>
+                          </t:Exists>
+                        </t:Restriction>
+                        <t:BaseFolderIds>
+                          <t:DistinguishedFolderId Id="inbox">
+                            <t:Mailbox>
+                              <t:EmailAddress>delegator@example.com</t:EmailAddress>
+                              <t:RoutingType>SMTP</t:RoutingType>
+                            </t:Mailbox>
+                          </t:DistinguishedFolderId>
+                        </t:BaseFolderIds>
+                      </t:SearchParameters>
+                    </t:SearchFolder>
+                  </m:Folders>
+                </m:GetFolderResponseMessage>
+              </m:ResponseMessages>
+            </m:GetFolderResponse>"#;
+
+        let expected = GetFolderResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetFolderResponseMessage {
+                    folders: Folders {
+                        inner: vec![Folder::SearchFolder {
+                            folder_id: Some(FolderId {
+                                id: "AAMkADAwATM0MDAAMS1i".to_string(),
+                                change_key: Some("AQAAABYAAAAA".to_string()),
+                            }),
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: None,
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: None,
+                            unread_count: None,
+                            search_parameters: Some(Box::new(SearchParameters {
+                                traversal: Some(SearchFolderTraversal::Shallow),
+                                restriction: Restriction {
+                                    expression: SearchExpression::Exists {
+                                        path: PathToElement::FieldURI {
+                                            field_URI: "item:Categories".to_string(),
+                                        },
+                                    },
+                                },
+                                base_folder_ids: BaseFolderIds {
+                                    inner: vec![BaseFolderId::DistinguishedFolderId {
+                                        id: DistinguishedFolderName::Inbox,
+                                        change_key: None,
+                                        mailbox: Some(Mailbox {
+                                            email_address: Some(
+                                                "delegator@example.com".to_string(),
+                                            ),
+                                            routing_type: Some("SMTP".to_string()),
+                                            ..Default::default()
+                                        }),
+                                    }],
+                                },
+                            })),
+                        }],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn test_distinguished_folder_name_round_trip() {
+        #[derive(Deserialize)]
+        struct DistinguishedFolderId {
+            #[serde(rename = "@Id")]
+            id: DistinguishedFolderName,
+        }
+
+        for name in [
+            DistinguishedFolderName::Inbox,
+            DistinguishedFolderName::SentItems,
+            DistinguishedFolderName::MsgFolderRoot,
+            DistinguishedFolderName::ArchiveInbox,
+            DistinguishedFolderName::RecoverableItemsDeletions,
+            DistinguishedFolderName::IMContactList,
+            DistinguishedFolderName::ToDoSearch,
+            DistinguishedFolderName::Other("shortnotes".to_string()),
+        ] {
+            let xml = format!(r#"<t:DistinguishedFolderId Id="{}"/>"#, name.as_str());
+            let parsed: DistinguishedFolderId = quick_xml::de::from_str(&xml).unwrap();
+
+            assert_eq!(parsed.id, name);
+        }
+
+        assert_eq!(DistinguishedFolderName::JunkEmail.as_str(), "junkemail");
+    }
+
+    #[test]
+    fn test_deserialize_calendar_folder_permissions() {
+        let content = r#"
+            <m:GetFolderResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                 xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetFolderResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:Folders>
+                    <t:CalendarFolder>
+                      <t:FolderId Id="AAMkADAwATM0MDAAMS1j" ChangeKey="AgAAABYAAAAA"/>
+                      <t:DisplayName>Calendar</t:DisplayName>
+                      <t:EffectiveRights>
+                        <t:CreateAssociated>true</t:CreateAssociated>
+                        <t:CreateContents>true</t:CreateContents>
+                        <t:CreateHierarchy>true</t:CreateHierarchy>
+                        <t:Delete>false</t:Delete>
+                        <t:Modify>true</t:Modify>
+                        <t:Read>true</t:Read>
+                        <t:ViewPrivateItems>true</t:ViewPrivateItems>
+                      </t:EffectiveRights>
+                      <t:PermissionSet>
+                        <t:CalendarPermissions>
+                          <t:CalendarPermission>
+                            <t:UserId>
+                              <t:DistinguishedUser>Default</t:DistinguishedUser>
+                            </t:UserId>
+                            <t:CanCreateItems>false</t:CanCreateItems>
+                            <t:CanCreateSubFolders>false</t:CanCreateSubFolders>
+                            <t:IsFolderOwner>false</t:IsFolderOwner>
+                            <t:IsFolderVisible>false</t:IsFolderVisible>
+                            <t:IsFolderContact>false</t:IsFolderContact>
+                            <t:EditItems>None</t:EditItems>
+                            <t:DeleteItems>None</t:DeleteItems>
+                            <t:ReadItems>TimeOnly</t:ReadItems>
+                            <t:CalendarPermissionLevel>FreeBusyTimeOnly</t:CalendarPermissionLevel>
+                          </t:CalendarPermission>
+                          <t:CalendarPermission>
+                            <t:UserId>
+                              <t:PrimarySmtpAddress>colleague@example.com</t:PrimarySmtpAddress>
+                              <t:DisplayName>Colleague</t:DisplayName>
+                            </t:UserId>
+                            <t:EditItems>All</t:EditItems>
+                            <t:DeleteItems>Owned</t:DeleteItems>
+                            <t:ReadItems>FullDetails</t:ReadItems>
+                            <t:CalendarPermissionLevel>Editor</t:CalendarPermissionLevel>
+                          </t:CalendarPermission>
+                        </t:CalendarPermissions>
+                        <t:UnknownEntries>
+                          <t:UnknownEntry>NT User:S-1-5-21-1234</t:UnknownEntry>
+                        </t:UnknownEntries>
+                      </t:PermissionSet>
+                    </t:CalendarFolder>
+                  </m:Folders>
+                </m:GetFolderResponseMessage>
+              </m:ResponseMessages>
+            </m:GetFolderResponse>"#;
+
+        let expected = GetFolderResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetFolderResponseMessage {
+                    folders: Folders {
+                        inner: vec![Folder::CalendarFolder {
+                            folder_id: Some(FolderId {
+                                id: "AAMkADAwATM0MDAAMS1j".to_string(),
+                                change_key: Some("AgAAABYAAAAA".to_string()),
+                            }),
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: Some("Calendar".to_string()),
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            effective_rights: Some(EffectiveRights {
+                                create_associated: true,
+                                create_contents: true,
+                                create_hierarchy: true,
+                                delete: false,
+                                modify: true,
+                                read: true,
+                                view_private_items: Some(true),
+                            }),
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: Some(CalendarPermissionSet {
+                                calendar_permissions: CalendarPermissions {
+                                    inner: vec![
+                                        CalendarPermission {
+                                            user_id: UserId {
+                                                distinguished_user: Some(
+                                                    DistinguishedUser::Default,
+                                                ),
+                                                ..Default::default()
+                                            },
+                                            can_create_items: Some(false),
+                                            can_create_sub_folders: Some(false),
+                                            is_folder_owner: Some(false),
+                                            is_folder_visible: Some(false),
+                                            is_folder_contact: Some(false),
+                                            edit_items: Some(PermissionActionType::None),
+                                            delete_items: Some(PermissionActionType::None),
+                                            read_items: Some(
+                                                CalendarPermissionReadAccess::TimeOnly,
+                                            ),
+                                            calendar_permission_level:
+                                                CalendarPermissionLevel::FreeBusyTimeOnly,
+                                        },
+                                        CalendarPermission {
+                                            user_id: UserId {
+                                                primary_smtp_address: Some(
+                                                    "colleague@example.com".to_string(),
+                                                ),
+                                                display_name: Some("Colleague".to_string()),
+                                                ..Default::default()
+                                            },
+                                            edit_items: Some(PermissionActionType::All),
+                                            delete_items: Some(PermissionActionType::Owned),
+                                            read_items: Some(
+                                                CalendarPermissionReadAccess::FullDetails,
+                                            ),
+                                            calendar_permission_level:
+                                                CalendarPermissionLevel::Editor,
+                                            ..Default::default()
+                                        },
+                                    ],
+                                },
+                                unknown_entries: Some(UnknownEntries {
+                                    inner: vec!["NT User:S-1-5-21-1234".to_string()],
+                                }),
+                            }),
+                        }],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn test_deserialize_search_folder() {
+        let content = r#"
+            <m:GetFolderResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                 xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetFolderResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:Folders>
+                    <t:SearchFolder>
+                      <t:FolderId Id="AAMkADAwATM0MDAAMS1s" ChangeKey="BwAAABYAAAAA"/>
+                      <t:DisplayName>Unread mail</t:DisplayName>
+                      <t:SearchParameters Traversal="Shallow">
+                        <t:Restriction>
+                          <t:Or>
+                            <t:Not>
+                              <t:Exists>
+                                <t:ExtendedFieldURI PropertyTag="0x0E07" PropertyType="Integer"/>
+                              </t:Exists>
+                            </t:Not>
+                            <t:Contains ContainmentMode="Substring" ContainmentComparison="IgnoreCase">
+                              <t:FieldURI FieldURI="item:Subject"/>
+                              <t:Constant Value="urgent"/>
+                            </t:Contains>
+                            <t:IsNotEqualTo>
+                              <t:FieldURI FieldURI="item:DateTimeCreated"/>
+                              <t:FieldURIOrConstant>
+                                <t:FieldURI FieldURI="item:LastModifiedTime"/>
+                              </t:FieldURIOrConstant>
+                            </t:IsNotEqualTo>
+                          </t:Or>
+                        </t:Restriction>
+                        <t:BaseFolderIds>
+                          <t:FolderId Id="AAMkADAwATM0MDAAMS1i"/>
+                        </t:BaseFolderIds>
+                      </t:SearchParameters>
+                    </t:SearchFolder>
+                  </m:Folders>
+                </m:GetFolderResponseMessage>
+              </m:ResponseMessages>
+            </m:GetFolderResponse>"#;
+
+        let field = |field_uri: &str| PathToElement::FieldURI {
+            field_URI: field_uri.to_string(),
+        };
+
+        let expected = GetFolderResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetFolderResponseMessage {
+                    folders: Folders {
+                        inner: vec![Folder::SearchFolder {
+                            folder_id: Some(FolderId {
+                                id: "AAMkADAwATM0MDAAMS1s".to_string(),
+                                change_key: Some("BwAAABYAAAAA".to_string()),
+                            }),
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: Some("Unread mail".to_string()),
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: None,
+                            unread_count: None,
+                            search_parameters: Some(Box::new(SearchParameters {
+                                traversal: Some(SearchFolderTraversal::Shallow),
+                                restriction: Restriction {
+                                    expression: SearchExpression::Or {
+                                        expressions: vec![
+                                            SearchExpression::Not {
+                                                expression: Box::new(SearchExpression::Exists {
+                                                    path: PathToElement::ExtendedFieldURI {
+                                                        distinguished_property_set_id: None,
+                                                        property_set_id: None,
+                                                        property_tag: Some("0x0E07".to_string()),
+                                                        property_name: None,
+                                                        property_id: None,
+                                                        property_type: PropertyType::Integer,
+                                                    },
+                                                }),
+                                            },
+                                            SearchExpression::Contains {
+                                                containment_mode: Some(ContainmentMode::Substring),
+                                                containment_comparison: Some(
+                                                    ContainmentComparison::IgnoreCase,
+                                                ),
+                                                path: field("item:Subject"),
+                                                constant: Constant {
+                                                    value: "urgent".to_string(),
+                                                },
+                                            },
+                                            SearchExpression::IsNotEqualTo {
+                                                path: field("item:DateTimeCreated"),
+                                                field_URI_or_constant: FieldURIOrConstant {
+                                                    value: ConstantOrPath::Path(field(
+                                                        "item:LastModifiedTime",
+                                                    )),
+                                                },
+                                            },
+                                        ],
+                                    },
+                                },
+                                base_folder_ids: BaseFolderIds {
+                                    inner: vec![BaseFolderId::FolderId {
+                                        id: "AAMkADAwATM0MDAAMS1i".to_string(),
+                                        change_key: None,
+                                    }],
+                                },
+                            })),
+                        }],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn test_serialize_get_folder_with_additional_properties() {
+        let get_folder = GetFolder {
+            folder_shape: FolderShape {
+                base_shape: BaseShape::IdOnly,
+                additional_properties: Some(vec![
+                    PathToElement::FieldURI {
+                        field_URI: "folder:UnreadCount".to_string(),
+                    },
+                    PathToElement::ExtendedFieldURI {
+                        distinguished_property_set_id: None,
+                        property_set_id: None,
+                        property_tag: Some("0x10F4".to_string()),
+                        property_name: None,
+                        property_id: None,
+                        property_type: PropertyType::Boolean,
+                    },
+                ]),
+            },
+            folder_ids: vec![BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Tasks,
+                change_key: None,
+                mailbox: None,
+            }],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <FolderShape>
+                <t:BaseShape>IdOnly</t:BaseShape>
+                <t:AdditionalProperties>
+                  <t:FieldURI FieldURI="folder:UnreadCount"/>
+                  <t:ExtendedFieldURI PropertyTag="0x10F4" PropertyType="Boolean"/>
+                </t:AdditionalProperties>
+              </FolderShape>
+              <FolderIds>
+                <t:DistinguishedFolderId Id="tasks"></t:DistinguishedFolderId>
+              </FolderIds>
+            </GetFolder>"#,
+        );
+
+        assert_serialized_content(&get_folder, "GetFolder", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_folder_additional_properties() {
+        let content = r#"
+            <m:GetFolderResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                 xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetFolderResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:Folders>
+                    <t:TasksFolder>
+                      <t:FolderId Id="AAMkADAwATM0MDAAMS10" ChangeKey="BAAAABYAAAAA"/>
+                      <t:ExtendedProperty>
+                        <t:ExtendedFieldURI PropertyTag="0x10f4" PropertyType="Boolean"/>
+                        <t:Value>false</t:Value>
+                      </t:ExtendedProperty>
+                      <t:ExtendedProperty>
+                        <t:ExtendedFieldURI PropertyTag="0x3601" PropertyType="Integer"/>
+                        <t:Value>1</t:Value>
+                      </t:ExtendedProperty>
+                      <t:UnreadCount>3</t:UnreadCount>
+                    </t:TasksFolder>
+                  </m:Folders>
+                </m:GetFolderResponseMessage>
+              </m:ResponseMessages>
+            </m:GetFolderResponse>"#;
+
+        let extended_property = |tag: &str, property_type, value: &str| ExtendedProperty {
+            extended_field_URI: ExtendedFieldURI {
+                distinguished_property_set_id: None,
+                property_set_id: None,
+                property_tag: Some(tag.to_string()),
+                property_name: None,
+                property_id: None,
+                property_type: Some(property_type),
+            },
+            value: value.to_string(),
+        };
+
+        let folder = Folder::TasksFolder {
+            folder_id: Some(FolderId {
+                id: "AAMkADAwATM0MDAAMS10".to_string(),
+                change_key: Some("BAAAABYAAAAA".to_string()),
+            }),
+            parent_folder_id: None,
+            folder_class: None,
+            display_name: None,
+            total_count: None,
+            child_folder_count: None,
+            extended_property: Some(vec![
+                extended_property("0x10f4", PropertyType::Boolean, "false"),
+                extended_property("0x3601", PropertyType::Integer, "1"),
+            ]),
+            effective_rights: None,
+            policy_tag: None,
+            archive_tag: None,
+            permission_set: None,
+            unread_count: Some(3),
+        };
+
+        assert_eq!(folder.unread_count(), Some(3));
+        assert_eq!(folder.extended_properties().len(), 2);
+        assert_eq!(folder.effective_rights(), None);
+
+        let expected = GetFolderResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetFolderResponseMessage {
+                    folders: Folders {
+                        inner: vec![folder],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
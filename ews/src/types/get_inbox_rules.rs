@@ -0,0 +1,178 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Rule, MESSAGES_NS_URI};
+
+/// A request to retrieve the Inbox rules of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getinboxrules-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetInboxRulesResponseMessage,
+    min_version = Exchange2010,
+    shape = ResponseClass
+)]
+pub struct GetInboxRules {
+    /// The SMTP address of the mailbox whose rules to retrieve, if other than
+    /// the mailbox of the authenticated user.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxsmtpaddress>
+    pub mailbox_smtp_address: Option<String>,
+}
+
+/// The contents of a successful response to a `GetInboxRules` request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getinboxrulesresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetInboxRulesResponseMessage {
+    /// Whether the mailbox contains rules created by Outlook which cannot be
+    /// represented with EWS.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/outlookruleblobexists>
+    pub outlook_rule_blob_exists: Option<bool>,
+
+    /// The rules of the mailbox, in no particular order.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/inboxrules>
+    #[serde(default)]
+    pub inbox_rules: InboxRules,
+}
+
+/// A list of Inbox rules.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/inboxrules>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct InboxRules {
+    #[serde(rename = "Rule", default)]
+    pub inner: Vec<Rule>,
+}
+
+#[cfg(test)]
+mod test {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    use crate::{
+        get_inbox_rules::{
+            GetInboxRules, GetInboxRulesResponse, GetInboxRulesResponseMessage, InboxRules,
+        },
+        inbox_rules::{RuleActions, RulePredicateDateRange, RulePredicates, TargetFolderId},
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ArrayOfEmailAddresses, ArrayOfStrings, BaseFolderId, DateTime, DistinguishedFolderName,
+        Importance, Mailbox, ResponseClass, Rule,
+    };
+
+    #[test]
+    fn test_serialize_get_inbox_rules() {
+        let get_inbox_rules = GetInboxRules {
+            mailbox_smtp_address: Some("user@example.com".to_string()),
+        };
+
+        let expected = r#"<GetInboxRules xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><MailboxSmtpAddress>user@example.com</MailboxSmtpAddress></GetInboxRules>"#;
+
+        assert_serialized_content(&get_inbox_rules, "GetInboxRules", expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_inbox_rules_response() {
+        let content = r#"<GetInboxRulesResponse ResponseClass="Success" xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseCode>NoError</ResponseCode>
+                  <OutlookRuleBlobExists>true</OutlookRuleBlobExists>
+                  <InboxRules>
+                    <t:Rule xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                      <t:RuleId>AQAAAAAAAAA=</t:RuleId>
+                      <t:DisplayName>Newsletters</t:DisplayName>
+                      <t:Priority>1</t:Priority>
+                      <t:IsEnabled>true</t:IsEnabled>
+                      <t:Conditions>
+                        <t:ContainsSubjectStrings>
+                          <t:String>newsletter</t:String>
+                          <t:String>digest</t:String>
+                        </t:ContainsSubjectStrings>
+                        <t:FromAddresses>
+                          <t:Address>
+                            <t:Name>News</t:Name>
+                            <t:EmailAddress>news@example.com</t:EmailAddress>
+                            <t:RoutingType>SMTP</t:RoutingType>
+                          </t:Address>
+                        </t:FromAddresses>
+                        <t:WithinDateRange>
+                          <t:StartDateTime>2024-01-01T00:00:00Z</t:StartDateTime>
+                        </t:WithinDateRange>
+                      </t:Conditions>
+                      <t:Exceptions>
+                        <t:Importance>High</t:Importance>
+                      </t:Exceptions>
+                      <t:Actions>
+                        <t:MoveToFolder>
+                          <t:DistinguishedFolderId Id="junkemail"/>
+                        </t:MoveToFolder>
+                        <t:StopProcessingRules>true</t:StopProcessingRules>
+                      </t:Actions>
+                    </t:Rule>
+                  </InboxRules>
+                </GetInboxRulesResponse>"#;
+
+        let expected = GetInboxRulesResponse {
+            response_message: ResponseClass::Success(GetInboxRulesResponseMessage {
+                outlook_rule_blob_exists: Some(true),
+                inbox_rules: InboxRules {
+                    inner: vec![Rule {
+                        rule_id: Some("AQAAAAAAAAA=".to_string()),
+                        display_name: "Newsletters".to_string(),
+                        priority: 1,
+                        is_enabled: true,
+                        conditions: Some(RulePredicates {
+                            contains_subject_strings: Some(ArrayOfStrings {
+                                inner: vec!["newsletter".to_string(), "digest".to_string()],
+                            }),
+                            from_addresses: Some(ArrayOfEmailAddresses {
+                                inner: vec![Mailbox {
+                                    name: Some("News".to_string()),
+                                    email_address: Some("news@example.com".to_string()),
+                                    routing_type: Some("SMTP".to_string()),
+                                    ..Default::default()
+                                }],
+                            }),
+                            within_date_range: Some(RulePredicateDateRange {
+                                start_date_time: Some(DateTime(
+                                    OffsetDateTime::parse(
+                                        "2024-01-01T00:00:00Z",
+                                        &Iso8601::DEFAULT,
+                                    )
+                                    .unwrap(),
+                                )),
+                                end_date_time: None,
+                            }),
+                            ..Default::default()
+                        }),
+                        exceptions: Some(RulePredicates {
+                            importance: Some(Importance::High),
+                            ..Default::default()
+                        }),
+                        actions: Some(RuleActions {
+                            move_to_folder: Some(TargetFolderId {
+                                folder_id: BaseFolderId::DistinguishedFolderId {
+                                    id: DistinguishedFolderName::JunkEmail,
+                                    change_key: None,
+                                    mailbox: None,
+                                },
+                            }),
+                            stop_processing_rules: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
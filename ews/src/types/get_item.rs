@@ -6,6 +6,7 @@ use ews_proc_macros::operation_response;
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::{BaseItemId, ItemShape, Items, MESSAGES_NS_URI};
 
 /// A request for the properties of one or more Exchange items, e.g. messages,
@@ -14,7 +15,7 @@ use crate::{BaseItemId, ItemShape, Items, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getitem>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(GetItemResponseMessage)]
+#[operation_response(GetItemResponseMessage, manual_version_requirements)]
 pub struct GetItem {
     /// A description of the information to be included in the response for each
     /// item.
@@ -28,8 +29,185 @@ pub struct GetItem {
     pub item_ids: Vec<BaseItemId>,
 }
 
+impl VersionRequirements for GetItem {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        self.item_shape
+            .check_version(version, &format!("{path}/ItemShape"), unsupported);
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        self.item_shape.strip_unsupported(version);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
 pub struct GetItemResponseMessage {
     pub items: Items,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        server_version::UnsupportedElement,
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        BaseShape, Body, BodyType, BodyTypeResponse, ItemId, Message, RealItem, ResponseClass,
+        ResponseMessages,
+    };
+
+    fn get_item_for_preview() -> GetItem {
+        GetItem {
+            item_shape: ItemShape {
+                base_shape: BaseShape::IdOnly,
+                body_type: Some(BodyTypeResponse::HTML),
+                unique_body_type: Some(BodyTypeResponse::Text),
+                filter_html_content: Some(true),
+                convert_html_code_page_to_UTF8: Some(true),
+                block_external_images: Some(true),
+                add_blank_target_to_links: Some(true),
+                maximum_body_size: Some(65536),
+                ..Default::default()
+            },
+            item_ids: vec![BaseItemId::ItemId {
+                id: "AAMkADAwATM0MDAAMS1p".to_string(),
+                change_key: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_serialize_get_item_with_body_options() {
+        let expected = minify_xml(
+            r#"
+            <GetItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <ItemShape>
+                <t:BaseShape>IdOnly</t:BaseShape>
+                <t:BodyType>HTML</t:BodyType>
+                <t:UniqueBodyType>Text</t:UniqueBodyType>
+                <t:FilterHtmlContent>true</t:FilterHtmlContent>
+                <t:ConvertHtmlCodePageToUTF8>true</t:ConvertHtmlCodePageToUTF8>
+                <t:BlockExternalImages>true</t:BlockExternalImages>
+                <t:AddBlankTargetToLinks>true</t:AddBlankTargetToLinks>
+                <t:MaximumBodySize>65536</t:MaximumBodySize>
+              </ItemShape>
+              <ItemIds>
+                <t:ItemId Id="AAMkADAwATM0MDAAMS1p"/>
+              </ItemIds>
+            </GetItem>"#,
+        );
+
+        assert_serialized_content(&get_item_for_preview(), "GetItem", &expected);
+    }
+
+    #[test]
+    fn test_get_item_shape_version_requirements() {
+        let mut get_item = get_item_for_preview();
+
+        let mut unsupported = vec![];
+        get_item.check_version(
+            &ExchangeServerVersion::Exchange2010,
+            "GetItem",
+            &mut unsupported,
+        );
+
+        assert_eq!(
+            unsupported,
+            vec![
+                UnsupportedElement {
+                    path: "GetItem/ItemShape/ConvertHtmlCodePageToUTF8".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2010_SP1,
+                },
+                UnsupportedElement {
+                    path: "GetItem/ItemShape/BlockExternalImages".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2013,
+                },
+                UnsupportedElement {
+                    path: "GetItem/ItemShape/AddBlankTargetToLinks".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2013,
+                },
+                UnsupportedElement {
+                    path: "GetItem/ItemShape/MaximumBodySize".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2013,
+                },
+            ]
+        );
+
+        get_item.strip_unsupported(&ExchangeServerVersion::Exchange2007_SP1);
+
+        assert_eq!(get_item.item_shape.body_type, Some(BodyTypeResponse::HTML));
+        assert_eq!(get_item.item_shape.unique_body_type, None);
+        assert_eq!(get_item.item_shape.filter_html_content, Some(true));
+        assert_eq!(get_item.item_shape.convert_html_code_page_to_UTF8, None);
+        assert_eq!(get_item.item_shape.add_blank_target_to_links, None);
+
+        // Options limiting the content returned are left to be reported.
+        assert_eq!(get_item.item_shape.block_external_images, Some(true));
+        assert_eq!(get_item.item_shape.maximum_body_size, Some(65536));
+    }
+
+    #[test]
+    fn test_deserialize_get_item_bodies() {
+        let content = r#"
+            <m:GetItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                               xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetItemResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:Items>
+                    <t:Message>
+                      <t:ItemId Id="AAMkADAwATM0MDAAMS1p" ChangeKey="CQAAABYAAAAA"/>
+                      <t:Body BodyType="HTML" IsTruncated="true">&lt;p&gt;Thanks!&lt;/p&gt;</t:Body>
+                      <t:UniqueBody BodyType="Text" IsTruncated="false">Thanks!</t:UniqueBody>
+                      <t:TextBody BodyType="Text" IsTruncated="false">Thanks!
+
+On Monday, Alice wrote:
+&gt; Here you go.</t:TextBody>
+                    </t:Message>
+                  </m:Items>
+                </m:GetItemResponseMessage>
+              </m:ResponseMessages>
+            </m:GetItemResponse>"#;
+
+        let expected = GetItemResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetItemResponseMessage {
+                    items: Items {
+                        inner: vec![RealItem::Message(Message {
+                            item_id: Some(ItemId {
+                                id: "AAMkADAwATM0MDAAMS1p".to_string(),
+                                change_key: Some("CQAAABYAAAAA".to_string()),
+                            }),
+                            body: Some(Body {
+                                body_type: BodyType::HTML,
+                                is_truncated: Some(true),
+                                content: Some("<p>Thanks!</p>".to_string()),
+                            }),
+                            unique_body: Some(Body {
+                                body_type: BodyType::Text,
+                                is_truncated: Some(false),
+                                content: Some("Thanks!".to_string()),
+                            }),
+                            text_body: Some(Body {
+                                body_type: BodyType::Text,
+                                is_truncated: Some(false),
+                                content: Some(
+                                    "Thanks!\n\nOn Monday, Alice wrote:\n> Here you go."
+                                        .to_string(),
+                                ),
+                            }),
+                            ..Default::default()
+                        })],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
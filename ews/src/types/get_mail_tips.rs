@@ -0,0 +1,361 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::{
+    de::{self, IntoDeserializer},
+    Deserialize, Deserializer,
+};
+use xml_struct::XmlSerialize;
+
+use crate::{
+    oof::{OofDuration, ReplyBody},
+    ArrayOfRecipients, Mailbox, ResponseMessages, MESSAGES_NS_URI,
+};
+
+/// A request to retrieve information about recipients which is useful before
+/// sending them a message, such as whether they are out of office or whether
+/// the message is too large for them to receive.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getmailtips-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetMailTipsResponseMessage, shape = ResponseClass, min_version = Exchange2010)]
+pub struct GetMailTips {
+    /// The mailbox the message would be sent from.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sendingas>
+    pub sending_as: Mailbox,
+
+    /// The recipients for which to retrieve mail tips.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/recipients-ex15websvcsotherref>
+    pub recipients: ArrayOfRecipients,
+
+    /// The kinds of mail tips to retrieve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailtipsrequested>
+    pub mail_tips_requested: MailTipTypes,
+}
+
+/// The contents of a successful response to a [`GetMailTips`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getmailtipsresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetMailTipsResponseMessage {
+    /// The mail tips of each recipient, in the order of the request.
+    #[serde(default)]
+    pub response_messages: ResponseMessages<MailTipsResponseMessage>,
+}
+
+/// The mail tips of a single recipient.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailtipsresponsemessagetype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MailTipsResponseMessage {
+    pub mail_tips: MailTips,
+}
+
+/// Information about a recipient which is useful before sending them a
+/// message.
+///
+/// Mail tips which were not requested or do not apply to the recipient are
+/// omitted.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailtips>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MailTips {
+    /// The recipient.
+    pub recipient_address: Mailbox,
+
+    /// The mail tips which the server did not retrieve in time.
+    pub pending_mail_tips: Option<MailTipTypes>,
+
+    /// The automatic reply of the recipient, if they are out of office.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/outofoffice>
+    pub out_of_office: Option<OutOfOfficeMailTip>,
+
+    /// Whether the mailbox of the recipient is full.
+    pub mailbox_full: Option<bool>,
+
+    /// A message configured by the administrator for the recipient.
+    pub custom_mail_tip: Option<String>,
+
+    /// The number of members of the recipient, if it is a group.
+    pub total_member_count: Option<u32>,
+
+    /// The number of members of the recipient outside the organization, if it
+    /// is a group.
+    pub external_member_count: Option<u32>,
+
+    /// The largest message the recipient can receive, in bytes.
+    pub max_message_size: Option<u64>,
+
+    /// Whether the recipient does not accept messages from the sender.
+    pub delivery_restricted: Option<bool>,
+
+    /// Whether messages to the recipient require the approval of a moderator.
+    pub is_moderated: Option<bool>,
+
+    /// Whether the recipient does not exist.
+    pub invalid_recipient: Option<bool>,
+}
+
+/// The automatic reply of a recipient who is out of office.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/outofoffice>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutOfOfficeMailTip {
+    pub reply_body: ReplyBody,
+
+    /// The period during which the recipient is out of office, if scheduled.
+    pub duration: Option<OofDuration>,
+}
+
+/// A list of kinds of mail tips.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MailTipTypes(pub Vec<MailTipType>);
+
+impl XmlSerialize for MailTipTypes {
+    /// Serializes the kinds of mail tips as a whitespace-separated list.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.0
+            .iter()
+            .map(|mail_tip| mail_tip.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .serialize_child_nodes(writer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MailTipTypes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        value
+            .split_ascii_whitespace()
+            .map(|mail_tip| mail_tip.parse().map_err(de::Error::custom))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// A kind of mail tip.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailtipsrequested>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub enum MailTipType {
+    /// All kinds of mail tips.
+    All,
+    OutOfOfficeMessage,
+    MailboxFullStatus,
+    CustomMailTip,
+    ExternalMemberCount,
+    TotalMemberCount,
+    MaxMessageSize,
+    DeliveryRestriction,
+    ModerationStatus,
+    InvalidRecipient,
+
+    /// A kind of mail tip not known to this crate, e.g. one introduced in a
+    /// newer version of Exchange.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl MailTipType {
+    /// Gets the representation of the kind of mail tip used in EWS requests.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::All => "All",
+            Self::OutOfOfficeMessage => "OutOfOfficeMessage",
+            Self::MailboxFullStatus => "MailboxFullStatus",
+            Self::CustomMailTip => "CustomMailTip",
+            Self::ExternalMemberCount => "ExternalMemberCount",
+            Self::TotalMemberCount => "TotalMemberCount",
+            Self::MaxMessageSize => "MaxMessageSize",
+            Self::DeliveryRestriction => "DeliveryRestriction",
+            Self::ModerationStatus => "ModerationStatus",
+            Self::InvalidRecipient => "InvalidRecipient",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl std::str::FromStr for MailTipType {
+    type Err = de::value::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(value.into_deserializer())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        Recipient, ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_get_mail_tips() {
+        let get_mail_tips = GetMailTips {
+            sending_as: Mailbox {
+                email_address: Some("sender@example.com".to_string()),
+                ..Default::default()
+            },
+            recipients: ArrayOfRecipients(vec![
+                Recipient {
+                    mailbox: Mailbox {
+                        email_address: Some("away@example.com".to_string()),
+                        ..Default::default()
+                    },
+                },
+                Recipient {
+                    mailbox: Mailbox {
+                        email_address: Some("everyone@example.com".to_string()),
+                        ..Default::default()
+                    },
+                },
+            ]),
+            mail_tips_requested: MailTipTypes(vec![
+                MailTipType::OutOfOfficeMessage,
+                MailTipType::MaxMessageSize,
+                MailTipType::ExternalMemberCount,
+            ]),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetMailTips xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <SendingAs>
+                <t:EmailAddress>sender@example.com</t:EmailAddress>
+              </SendingAs>
+              <Recipients>
+                <t:Mailbox>
+                  <t:EmailAddress>away@example.com</t:EmailAddress>
+                </t:Mailbox>
+                <t:Mailbox>
+                  <t:EmailAddress>everyone@example.com</t:EmailAddress>
+                </t:Mailbox>
+              </Recipients>
+              <MailTipsRequested>OutOfOfficeMessage MaxMessageSize ExternalMemberCount</MailTipsRequested>
+            </GetMailTips>"#,
+        );
+
+        assert_serialized_content(&get_mail_tips, "GetMailTips", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_mail_tips_response() {
+        let content = r#"
+            <m:GetMailTipsResponse ResponseClass="Success"
+                                   xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                   xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:ResponseMessages>
+                <m:MailTipsResponseMessageType ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:MailTips>
+                    <t:RecipientAddress>
+                      <t:Name/>
+                      <t:EmailAddress>away@example.com</t:EmailAddress>
+                      <t:RoutingType>SMTP</t:RoutingType>
+                    </t:RecipientAddress>
+                    <t:OutOfOffice>
+                      <t:ReplyBody>
+                        <t:Message>I am away.</t:Message>
+                      </t:ReplyBody>
+                    </t:OutOfOffice>
+                    <t:MaxMessageSize>36700160</t:MaxMessageSize>
+                  </m:MailTips>
+                </m:MailTipsResponseMessageType>
+                <m:MailTipsResponseMessageType ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:MailTips>
+                    <t:RecipientAddress>
+                      <t:EmailAddress>everyone@example.com</t:EmailAddress>
+                      <t:RoutingType>SMTP</t:RoutingType>
+                    </t:RecipientAddress>
+                    <t:PendingMailTips>ExternalMemberCount Scope</t:PendingMailTips>
+                    <t:MaxMessageSize>36700160</t:MaxMessageSize>
+                  </m:MailTips>
+                </m:MailTipsResponseMessageType>
+              </m:ResponseMessages>
+            </m:GetMailTipsResponse>"#;
+
+        let expected = GetMailTipsResponse {
+            response_message: ResponseClass::Success(GetMailTipsResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![
+                        ResponseClass::Success(MailTipsResponseMessage {
+                            mail_tips: MailTips {
+                                recipient_address: Mailbox {
+                                    name: Some("".to_string()),
+                                    email_address: Some("away@example.com".to_string()),
+                                    routing_type: Some("SMTP".to_string()),
+                                    ..Default::default()
+                                },
+                                pending_mail_tips: None,
+                                out_of_office: Some(OutOfOfficeMailTip {
+                                    reply_body: ReplyBody {
+                                        message: Some("I am away.".to_string()),
+                                    },
+                                    duration: None,
+                                }),
+                                mailbox_full: None,
+                                custom_mail_tip: None,
+                                total_member_count: None,
+                                external_member_count: None,
+                                max_message_size: Some(36700160),
+                                delivery_restricted: None,
+                                is_moderated: None,
+                                invalid_recipient: None,
+                            },
+                        }),
+                        ResponseClass::Success(MailTipsResponseMessage {
+                            mail_tips: MailTips {
+                                recipient_address: Mailbox {
+                                    email_address: Some("everyone@example.com".to_string()),
+                                    routing_type: Some("SMTP".to_string()),
+                                    ..Default::default()
+                                },
+                                pending_mail_tips: Some(MailTipTypes(vec![
+                                    MailTipType::ExternalMemberCount,
+                                    MailTipType::Unknown("Scope".to_string()),
+                                ])),
+                                out_of_office: None,
+                                mailbox_full: None,
+                                custom_mail_tip: None,
+                                total_member_count: None,
+                                external_member_count: None,
+                                max_message_size: Some(36700160),
+                                delivery_restricted: None,
+                                is_moderated: None,
+                                invalid_recipient: None,
+                            },
+                        }),
+                    ],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
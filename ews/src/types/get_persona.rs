@@ -0,0 +1,170 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ItemId, Persona, MESSAGES_NS_URI};
+
+/// A request to retrieve all information about a persona, e.g. one returned by
+/// a [`FindPeople`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getpersona-operation>
+///
+/// [`FindPeople`]: crate::find_people::FindPeople
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetPersonaResponseMessage,
+    shape = ResponseClass,
+    response_name = GetPersonaResponseMessage,
+    min_version = Exchange2013
+)]
+pub struct GetPersona {
+    /// The identifier of the persona.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/personaid>
+    pub persona_id: ItemId,
+}
+
+/// The contents of a successful response to a [`GetPersona`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getpersonaresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetPersonaResponseMessage {
+    pub persona: Persona,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        personas::{AttributedValue, AttributedValues, Attributions, PersonaPhoneNumber},
+        soap::Envelope,
+        test_utils::{assert_serialized_content, minify_xml},
+        Mailbox, ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_get_persona() {
+        let get_persona = GetPersona {
+            persona_id: ItemId {
+                id: "AAUQAAAAAAA=".to_string(),
+                change_key: None,
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetPersona xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <PersonaId Id="AAUQAAAAAAA="/>
+            </GetPersona>"#,
+        );
+
+        assert_serialized_content(&get_persona, "GetPersona", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_persona_response() {
+        let content = br#"<?xml version="1.0" encoding="utf-8"?>
+            <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+              <s:Header/>
+              <s:Body>
+                <GetPersonaResponseMessage ResponseClass="Success"
+                                           xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                           xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                  <ResponseCode>NoError</ResponseCode>
+                  <Persona>
+                    <t:PersonaId Id="AAUQAAAAAAA="/>
+                    <t:DisplayName>Alice Example</t:DisplayName>
+                    <t:DisplayNames>
+                      <t:StringAttributedValue>
+                        <t:Value>Alice Example</t:Value>
+                        <t:Attributions>
+                          <t:Attribution>0</t:Attribution>
+                          <t:Attribution>1</t:Attribution>
+                        </t:Attributions>
+                      </t:StringAttributedValue>
+                    </t:DisplayNames>
+                    <t:Emails1>
+                      <t:EmailAddressAttributedValue>
+                        <t:Value>
+                          <t:Name>Alice Example</t:Name>
+                          <t:EmailAddress>alice@example.com</t:EmailAddress>
+                          <t:RoutingType>SMTP</t:RoutingType>
+                        </t:Value>
+                        <t:Attributions>
+                          <t:Attribution>0</t:Attribution>
+                        </t:Attributions>
+                      </t:EmailAddressAttributedValue>
+                    </t:Emails1>
+                    <t:MobilePhones>
+                      <t:PhoneNumberAttributedValue>
+                        <t:Value>
+                          <t:Number>+1 555 0101</t:Number>
+                          <t:Type>Mobile</t:Type>
+                        </t:Value>
+                        <t:Attributions>
+                          <t:Attribution>1</t:Attribution>
+                        </t:Attributions>
+                      </t:PhoneNumberAttributedValue>
+                    </t:MobilePhones>
+                  </Persona>
+                </GetPersonaResponseMessage>
+              </s:Body>
+            </s:Envelope>"#;
+
+        let envelope: Envelope<GetPersonaResponse> =
+            Envelope::from_xml_document(content).expect("response should deserialize");
+
+        let expected = GetPersonaResponse {
+            response_message: ResponseClass::Success(GetPersonaResponseMessage {
+                persona: Persona {
+                    persona_id: Some(ItemId {
+                        id: "AAUQAAAAAAA=".to_string(),
+                        change_key: None,
+                    }),
+                    display_name: Some("Alice Example".to_string()),
+                    display_names: Some(AttributedValues {
+                        inner: vec![AttributedValue {
+                            value: "Alice Example".to_string(),
+                            attributions: Attributions {
+                                inner: vec!["0".to_string(), "1".to_string()],
+                            },
+                        }],
+                    }),
+                    emails1: Some(AttributedValues {
+                        inner: vec![AttributedValue {
+                            value: Mailbox {
+                                name: Some("Alice Example".to_string()),
+                                email_address: Some("alice@example.com".to_string()),
+                                routing_type: Some("SMTP".to_string()),
+                                ..Default::default()
+                            },
+                            attributions: Attributions {
+                                inner: vec!["0".to_string()],
+                            },
+                        }],
+                    }),
+                    mobile_phones: Some(AttributedValues {
+                        inner: vec![AttributedValue {
+                            value: PersonaPhoneNumber {
+                                number: "+1 555 0101".to_string(),
+                                phone_type: "Mobile".to_string(),
+                            },
+                            attributions: Attributions {
+                                inner: vec!["1".to_string()],
+                            },
+                        }],
+                    }),
+                    ..Default::default()
+                },
+            }),
+        };
+
+        assert_eq!(envelope.body, expected);
+    }
+}
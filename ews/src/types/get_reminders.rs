@@ -0,0 +1,199 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{DateTime, ItemId, MESSAGES_NS_URI};
+
+/// A request to retrieve the reminders of the mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getreminders>
+#[derive(Clone, Debug, Default, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetRemindersResponseMessage, shape = ResponseClass, min_version = Exchange2013)]
+pub struct GetReminders {
+    /// The start of the time window from which to retrieve reminders.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/begintime>
+    pub begin_time: Option<DateTime>,
+
+    /// The end of the time window from which to retrieve reminders.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/endtime>
+    pub end_time: Option<DateTime>,
+
+    /// The maximum number of reminders to retrieve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/maxitems>
+    pub max_items: Option<u32>,
+
+    /// Which reminders to retrieve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/remindertype>
+    pub reminder_type: Option<ReminderType>,
+}
+
+/// Which reminders to retrieve in a [`GetReminders`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/remindertype>
+#[derive(Clone, Copy, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ReminderType {
+    /// All reminders.
+    All,
+
+    /// Reminders which are due and have not been dismissed.
+    Current,
+
+    /// Reminders for events which have already ended.
+    Old,
+}
+
+/// The contents of a successful response to a [`GetReminders`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getremindersresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetRemindersResponseMessage {
+    #[serde(default)]
+    pub reminders: Reminders,
+}
+
+/// A list of reminders.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/reminders>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Reminders {
+    #[serde(rename = "Reminder", default)]
+    pub inner: Vec<Reminder>,
+}
+
+/// A reminder for a calendar item or a task.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/reminder>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Reminder {
+    pub subject: Option<String>,
+
+    pub location: Option<String>,
+
+    /// The time at which the reminder is due.
+    pub reminder_time: DateTime,
+
+    /// The start of the event or task the reminder is for.
+    pub start_date: DateTime,
+
+    /// The end of the event or task the reminder is for.
+    pub end_date: DateTime,
+
+    /// The item the reminder is for.
+    pub item_id: ItemId,
+
+    /// The recurring master of the item the reminder is for, if that item is
+    /// an occurrence of a recurring series.
+    pub recurring_master_item_id: Option<ItemId>,
+
+    /// The kind of item the reminder is for.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/remindergroup>
+    pub reminder_group: Option<ReminderGroup>,
+
+    /// The unique identifier of the reminder.
+    #[serde(rename = "UID")]
+    pub uid: Option<String>,
+}
+
+/// The kind of item a reminder is for.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/remindergroup>
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum ReminderGroup {
+    Calendar,
+    Task,
+}
+
+#[cfg(test)]
+mod test {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        ResponseClass,
+    };
+
+    fn date_time(value: &str) -> DateTime {
+        DateTime(OffsetDateTime::parse(value, &Iso8601::DEFAULT).unwrap())
+    }
+
+    #[test]
+    fn test_serialize_get_reminders() {
+        let get_reminders = GetReminders {
+            begin_time: Some(date_time("2024-07-01T00:00:00Z")),
+            end_time: Some(date_time("2024-07-02T00:00:00Z")),
+            max_items: Some(50),
+            reminder_type: Some(ReminderType::Current),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetReminders xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <BeginTime>2024-07-01T00:00:00.000000000Z</BeginTime>
+              <EndTime>2024-07-02T00:00:00.000000000Z</EndTime>
+              <MaxItems>50</MaxItems>
+              <ReminderType>Current</ReminderType>
+            </GetReminders>"#,
+        );
+
+        assert_serialized_content(&get_reminders, "GetReminders", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_reminders_response() {
+        let content = r#"
+            <m:GetRemindersResponse ResponseClass="Success"
+                                    xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                    xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:Reminders>
+                <t:Reminder>
+                  <t:Subject>Team meeting</t:Subject>
+                  <t:Location>Conference Room 1</t:Location>
+                  <t:ReminderTime>2024-07-01T08:45:00Z</t:ReminderTime>
+                  <t:StartDate>2024-07-01T09:00:00Z</t:StartDate>
+                  <t:EndDate>2024-07-01T10:00:00Z</t:EndDate>
+                  <t:ItemId Id="AAMkADg=" ChangeKey="DwAAABYA"/>
+                  <t:ReminderGroup>Calendar</t:ReminderGroup>
+                  <t:UID>040000008200E00074C5B7101A82E008</t:UID>
+                </t:Reminder>
+              </m:Reminders>
+            </m:GetRemindersResponse>"#;
+
+        let expected = GetRemindersResponse {
+            response_message: ResponseClass::Success(GetRemindersResponseMessage {
+                reminders: Reminders {
+                    inner: vec![Reminder {
+                        subject: Some("Team meeting".to_string()),
+                        location: Some("Conference Room 1".to_string()),
+                        reminder_time: date_time("2024-07-01T08:45:00Z"),
+                        start_date: date_time("2024-07-01T09:00:00Z"),
+                        end_date: date_time("2024-07-01T10:00:00Z"),
+                        item_id: ItemId {
+                            id: "AAMkADg=".to_string(),
+                            change_key: Some("DwAAABYA".to_string()),
+                        },
+                        recurring_master_item_id: None,
+                        reminder_group: Some(ReminderGroup::Calendar),
+                        uid: Some("040000008200E00074C5B7101A82E008".to_string()),
+                    }],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,87 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ArrayOfEmailAddresses, MESSAGES_NS_URI};
+
+/// A request to retrieve the room lists of the organization, i.e. the
+/// distribution lists grouping its conference rooms.
+///
+/// The rooms of each list can be retrieved with [`GetRooms`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getroomlists-operation>
+///
+/// [`GetRooms`]: crate::get_rooms::GetRooms
+#[derive(Clone, Debug, Default, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetRoomListsResponseMessage, shape = ResponseClass, min_version = Exchange2010)]
+pub struct GetRoomLists {}
+
+/// The contents of a successful response to a [`GetRoomLists`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getroomlistsresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetRoomListsResponseMessage {
+    /// The room lists of the organization.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/roomlists>
+    #[serde(default)]
+    pub room_lists: ArrayOfEmailAddresses,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        Mailbox, MailboxType, ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_get_room_lists() {
+        assert_serialized_content(
+            &GetRoomLists {},
+            "GetRoomLists",
+            r#"<GetRoomLists xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"/>"#,
+        );
+    }
+
+    #[test]
+    fn test_deserialize_get_room_lists_response() {
+        let content = r#"
+            <m:GetRoomListsResponse ResponseClass="Success"
+                                    xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                    xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:RoomLists>
+                <t:Address>
+                  <t:Name>Building 1</t:Name>
+                  <t:EmailAddress>building1@example.com</t:EmailAddress>
+                  <t:RoutingType>SMTP</t:RoutingType>
+                  <t:MailboxType>PublicDL</t:MailboxType>
+                </t:Address>
+              </m:RoomLists>
+            </m:GetRoomListsResponse>"#;
+
+        let expected = GetRoomListsResponse {
+            response_message: ResponseClass::Success(GetRoomListsResponseMessage {
+                room_lists: ArrayOfEmailAddresses {
+                    inner: vec![Mailbox {
+                        name: Some("Building 1".to_string()),
+                        email_address: Some("building1@example.com".to_string()),
+                        routing_type: Some("SMTP".to_string()),
+                        mailbox_type: Some(MailboxType::PublicDL),
+                        ..Default::default()
+                    }],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,133 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Mailbox, MESSAGES_NS_URI};
+
+/// A request to retrieve the rooms of a room list.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getrooms-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetRoomsResponseMessage, shape = ResponseClass, min_version = Exchange2010)]
+pub struct GetRooms {
+    /// The room list, e.g. as returned by [`GetRoomLists`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/roomlist>
+    ///
+    /// [`GetRoomLists`]: crate::get_room_lists::GetRoomLists
+    pub room_list: Mailbox,
+}
+
+/// The contents of a successful response to a [`GetRooms`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getroomsresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetRoomsResponseMessage {
+    /// The rooms of the room list.
+    #[serde(default)]
+    pub rooms: Rooms,
+}
+
+/// A list of rooms.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/rooms>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Rooms {
+    #[serde(rename = "Room", default)]
+    pub inner: Vec<Room>,
+}
+
+/// A conference room.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/room>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Room {
+    /// The mailbox of the room, whose availability can be looked up like that
+    /// of any attendee.
+    pub id: Mailbox,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        MailboxType, ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_get_rooms() {
+        let get_rooms = GetRooms {
+            room_list: Mailbox {
+                email_address: Some("building1@example.com".to_string()),
+                ..Default::default()
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetRooms xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <RoomList>
+                <t:EmailAddress>building1@example.com</t:EmailAddress>
+              </RoomList>
+            </GetRooms>"#,
+        );
+
+        assert_serialized_content(&get_rooms, "GetRooms", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_rooms_response() {
+        let content = r#"
+            <m:GetRoomsResponse ResponseClass="Success"
+                                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:Rooms>
+                <t:Room>
+                  <t:Id>
+                    <t:Name>Conference Room 1</t:Name>
+                    <t:EmailAddress>room1@example.com</t:EmailAddress>
+                    <t:RoutingType>SMTP</t:RoutingType>
+                    <t:MailboxType>Mailbox</t:MailboxType>
+                  </t:Id>
+                </t:Room>
+                <t:Room>
+                  <t:Id>
+                    <t:Name>Conference Room 2</t:Name>
+                    <t:EmailAddress>room2@example.com</t:EmailAddress>
+                    <t:RoutingType>SMTP</t:RoutingType>
+                    <t:MailboxType>Mailbox</t:MailboxType>
+                  </t:Id>
+                </t:Room>
+              </m:Rooms>
+            </m:GetRoomsResponse>"#;
+
+        let room = |number: u32| Room {
+            id: Mailbox {
+                name: Some(format!("Conference Room {number}")),
+                email_address: Some(format!("room{number}@example.com")),
+                routing_type: Some("SMTP".to_string()),
+                mailbox_type: Some(MailboxType::Mailbox),
+                ..Default::default()
+            },
+        };
+
+        let expected = GetRoomsResponse {
+            response_message: ResponseClass::Success(GetRoomsResponseMessage {
+                rooms: Rooms {
+                    inner: vec![room(1), room(2)],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,206 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{TimeZoneDefinition, MESSAGES_NS_URI};
+
+/// A request for the definitions of the time zones known to the server.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getservertimezones>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetServerTimeZonesResponseMessage, min_version = Exchange2010)]
+pub struct GetServerTimeZones {
+    /// Whether the response should include the periods and transitions of
+    /// each time zone, or only their identifiers and names.
+    ///
+    /// If omitted, the server will default to including full data.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getservertimezones#attributes>
+    #[xml_struct(attribute)]
+    pub return_full_time_zone_data: Option<bool>,
+
+    /// The identifiers of the time zones to retrieve.
+    ///
+    /// If omitted, all time zones known to the server are returned.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ids>
+    pub ids: Option<TimeZoneIds>,
+}
+
+/// A list of time zone identifiers, e.g. "Pacific Standard Time".
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ids>
+#[derive(Clone, Debug)]
+pub struct TimeZoneIds {
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for TimeZoneIds {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for id in &self.inner {
+            id.serialize_as_element(writer, "t:Id")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A response to a request for time zone definitions within a
+/// [`GetServerTimeZones`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getservertimezonesresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetServerTimeZonesResponseMessage {
+    /// The definitions of the requested time zones.
+    pub time_zone_definitions: TimeZoneDefinitions,
+}
+
+/// A list of time zone definitions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/timezonedefinitions>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct TimeZoneDefinitions {
+    #[serde(rename = "$value", default)]
+    pub inner: Vec<TimeZoneDefinition>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        soap::{Envelope, Header},
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        time_zone::{Period, Periods},
+        Duration, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn test_serialize_get_server_time_zones() {
+        let request = GetServerTimeZones {
+            return_full_time_zone_data: Some(false),
+            ids: Some(TimeZoneIds {
+                inner: vec![
+                    "Pacific Standard Time".to_string(),
+                    "W. Europe Standard Time".to_string(),
+                ],
+            }),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetServerTimeZones xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" ReturnFullTimeZoneData="false">
+              <Ids>
+                <t:Id>Pacific Standard Time</t:Id>
+                <t:Id>W. Europe Standard Time</t:Id>
+              </Ids>
+            </GetServerTimeZones>"#,
+        );
+
+        assert_serialized_content(&request, "GetServerTimeZones", &expected);
+    }
+
+    #[test]
+    fn test_serialize_envelope_with_time_zone_context() {
+        let envelope = Envelope {
+            headers: vec![Header::TimeZoneContext {
+                time_zone_definition: TimeZoneDefinition {
+                    id: Some("W. Europe Standard Time".to_string()),
+                    ..Default::default()
+                },
+            }],
+            body: GetServerTimeZones {
+                return_full_time_zone_data: None,
+                ids: None,
+            },
+        };
+
+        let expected = minify_xml(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <soap:Header>
+                <t:TimeZoneContext>
+                  <t:TimeZoneDefinition Id="W. Europe Standard Time"></t:TimeZoneDefinition>
+                </t:TimeZoneContext>
+              </soap:Header>
+              <soap:Body>
+                <GetServerTimeZones xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"></GetServerTimeZones>
+              </soap:Body>
+            </soap:Envelope>"#,
+        );
+
+        let actual = envelope.as_xml_document().unwrap();
+        assert_eq!(std::str::from_utf8(&actual).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_server_time_zones_response() {
+        let content = r#"
+            <m:GetServerTimeZonesResponse
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetServerTimeZonesResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:TimeZoneDefinitions>
+                    <t:TimeZoneDefinition Name="(UTC) Coordinated Universal Time" Id="UTC">
+                      <t:Periods>
+                        <t:Period Bias="PT0H" Name="Standard" Id="Std" />
+                      </t:Periods>
+                      <t:TransitionsGroups/>
+                      <t:Transitions/>
+                    </t:TimeZoneDefinition>
+                    <t:TimeZoneDefinition Name="(UTC+01:00) Amsterdam, Berlin, Bern, Rome, Stockholm, Vienna" Id="W. Europe Standard Time"/>
+                  </m:TimeZoneDefinitions>
+                </m:GetServerTimeZonesResponseMessage>
+              </m:ResponseMessages>
+            </m:GetServerTimeZonesResponse>"#;
+
+        let expected = GetServerTimeZonesResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(
+                    GetServerTimeZonesResponseMessage {
+                        time_zone_definitions: TimeZoneDefinitions {
+                            inner: vec![
+                                TimeZoneDefinition {
+                                    id: Some("UTC".to_string()),
+                                    name: Some("(UTC) Coordinated Universal Time".to_string()),
+                                    periods: Some(Periods {
+                                        inner: vec![Period {
+                                            bias: Duration(time::Duration::ZERO),
+                                            name: "Standard".to_string(),
+                                            id: "Std".to_string(),
+                                        }],
+                                    }),
+                                    transitions_groups: Some(Default::default()),
+                                    transitions: Some(Default::default()),
+                                },
+                                TimeZoneDefinition {
+                                    id: Some("W. Europe Standard Time".to_string()),
+                                    name: Some(
+                                        "(UTC+01:00) Amsterdam, Berlin, Bern, Rome, Stockholm, Vienna"
+                                            .to_string(),
+                                    ),
+                                    ..Default::default()
+                                },
+                            ],
+                        },
+                    },
+                )],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
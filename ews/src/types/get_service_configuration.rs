@@ -0,0 +1,382 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Mailbox, ResponseMessages, MESSAGES_NS_URI};
+
+/// A request to retrieve the configuration of server features, such as the
+/// limits applying to mail tips.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getserviceconfiguration-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetServiceConfigurationResponseMessage,
+    shape = ResponseClass,
+    min_version = Exchange2010
+)]
+pub struct GetServiceConfiguration {
+    /// The mailbox on behalf of which to retrieve the configuration, if other
+    /// than that of the authenticated user.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/actingas>
+    pub acting_as: Option<Mailbox>,
+
+    /// The configurations to retrieve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/requestedconfiguration>
+    pub requested_configuration: RequestedConfiguration,
+}
+
+/// A list of configurations to retrieve.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RequestedConfiguration {
+    pub inner: Vec<ServiceConfiguration>,
+}
+
+impl XmlSerialize for RequestedConfiguration {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for configuration in &self.inner {
+            configuration.serialize_as_element(writer, "ConfigurationName")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A configuration of server features.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/configurationname>
+#[derive(Clone, Copy, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ServiceConfiguration {
+    MailTips,
+    UnifiedMessagingConfiguration,
+    ProtectionRules,
+}
+
+/// The contents of a successful response to a [`GetServiceConfiguration`]
+/// operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getserviceconfigurationresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetServiceConfigurationResponseMessage {
+    #[serde(default)]
+    pub response_messages: ResponseMessages<ServiceConfigurationResponseMessage>,
+}
+
+/// The requested configurations.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/serviceconfigurationresponsemessagetype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServiceConfigurationResponseMessage {
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailtipsconfiguration>
+    pub mail_tips_configuration: Option<MailTipsConfiguration>,
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/unifiedmessagingconfiguration>
+    pub unified_messaging_configuration: Option<UnifiedMessagingConfiguration>,
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/protectionrulesconfiguration>
+    pub protection_rules_configuration: Option<ProtectionRulesConfiguration>,
+}
+
+/// The configuration of mail tips.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailtipsconfiguration>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MailTipsConfiguration {
+    pub mail_tips_enabled: bool,
+
+    /// The maximum number of recipients in a `GetMailTips` request.
+    pub max_recipients_per_get_mail_tips_request: u32,
+
+    /// The largest message which can be sent, in bytes.
+    pub max_message_size: u64,
+
+    /// The number of recipients above which the sender is warned of a large
+    /// audience.
+    pub large_audience_threshold: u32,
+
+    /// Whether the number of external recipients is shown to the sender.
+    pub show_external_recipient_count: bool,
+
+    /// The domains considered internal to the organization.
+    pub internal_domains: SmtpDomains,
+
+    pub policy_tips_enabled: Option<bool>,
+
+    /// The number of recipients above which the exact number is not shown.
+    pub large_audience_cap: Option<u32>,
+}
+
+/// A list of email domains.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/internaldomains>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct SmtpDomains {
+    #[serde(rename = "Domain", default)]
+    pub inner: Vec<SmtpDomain>,
+}
+
+/// An email domain.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/domain>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct SmtpDomain {
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    /// Whether subdomains of the domain are included.
+    #[serde(rename = "@IncludeSubdomains")]
+    pub include_subdomains: Option<bool>,
+}
+
+/// The configuration of unified messaging.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/unifiedmessagingconfiguration>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UnifiedMessagingConfiguration {
+    pub um_enabled: bool,
+
+    /// The number dialed to play messages on a phone.
+    pub play_on_phone_dial_string: Option<String>,
+
+    pub play_on_phone_enabled: bool,
+}
+
+/// The configuration of the rules protecting messages with rights management.
+///
+/// The conditions of the rules are not currently represented.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/protectionrulesconfiguration>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProtectionRulesConfiguration {
+    /// The number of hours after which the configuration should be retrieved
+    /// again.
+    #[serde(rename = "@RefreshInterval")]
+    pub refresh_interval: u32,
+
+    pub rules: ProtectionRules,
+
+    /// The domains considered internal to the organization.
+    pub internal_domains: SmtpDomains,
+}
+
+/// A list of protection rules.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/rules-protectionrulestype>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ProtectionRules {
+    #[serde(rename = "Rule", default)]
+    pub inner: Vec<ProtectionRule>,
+}
+
+/// A rule protecting messages with rights management.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/rule-protectionruletype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProtectionRule {
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    /// Whether the sender may override the rule.
+    #[serde(rename = "@UserOverridable")]
+    pub user_overridable: bool,
+
+    /// The priority of the rule, the lowest value being evaluated first.
+    #[serde(rename = "@Priority")]
+    pub priority: u32,
+
+    /// The action taken on messages matching the rule.
+    pub action: ProtectionRuleAction,
+}
+
+/// The action of a protection rule.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/action-protectionruleactiontype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ProtectionRuleAction {
+    /// The name of the action, e.g. `RightsProtectMessage`.
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    #[serde(rename = "Argument", default)]
+    pub arguments: Vec<ProtectionRuleArgument>,
+}
+
+/// An argument to a protection rule action.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/argument>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ProtectionRuleArgument {
+    #[serde(rename = "@Name")]
+    pub name: String,
+
+    #[serde(rename = "@Value")]
+    pub value: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_get_service_configuration() {
+        let get_service_configuration = GetServiceConfiguration {
+            acting_as: Some(Mailbox {
+                email_address: Some("sender@example.com".to_string()),
+                routing_type: Some("SMTP".to_string()),
+                ..Default::default()
+            }),
+            requested_configuration: RequestedConfiguration {
+                inner: vec![
+                    ServiceConfiguration::MailTips,
+                    ServiceConfiguration::UnifiedMessagingConfiguration,
+                    ServiceConfiguration::ProtectionRules,
+                ],
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetServiceConfiguration xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <ActingAs>
+                <t:EmailAddress>sender@example.com</t:EmailAddress>
+                <t:RoutingType>SMTP</t:RoutingType>
+              </ActingAs>
+              <RequestedConfiguration>
+                <ConfigurationName>MailTips</ConfigurationName>
+                <ConfigurationName>UnifiedMessagingConfiguration</ConfigurationName>
+                <ConfigurationName>ProtectionRules</ConfigurationName>
+              </RequestedConfiguration>
+            </GetServiceConfiguration>"#,
+        );
+
+        assert_serialized_content(
+            &get_service_configuration,
+            "GetServiceConfiguration",
+            &expected,
+        );
+    }
+
+    #[test]
+    fn test_deserialize_get_service_configuration_response() {
+        let content = r#"
+            <m:GetServiceConfigurationResponse ResponseClass="Success"
+                                               xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                               xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:ResponseMessages>
+                <m:ServiceConfigurationResponseMessageType ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:MailTipsConfiguration>
+                    <t:MailTipsEnabled>true</t:MailTipsEnabled>
+                    <t:MaxRecipientsPerGetMailTipsRequest>50</t:MaxRecipientsPerGetMailTipsRequest>
+                    <t:MaxMessageSize>10485760</t:MaxMessageSize>
+                    <t:LargeAudienceThreshold>25</t:LargeAudienceThreshold>
+                    <t:ShowExternalRecipientCount>true</t:ShowExternalRecipientCount>
+                    <t:InternalDomains>
+                      <t:Domain Name="example.com" IncludeSubdomains="false"/>
+                    </t:InternalDomains>
+                  </m:MailTipsConfiguration>
+                  <m:UnifiedMessagingConfiguration>
+                    <t:UmEnabled>false</t:UmEnabled>
+                    <t:PlayOnPhoneEnabled>false</t:PlayOnPhoneEnabled>
+                  </m:UnifiedMessagingConfiguration>
+                  <m:ProtectionRulesConfiguration RefreshInterval="24">
+                    <t:Rules>
+                      <t:Rule Name="Confidential" UserOverridable="true" Priority="1">
+                        <t:Condition>
+                          <t:AllInternal/>
+                        </t:Condition>
+                        <t:Action Name="RightsProtectMessage">
+                          <t:Argument Name="TemplateId" Value="cf5cf348-a8d7-40d5-91ef-a600b88a395d"/>
+                        </t:Action>
+                      </t:Rule>
+                    </t:Rules>
+                    <t:InternalDomains>
+                      <t:Domain Name="example.com"/>
+                    </t:InternalDomains>
+                  </m:ProtectionRulesConfiguration>
+                </m:ServiceConfigurationResponseMessageType>
+              </m:ResponseMessages>
+            </m:GetServiceConfigurationResponse>"#;
+
+        let expected = GetServiceConfigurationResponse {
+            response_message: ResponseClass::Success(GetServiceConfigurationResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![ResponseClass::Success(
+                        ServiceConfigurationResponseMessage {
+                            mail_tips_configuration: Some(MailTipsConfiguration {
+                                mail_tips_enabled: true,
+                                max_recipients_per_get_mail_tips_request: 50,
+                                max_message_size: 10485760,
+                                large_audience_threshold: 25,
+                                show_external_recipient_count: true,
+                                internal_domains: SmtpDomains {
+                                    inner: vec![SmtpDomain {
+                                        name: "example.com".to_string(),
+                                        include_subdomains: Some(false),
+                                    }],
+                                },
+                                policy_tips_enabled: None,
+                                large_audience_cap: None,
+                            }),
+                            unified_messaging_configuration: Some(UnifiedMessagingConfiguration {
+                                um_enabled: false,
+                                play_on_phone_dial_string: None,
+                                play_on_phone_enabled: false,
+                            }),
+                            protection_rules_configuration: Some(ProtectionRulesConfiguration {
+                                refresh_interval: 24,
+                                rules: ProtectionRules {
+                                    inner: vec![ProtectionRule {
+                                        name: "Confidential".to_string(),
+                                        user_overridable: true,
+                                        priority: 1,
+                                        action: ProtectionRuleAction {
+                                            name: "RightsProtectMessage".to_string(),
+                                            arguments: vec![ProtectionRuleArgument {
+                                                name: "TemplateId".to_string(),
+                                                value: "cf5cf348-a8d7-40d5-91ef-a600b88a395d"
+                                                    .to_string(),
+                                            }],
+                                        },
+                                    }],
+                                },
+                                internal_domains: SmtpDomains {
+                                    inner: vec![SmtpDomain {
+                                        name: "example.com".to_string(),
+                                        include_subdomains: None,
+                                    }],
+                                },
+                            }),
+                        },
+                    )],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,159 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    user_configuration::UserConfigurationProperties, UserConfiguration, UserConfigurationName,
+    MESSAGES_NS_URI,
+};
+
+/// A request to retrieve a user configuration object from a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserconfiguration-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetUserConfigurationResponseMessage, min_version = Exchange2010)]
+pub struct GetUserConfiguration {
+    /// The name of the configuration object and the folder containing it.
+    pub user_configuration_name: UserConfigurationName,
+
+    /// The parts of the configuration object to retrieve.
+    pub user_configuration_properties: UserConfigurationProperties,
+}
+
+/// A response to a request for a user configuration object.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserconfigurationresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserConfigurationResponseMessage {
+    /// The requested parts of the configuration object.
+    pub user_configuration: UserConfiguration,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        user_configuration::{
+            Dictionary, DictionaryEntry, DictionaryObject, DictionaryObjectType,
+            UserConfigurationProperty,
+        },
+        Base64Binary, BaseFolderId, CategoryList, ItemId, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn test_serialize_get_user_configuration() {
+        let get_user_configuration = GetUserConfiguration {
+            user_configuration_name: CategoryList::configuration_name(),
+            user_configuration_properties: UserConfigurationProperties(vec![
+                UserConfigurationProperty::Id,
+                UserConfigurationProperty::XmlData,
+            ]),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetUserConfiguration xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <UserConfigurationName Name="CategoryList">
+                <t:DistinguishedFolderId Id="calendar"></t:DistinguishedFolderId>
+              </UserConfigurationName>
+              <UserConfigurationProperties>Id XmlData</UserConfigurationProperties>
+            </GetUserConfiguration>"#,
+        );
+
+        assert_serialized_content(&get_user_configuration, "GetUserConfiguration", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_user_configuration_response() {
+        let content = r#"
+            <m:GetUserConfigurationResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                            xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:GetUserConfigurationResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:UserConfiguration>
+                    <t:UserConfigurationName Name="Settings">
+                      <t:FolderId Id="AAMkADAwATM0MDAAMS1k" ChangeKey="AQAAABYAAAAA"/>
+                    </t:UserConfigurationName>
+                    <t:ItemId Id="AAMkADAwATM0MDAAMS1l" ChangeKey="CQAAABYAAAAA"/>
+                    <t:Dictionary>
+                      <t:DictionaryEntry>
+                        <t:DictionaryKey>
+                          <t:Type>String</t:Type>
+                          <t:Value>Languages</t:Value>
+                        </t:DictionaryKey>
+                        <t:DictionaryValue>
+                          <t:Type>StringArray</t:Type>
+                          <t:Value>en</t:Value>
+                          <t:Value>fr</t:Value>
+                        </t:DictionaryValue>
+                      </t:DictionaryEntry>
+                      <t:DictionaryEntry>
+                        <t:DictionaryKey>
+                          <t:Type>String</t:Type>
+                          <t:Value>Unset</t:Value>
+                        </t:DictionaryKey>
+                      </t:DictionaryEntry>
+                    </t:Dictionary>
+                    <t:BinaryData>AQID
+                      BAU=</t:BinaryData>
+                  </m:UserConfiguration>
+                </m:GetUserConfigurationResponseMessage>
+              </m:ResponseMessages>
+            </m:GetUserConfigurationResponse>"#;
+
+        let expected = GetUserConfigurationResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(
+                    GetUserConfigurationResponseMessage {
+                        user_configuration: UserConfiguration {
+                            user_configuration_name: UserConfigurationName {
+                                name: "Settings".to_string(),
+                                folder_id: BaseFolderId::FolderId {
+                                    id: "AAMkADAwATM0MDAAMS1k".to_string(),
+                                    change_key: Some("AQAAABYAAAAA".to_string()),
+                                },
+                            },
+                            item_id: Some(ItemId {
+                                id: "AAMkADAwATM0MDAAMS1l".to_string(),
+                                change_key: Some("CQAAABYAAAAA".to_string()),
+                            }),
+                            dictionary: Some(Dictionary {
+                                inner: vec![
+                                    DictionaryEntry {
+                                        dictionary_key: DictionaryObject {
+                                            object_type: DictionaryObjectType::String,
+                                            values: vec!["Languages".to_string()],
+                                        },
+                                        dictionary_value: Some(DictionaryObject {
+                                            object_type: DictionaryObjectType::StringArray,
+                                            values: vec!["en".to_string(), "fr".to_string()],
+                                        }),
+                                    },
+                                    DictionaryEntry {
+                                        dictionary_key: DictionaryObject {
+                                            object_type: DictionaryObjectType::String,
+                                            values: vec!["Unset".to_string()],
+                                        },
+                                        dictionary_value: None,
+                                    },
+                                ],
+                            }),
+                            xml_data: None,
+                            binary_data: Some(Base64Binary(vec![1, 2, 3, 4, 5])),
+                        },
+                    },
+                )],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,171 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{oof::ExternalAudience, EmailAddress, UserOofSettings, MESSAGES_NS_URI};
+
+/// A request to retrieve the out-of-office settings of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuseroofsettings-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetUserOofSettingsResponseMessage,
+    shape = ResponseMessage,
+    name = GetUserOofSettingsRequest
+)]
+pub struct GetUserOofSettings {
+    /// The mailbox whose settings to retrieve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailbox-availability>
+    #[xml_struct(ns_prefix = "t")]
+    pub mailbox: EmailAddress,
+}
+
+/// The contents of a successful response to a `GetUserOofSettingsRequest`.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuseroofsettingsresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserOofSettingsResponseMessage {
+    /// The out-of-office settings of the mailbox.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/oofsettings>
+    pub oof_settings: Option<UserOofSettings>,
+
+    /// The senders outside the organization to whom the mailbox is allowed to
+    /// send automatic replies.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/allowexternaloof>
+    pub allow_external_oof: Option<ExternalAudience>,
+}
+
+#[cfg(test)]
+mod test {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    use crate::{
+        get_user_oof_settings::{
+            GetUserOofSettings, GetUserOofSettingsResponse, GetUserOofSettingsResponseMessage,
+        },
+        oof::{ExternalAudience, OofDuration, OofState, ReplyBody},
+        response::{ResponseCode, ResponseError},
+        soap::Envelope,
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        DateTime, EmailAddress, ResponseClass, UserOofSettings,
+    };
+
+    #[test]
+    fn test_serialize_get_user_oof_settings() {
+        let get_user_oof_settings = GetUserOofSettings {
+            mailbox: EmailAddress {
+                address: "user@example.com".to_string(),
+                ..Default::default()
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetUserOofSettingsRequest xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <t:Mailbox>
+                <t:Address>user@example.com</t:Address>
+              </t:Mailbox>
+            </GetUserOofSettingsRequest>"#,
+        );
+
+        assert_serialized_content(
+            &get_user_oof_settings,
+            "GetUserOofSettingsRequest",
+            &expected,
+        );
+    }
+
+    #[test]
+    fn test_deserialize_get_user_oof_settings_response() {
+        let content = r#"<?xml version="1.0" encoding="utf-8"?>
+            <s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/">
+              <s:Header>
+                <h:ServerVersionInfo MajorVersion="15" MinorVersion="20" MajorBuildNumber="7892" MinorBuildNumber="23" Version="V2018_01_08" xmlns:h="http://schemas.microsoft.com/exchange/services/2006/types" xmlns="http://schemas.microsoft.com/exchange/services/2006/types"/>
+              </s:Header>
+              <s:Body>
+                <GetUserOofSettingsResponse xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseMessage ResponseClass="Success">
+                    <ResponseCode>NoError</ResponseCode>
+                  </ResponseMessage>
+                  <OofSettings xmlns="http://schemas.microsoft.com/exchange/services/2006/types">
+                    <OofState>Scheduled</OofState>
+                    <ExternalAudience>Known</ExternalAudience>
+                    <Duration>
+                      <StartTime>2024-07-01T08:00:00Z</StartTime>
+                      <EndTime>2024-07-15T17:00:00Z</EndTime>
+                    </Duration>
+                    <InternalReply>
+                      <Message>I am on vacation.</Message>
+                    </InternalReply>
+                    <ExternalReply>
+                      <Message/>
+                    </ExternalReply>
+                  </OofSettings>
+                  <AllowExternalOof>All</AllowExternalOof>
+                </GetUserOofSettingsResponse>
+              </s:Body>
+            </s:Envelope>"#;
+
+        let envelope: Envelope<GetUserOofSettingsResponse> =
+            Envelope::from_xml_document(content.as_bytes())
+                .expect("deserialization should succeed");
+
+        let expected = GetUserOofSettingsResponse {
+            response_message: ResponseClass::Success(GetUserOofSettingsResponseMessage {
+                oof_settings: Some(UserOofSettings {
+                    oof_state: OofState::Scheduled,
+                    external_audience: ExternalAudience::Known,
+                    duration: Some(OofDuration {
+                        start_time: DateTime(
+                            OffsetDateTime::parse("2024-07-01T08:00:00Z", &Iso8601::DEFAULT)
+                                .unwrap(),
+                        ),
+                        end_time: DateTime(
+                            OffsetDateTime::parse("2024-07-15T17:00:00Z", &Iso8601::DEFAULT)
+                                .unwrap(),
+                        ),
+                    }),
+                    internal_reply: Some(ReplyBody {
+                        message: Some("I am on vacation.".to_string()),
+                    }),
+                    external_reply: Some(ReplyBody {
+                        message: Some(String::new()),
+                    }),
+                }),
+                allow_external_oof: Some(ExternalAudience::All),
+            }),
+        };
+
+        assert_eq!(envelope.body, expected);
+    }
+
+    #[test]
+    fn test_deserialize_get_user_oof_settings_error() {
+        let content = r#"<GetUserOofSettingsResponse xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseMessage ResponseClass="Error">
+                    <MessageText>Microsoft.Exchange.Data.Storage.AccessDeniedException</MessageText>
+                    <ResponseCode>ErrorAccessDenied</ResponseCode>
+                    <DescriptiveLinkKey>0</DescriptiveLinkKey>
+                  </ResponseMessage>
+                </GetUserOofSettingsResponse>"#;
+
+        let expected = GetUserOofSettingsResponse {
+            response_message: ResponseClass::Error(ResponseError {
+                message_text: "Microsoft.Exchange.Data.Storage.AccessDeniedException".to_string(),
+                response_code: ResponseCode::ErrorAccessDenied,
+                message_xml: None,
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
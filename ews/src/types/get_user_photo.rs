@@ -0,0 +1,187 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    response::{ResponseCode, ResponseError},
+    Base64Binary, ResponseClass, MESSAGES_NS_URI,
+};
+
+/// A request to retrieve the photo of a user.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserphoto-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetUserPhotoResponseMessage, shape = ResponseClass, min_version = Exchange2013)]
+pub struct GetUserPhoto {
+    /// The SMTP address of the user.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/email-string>
+    pub email: String,
+
+    /// The dimensions of the photo to retrieve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sizerequested>
+    pub size_requested: UserPhotoSize,
+}
+
+/// The dimensions of a user photo, in pixels.
+///
+/// The server returns the largest photo it has which does not exceed the
+/// requested size.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sizerequested>
+#[derive(Clone, Copy, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum UserPhotoSize {
+    HR48x48,
+    HR64x64,
+    HR96x96,
+    HR120x120,
+    HR240x240,
+    HR360x360,
+    HR432x432,
+    HR504x504,
+    HR648x648,
+}
+
+/// The contents of a successful response to a [`GetUserPhoto`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserphotoresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserPhotoResponseMessage {
+    /// Whether the photo has changed since it was last retrieved.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/haschanged>
+    pub has_changed: bool,
+
+    /// The image data of the photo, usually JPEG.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/picturedata>
+    pub picture_data: Option<Base64Binary>,
+}
+
+/// The photo of a user, as retrieved by a [`GetUserPhoto`] operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UserPhoto {
+    /// The user has a photo.
+    Photo {
+        /// The image data of the photo, usually JPEG.
+        data: Vec<u8>,
+
+        /// Whether the photo has changed since it was last retrieved.
+        has_changed: bool,
+    },
+
+    /// The user has no photo.
+    NoPhoto,
+}
+
+impl GetUserPhotoResponse {
+    /// Gets the photo from the response.
+    ///
+    /// A user without a photo is reported by the server as an error with the
+    /// [`ResponseCode::ErrorItemNotFound`] response code, which this returns
+    /// as [`UserPhoto::NoPhoto`] rather than as an error.
+    pub fn into_user_photo(self) -> Result<UserPhoto, ResponseError> {
+        match self.response_message {
+            ResponseClass::Success(message) | ResponseClass::Warning(message) => {
+                Ok(match message.picture_data {
+                    Some(Base64Binary(data)) if !data.is_empty() => UserPhoto::Photo {
+                        data,
+                        has_changed: message.has_changed,
+                    },
+                    _ => UserPhoto::NoPhoto,
+                })
+            }
+            ResponseClass::Error(err) if err.response_code == ResponseCode::ErrorItemNotFound => {
+                Ok(UserPhoto::NoPhoto)
+            }
+            ResponseClass::Error(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::{assert_serialized_content, minify_xml};
+
+    #[test]
+    fn test_serialize_get_user_photo() {
+        let get_user_photo = GetUserPhoto {
+            email: "alice@example.com".to_string(),
+            size_requested: UserPhotoSize::HR96x96,
+        };
+
+        let expected = minify_xml(
+            r#"
+            <GetUserPhoto xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <Email>alice@example.com</Email>
+              <SizeRequested>HR96x96</SizeRequested>
+            </GetUserPhoto>"#,
+        );
+
+        assert_serialized_content(&get_user_photo, "GetUserPhoto", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_user_photo() {
+        let content = r#"
+            <m:GetUserPhotoResponse ResponseClass="Success"
+                                    xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:HasChanged>true</m:HasChanged>
+              <m:PictureData>/9j/4AAQ</m:PictureData>
+            </m:GetUserPhotoResponse>"#;
+
+        let response: GetUserPhotoResponse = quick_xml::de::from_str(content).unwrap();
+
+        assert_eq!(
+            response.into_user_photo().unwrap(),
+            UserPhoto::Photo {
+                data: vec![0xff, 0xd8, 0xff, 0xe0, 0x00, 0x10],
+                has_changed: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_no_user_photo() {
+        let content = r#"
+            <m:GetUserPhotoResponse ResponseClass="Error"
+                                    xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <m:MessageText>The specified object was not found in the store.</m:MessageText>
+              <m:ResponseCode>ErrorItemNotFound</m:ResponseCode>
+              <m:DescriptiveLinkKey>0</m:DescriptiveLinkKey>
+              <m:HasChanged>false</m:HasChanged>
+            </m:GetUserPhotoResponse>"#;
+
+        let response: GetUserPhotoResponse = quick_xml::de::from_str(content).unwrap();
+
+        assert_eq!(response.into_user_photo().unwrap(), UserPhoto::NoPhoto);
+    }
+
+    #[test]
+    fn test_deserialize_user_photo_error() {
+        let content = r#"
+            <m:GetUserPhotoResponse ResponseClass="Error"
+                                    xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <m:MessageText>The requested photo size is invalid.</m:MessageText>
+              <m:ResponseCode>ErrorInvalidPhotoSize</m:ResponseCode>
+              <m:DescriptiveLinkKey>0</m:DescriptiveLinkKey>
+            </m:GetUserPhotoResponse>"#;
+
+        let response: GetUserPhotoResponse = quick_xml::de::from_str(content).unwrap();
+
+        assert_eq!(
+            response.into_user_photo().unwrap_err().response_code,
+            ResponseCode::ErrorInvalidPhotoSize
+        );
+    }
+}
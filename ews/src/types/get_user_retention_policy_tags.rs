@@ -0,0 +1,304 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::{
+    de::{self, IntoDeserializer},
+    Deserialize, Deserializer,
+};
+use xml_struct::XmlSerialize;
+
+use crate::MESSAGES_NS_URI;
+
+/// A request to retrieve the retention and archive policy tags available to
+/// the authenticated user.
+///
+/// The retention ID of a tag can be applied to an item or folder through the
+/// `PolicyTag` or `ArchiveTag` property, e.g. in an `UpdateItem` operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserretentionpolicytags-operation>
+#[derive(Clone, Debug, Default, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetUserRetentionPolicyTagsResponseMessage,
+    shape = ResponseClass,
+    min_version = Exchange2013
+)]
+pub struct GetUserRetentionPolicyTags {}
+
+/// The contents of a successful response to a [`GetUserRetentionPolicyTags`]
+/// operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserretentionpolicytagsresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserRetentionPolicyTagsResponseMessage {
+    /// The policy tags available to the user.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/retentionpolicytags>
+    pub retention_policy_tags: RetentionPolicyTags,
+}
+
+/// A list of retention and archive policy tags.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct RetentionPolicyTags {
+    #[serde(rename = "RetentionPolicyTag", default)]
+    pub inner: Vec<RetentionPolicyTag>,
+}
+
+/// A retention or archive policy tag.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/retentionpolicytag>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RetentionPolicyTag {
+    /// The name of the tag.
+    pub display_name: String,
+
+    /// The GUID identifying the tag.
+    pub retention_id: String,
+
+    /// The number of days after which the action of the tag is taken.
+    pub retention_period: u32,
+
+    /// The kind of folder to which the tag applies.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/type-elcfoldertype>
+    #[serde(rename = "Type", deserialize_with = "deserialize_from_str")]
+    pub folder_type: ElcFolderType,
+
+    /// The action taken on items once the retention period has passed.
+    #[serde(deserialize_with = "deserialize_from_str")]
+    pub retention_action: RetentionAction,
+
+    /// A description of the tag.
+    pub description: Option<String>,
+
+    /// Whether the tag is shown to the user.
+    pub is_visible: bool,
+
+    /// Whether the user has opted into the tag.
+    pub opted_into: bool,
+
+    /// Whether the tag is an archive tag, moving items to the archive mailbox,
+    /// rather than a retention tag.
+    pub is_archive: bool,
+}
+
+/// The kind of folder to which a policy tag applies.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/type-elcfoldertype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub enum ElcFolderType {
+    Calendar,
+    Contacts,
+    DeletedItems,
+    Drafts,
+    Inbox,
+    JunkEmail,
+    Journal,
+    Notes,
+    Outbox,
+    SentItems,
+    Tasks,
+
+    /// The default tag of the mailbox, applying to all folders without a
+    /// more specific tag.
+    All,
+
+    ManagedCustomFolder,
+    RssSubscriptions,
+    SyncIssues,
+    ConversationHistory,
+
+    /// A tag which the user may apply to items and folders.
+    Personal,
+
+    RecoverableItems,
+    NonIpmRoot,
+
+    /// A kind of folder not known to this crate, e.g. one introduced in a
+    /// newer version of Exchange.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::str::FromStr for ElcFolderType {
+    type Err = de::value::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(value.into_deserializer())
+    }
+}
+
+/// The action taken on items once the retention period of their policy tag
+/// has passed.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/retentionaction>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub enum RetentionAction {
+    None,
+    MoveToDeletedItems,
+    MoveToFolder,
+    DeleteAndAllowRecovery,
+    PermanentlyDelete,
+    MarkAsPastRetentionLimit,
+    MoveToArchive,
+
+    /// An action not known to this crate, e.g. one introduced in a newer
+    /// version of Exchange.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::str::FromStr for RetentionAction {
+    type Err = de::value::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(value.into_deserializer())
+    }
+}
+
+/// Deserializes the text content of an element through [`FromStr`].
+///
+/// The untagged fallback variants of [`ElcFolderType`] and [`RetentionAction`]
+/// can only be matched against a plain string, which quick-xml does not
+/// provide when deserializing element content directly.
+///
+/// [`FromStr`]: std::str::FromStr
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr<Err = de::value::Error>,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_get_user_retention_policy_tags() {
+        assert_serialized_content(
+            &GetUserRetentionPolicyTags {},
+            "GetUserRetentionPolicyTags",
+            r#"<GetUserRetentionPolicyTags xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"/>"#,
+        );
+    }
+
+    #[test]
+    fn test_deserialize_get_user_retention_policy_tags_response() {
+        let content = r#"
+            <m:GetUserRetentionPolicyTagsResponse ResponseClass="Success"
+                                                  xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                                  xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:RetentionPolicyTags>
+                <t:RetentionPolicyTag>
+                  <t:DisplayName>Default 2 year move to archive</t:DisplayName>
+                  <t:RetentionId>1f2e3d4c-5b6a-4978-8695-a4b3c2d1e0f9</t:RetentionId>
+                  <t:RetentionPeriod>730</t:RetentionPeriod>
+                  <t:Type>All</t:Type>
+                  <t:RetentionAction>MoveToArchive</t:RetentionAction>
+                  <t:IsVisible>true</t:IsVisible>
+                  <t:OptedInto>false</t:OptedInto>
+                  <t:IsArchive>true</t:IsArchive>
+                </t:RetentionPolicyTag>
+                <t:RetentionPolicyTag>
+                  <t:DisplayName>1 Week Delete</t:DisplayName>
+                  <t:RetentionId>0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d</t:RetentionId>
+                  <t:RetentionPeriod>7</t:RetentionPeriod>
+                  <t:Type>Personal</t:Type>
+                  <t:RetentionAction>DeleteAndAllowRecovery</t:RetentionAction>
+                  <t:Description>Deleted after a week.</t:Description>
+                  <t:IsVisible>true</t:IsVisible>
+                  <t:OptedInto>false</t:OptedInto>
+                  <t:IsArchive>false</t:IsArchive>
+                </t:RetentionPolicyTag>
+              </m:RetentionPolicyTags>
+            </m:GetUserRetentionPolicyTagsResponse>"#;
+
+        let expected = GetUserRetentionPolicyTagsResponse {
+            response_message: ResponseClass::Success(GetUserRetentionPolicyTagsResponseMessage {
+                retention_policy_tags: RetentionPolicyTags {
+                    inner: vec![
+                        RetentionPolicyTag {
+                            display_name: "Default 2 year move to archive".to_string(),
+                            retention_id: "1f2e3d4c-5b6a-4978-8695-a4b3c2d1e0f9".to_string(),
+                            retention_period: 730,
+                            folder_type: ElcFolderType::All,
+                            retention_action: RetentionAction::MoveToArchive,
+                            description: None,
+                            is_visible: true,
+                            opted_into: false,
+                            is_archive: true,
+                        },
+                        RetentionPolicyTag {
+                            display_name: "1 Week Delete".to_string(),
+                            retention_id: "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d".to_string(),
+                            retention_period: 7,
+                            folder_type: ElcFolderType::Personal,
+                            retention_action: RetentionAction::DeleteAndAllowRecovery,
+                            description: Some("Deleted after a week.".to_string()),
+                            is_visible: true,
+                            opted_into: false,
+                            is_archive: false,
+                        },
+                    ],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn test_deserialize_unknown_folder_type_and_action() {
+        let content = r#"
+            <m:GetUserRetentionPolicyTagsResponse ResponseClass="Success"
+                                                  xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                                  xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:RetentionPolicyTags>
+                <t:RetentionPolicyTag>
+                  <t:DisplayName>Future tag</t:DisplayName>
+                  <t:RetentionId>9f8e7d6c-5b4a-4392-8170-f6e5d4c3b2a1</t:RetentionId>
+                  <t:RetentionPeriod>30</t:RetentionPeriod>
+                  <t:Type>SomeFutureFolder</t:Type>
+                  <t:RetentionAction>SomeFutureAction</t:RetentionAction>
+                  <t:IsVisible>false</t:IsVisible>
+                  <t:OptedInto>false</t:OptedInto>
+                  <t:IsArchive>false</t:IsArchive>
+                </t:RetentionPolicyTag>
+              </m:RetentionPolicyTags>
+            </m:GetUserRetentionPolicyTagsResponse>"#;
+
+        let expected = GetUserRetentionPolicyTagsResponse {
+            response_message: ResponseClass::Success(GetUserRetentionPolicyTagsResponseMessage {
+                retention_policy_tags: RetentionPolicyTags {
+                    inner: vec![RetentionPolicyTag {
+                        display_name: "Future tag".to_string(),
+                        retention_id: "9f8e7d6c-5b4a-4392-8170-f6e5d4c3b2a1".to_string(),
+                        retention_period: 30,
+                        folder_type: ElcFolderType::Unknown("SomeFutureFolder".to_string()),
+                        retention_action: RetentionAction::Unknown("SomeFutureAction".to_string()),
+                        description: None,
+                        is_visible: false,
+                        opted_into: false,
+                        is_archive: false,
+                    }],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
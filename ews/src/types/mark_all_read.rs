@@ -13,7 +13,7 @@ use crate::{BaseFolderId, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/markallitemsasread>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(MarkAllItemsAsReadResponseMessage)]
+#[operation_response(MarkAllItemsAsReadResponseMessage, min_version = Exchange2013)]
 pub struct MarkAllItemsAsRead {
     pub read_flag: bool,
     pub suppress_read_receipts: bool,
@@ -13,7 +13,7 @@ use crate::{BaseItemId, ItemId, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/markasjunk>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(MarkAsJunkResponseMessage)]
+#[operation_response(MarkAsJunkResponseMessage, min_version = Exchange2013)]
 pub struct MarkAsJunk {
     #[xml_struct(attribute)]
     pub is_junk: bool,
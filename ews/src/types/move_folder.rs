@@ -23,8 +23,8 @@ mod test {
     use crate::{
         move_folder::{MoveFolder, MoveFolderResponse},
         test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
-        BaseFolderId, CopyMoveFolderData, Folder, FolderId, FolderResponseMessage, Folders,
-        ResponseClass, ResponseMessages,
+        BaseFolderId, CopyMoveFolderData, DistinguishedFolderName, Folder, FolderId,
+        FolderResponseMessage, Folders, ResponseClass, ResponseMessages,
     };
 
     #[test]
@@ -32,8 +32,9 @@ mod test {
         let move_folder = MoveFolder {
             inner: CopyMoveFolderData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "junkemail".to_string(),
+                    id: DistinguishedFolderName::JunkEmail,
                     change_key: None,
+                    mailbox: None,
                 },
                 folder_ids: vec![BaseFolderId::FolderId {
                     id: "AScAc".to_string(),
@@ -46,7 +47,7 @@ mod test {
             r#"
             <MoveFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
               <ToFolderId>
-                <t:DistinguishedFolderId Id="junkemail"/>
+                <t:DistinguishedFolderId Id="junkemail"></t:DistinguishedFolderId>
               </ToFolderId>
               <FolderIds>
                 <t:FolderId Id="AScAc"/>
@@ -91,6 +92,10 @@ mod test {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: None,
                             unread_count: None,
                         }],
                     },
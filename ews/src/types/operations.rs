@@ -5,6 +5,8 @@
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, VersionRequirements};
+
 /// A marker trait for EWS operations.
 ///
 /// Types implementing this trait may appear in requests to EWS as the operation
@@ -15,7 +17,9 @@ use xml_struct::XmlSerialize;
 /// See [`Envelope`] for details.
 ///
 /// [`Envelope`]: crate::soap::Envelope
-pub trait Operation: XmlSerialize + sealed::EnvelopeBodyContents + std::fmt::Debug {
+pub trait Operation:
+    XmlSerialize + sealed::EnvelopeBodyContents + VersionRequirements + std::fmt::Debug
+{
     /// The structure returned by EWS in response to requests containing this
     /// operation.
     type Response: OperationResponse;
@@ -25,6 +29,9 @@ pub trait Operation: XmlSerialize + sealed::EnvelopeBodyContents + std::fmt::Deb
     /// This is the same as the local part of the name of the XML element used
     /// to represent this option.
     const NAME: &'static str;
+
+    /// The oldest server version supporting the operation.
+    const MIN_VERSION: ExchangeServerVersion = ExchangeServerVersion::Exchange2007;
 }
 
 // Blanket implementation for borrows, so the consumer does not need full
@@ -36,6 +43,7 @@ where
     type Response = T::Response;
 
     const NAME: &'static str = <T as Operation>::NAME;
+    const MIN_VERSION: ExchangeServerVersion = T::MIN_VERSION;
 }
 
 /// A marker trait for EWS operation responses.
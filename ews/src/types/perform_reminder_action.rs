@@ -0,0 +1,181 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{DateTime, ItemId, MESSAGES_NS_URI};
+
+/// A request to dismiss or snooze reminders.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/performreminderaction>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(PerformReminderActionResponseMessage, shape = ResponseClass, min_version = Exchange2013)]
+pub struct PerformReminderAction {
+    /// The actions to perform.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/reminderitemactions>
+    pub reminder_item_actions: ReminderItemActions,
+}
+
+/// A list of actions to perform on reminders.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReminderItemActions {
+    pub inner: Vec<ReminderItemAction>,
+}
+
+impl XmlSerialize for ReminderItemActions {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for action in &self.inner {
+            action.serialize_as_element(writer, "t:ReminderItemAction")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An action to perform on the reminder of an item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/reminderitemaction>
+#[derive(Clone, Debug, XmlSerialize, PartialEq, Eq)]
+pub struct ReminderItemAction {
+    #[xml_struct(ns_prefix = "t")]
+    pub action_type: ReminderActionType,
+
+    /// The item whose reminder to act on, as returned by [`GetReminders`].
+    ///
+    /// [`GetReminders`]: crate::get_reminders::GetReminders
+    #[xml_struct(ns_prefix = "t")]
+    pub item_id: ItemId,
+
+    /// The time at which a snoozed reminder is next due.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/newremindertime>
+    #[xml_struct(ns_prefix = "t")]
+    pub new_reminder_time: Option<DateTime>,
+}
+
+/// An action to perform on a reminder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/actiontype>
+#[derive(Clone, Copy, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ReminderActionType {
+    Dismiss,
+    Snooze,
+}
+
+/// The contents of a successful response to a [`PerformReminderAction`]
+/// operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/performreminderactionresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PerformReminderActionResponseMessage {
+    /// The updated identifiers of the items whose reminders were acted on.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateditemids>
+    #[serde(default)]
+    pub updated_item_ids: UpdatedItemIds,
+}
+
+/// A list of item identifiers.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct UpdatedItemIds {
+    #[serde(rename = "ItemId", default)]
+    pub inner: Vec<ItemId>,
+}
+
+#[cfg(test)]
+mod test {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        ResponseClass,
+    };
+
+    #[test]
+    fn test_serialize_perform_reminder_action() {
+        let perform_reminder_action = PerformReminderAction {
+            reminder_item_actions: ReminderItemActions {
+                inner: vec![
+                    ReminderItemAction {
+                        action_type: ReminderActionType::Dismiss,
+                        item_id: ItemId {
+                            id: "AAMkADg=".to_string(),
+                            change_key: Some("DwAAABYA".to_string()),
+                        },
+                        new_reminder_time: None,
+                    },
+                    ReminderItemAction {
+                        action_type: ReminderActionType::Snooze,
+                        item_id: ItemId {
+                            id: "AAMkADh=".to_string(),
+                            change_key: Some("DwAAABYB".to_string()),
+                        },
+                        new_reminder_time: Some(DateTime(
+                            OffsetDateTime::parse("2024-07-01T09:00:00Z", &Iso8601::DEFAULT)
+                                .unwrap(),
+                        )),
+                    },
+                ],
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <PerformReminderAction xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <ReminderItemActions>
+                <t:ReminderItemAction>
+                  <t:ActionType>Dismiss</t:ActionType>
+                  <t:ItemId Id="AAMkADg=" ChangeKey="DwAAABYA"/>
+                </t:ReminderItemAction>
+                <t:ReminderItemAction>
+                  <t:ActionType>Snooze</t:ActionType>
+                  <t:ItemId Id="AAMkADh=" ChangeKey="DwAAABYB"/>
+                  <t:NewReminderTime>2024-07-01T09:00:00.000000000Z</t:NewReminderTime>
+                </t:ReminderItemAction>
+              </ReminderItemActions>
+            </PerformReminderAction>"#,
+        );
+
+        assert_serialized_content(&perform_reminder_action, "PerformReminderAction", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_perform_reminder_action_response() {
+        let content = r#"
+            <m:PerformReminderActionResponse ResponseClass="Success"
+                                             xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                             xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseCode>NoError</m:ResponseCode>
+              <m:UpdatedItemIds>
+                <t:ItemId Id="AAMkADg=" ChangeKey="DwAAABYC"/>
+              </m:UpdatedItemIds>
+            </m:PerformReminderActionResponse>"#;
+
+        let expected = PerformReminderActionResponse {
+            response_message: ResponseClass::Success(PerformReminderActionResponseMessage {
+                updated_item_ids: UpdatedItemIds {
+                    inner: vec![ItemId {
+                        id: "AAMkADg=".to_string(),
+                        change_key: Some("DwAAABYC".to_string()),
+                    }],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
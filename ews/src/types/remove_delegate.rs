@@ -0,0 +1,96 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    delegates::{DelegateResponseMessage, UserIds},
+    Mailbox, MESSAGES_NS_URI,
+};
+
+/// A request to revoke the delegate access of one or more users to a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/removedelegate>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    DelegateResponseMessage,
+    min_version = Exchange2007_SP1,
+    shape = ResponseClass
+)]
+pub struct RemoveDelegate {
+    /// The mailbox to revoke access to.
+    pub mailbox: Mailbox,
+
+    /// The delegates to remove.
+    pub user_ids: UserIds,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        delegates::{DelegateResponseMessage, DelegateUserResponseMessage, UserIds},
+        remove_delegate::{RemoveDelegate, RemoveDelegateResponse},
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        Mailbox, ResponseClass, ResponseMessages, UserId,
+    };
+
+    #[test]
+    fn test_serialize_remove_delegate() {
+        let remove_delegate = RemoveDelegate {
+            mailbox: Mailbox {
+                email_address: Some("owner@example.com".to_string()),
+                ..Default::default()
+            },
+            user_ids: UserIds {
+                inner: vec![UserId {
+                    primary_smtp_address: Some("assistant@example.com".to_string()),
+                    ..Default::default()
+                }],
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <RemoveDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <Mailbox>
+                <t:EmailAddress>owner@example.com</t:EmailAddress>
+              </Mailbox>
+              <UserIds>
+                <t:UserId>
+                  <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                </t:UserId>
+              </UserIds>
+            </RemoveDelegate>"#,
+        );
+
+        assert_serialized_content(&remove_delegate, "RemoveDelegate", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_remove_delegate_response() {
+        let content = r#"<RemoveDelegateResponse ResponseClass="Success" xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseCode>NoError</ResponseCode>
+                  <ResponseMessages>
+                    <DelegateUserResponseMessageType ResponseClass="Success">
+                      <ResponseCode>NoError</ResponseCode>
+                    </DelegateUserResponseMessageType>
+                  </ResponseMessages>
+                </RemoveDelegateResponse>"#;
+
+        let expected = RemoveDelegateResponse {
+            response_message: ResponseClass::Success(DelegateResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![ResponseClass::Success(DelegateUserResponseMessage {
+                        delegate_user: None,
+                    })],
+                },
+                deliver_meeting_requests: None,
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
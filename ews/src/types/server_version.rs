@@ -2,65 +2,331 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use serde::Deserialize;
-use xml_struct::XmlSerialize;
+use serde::{Deserialize, Deserializer};
+use xml_struct::{XmlSerialize, XmlSerializeAttr};
 
 use crate::Error;
 
 /// The Exchange Server version identifiers allowed in `RequestServerVersion`
 /// headers.
 ///
+/// Versions are ordered chronologically. Versions not known to this crate are
+/// represented by [`ExchangeServerVersion::Unknown`] and are considered newer
+/// than all known versions. As some servers report identifiers which are not
+/// dated, e.g. `V2_4`, [`ServerVersionInfo::highest_supported_version`]
+/// resolves unknown identifiers from the build number of the server.
+///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/requestserverversion#version-attribute-values>
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize, XmlSerialize)]
-#[xml_struct(text)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ExchangeServerVersion {
     Exchange2007,
     Exchange2007_SP1,
+    Exchange2009,
     Exchange2010,
     Exchange2010_SP1,
     Exchange2010_SP2,
+    Exchange2012,
     Exchange2013,
     Exchange2013_SP1,
+    Exchange2015,
+    Exchange2016,
+    V2015_10_05,
+    V2016_01_06,
+    V2016_04_13,
+    V2016_07_13,
+    V2016_10_10,
+    V2017_01_07,
+    V2017_04_14,
+    V2017_07_11,
+    V2017_10_09,
+    V2018_01_08,
+
+    /// A version identifier not known to this crate, e.g. one introduced after
+    /// its release.
+    ///
+    /// Unknown versions are ordered after all known versions, and between
+    /// themselves by their identifier, which preserves the chronological order
+    /// of dated versions such as `V2019_01_01`.
+    Unknown(String),
+}
+
+impl ExchangeServerVersion {
+    /// The identifier of the version as represented in EWS, e.g.
+    /// "Exchange2013_SP1".
+    pub fn as_str(&self) -> &str {
+        match self {
+            ExchangeServerVersion::Exchange2007 => "Exchange2007",
+            ExchangeServerVersion::Exchange2007_SP1 => "Exchange2007_SP1",
+            ExchangeServerVersion::Exchange2009 => "Exchange2009",
+            ExchangeServerVersion::Exchange2010 => "Exchange2010",
+            ExchangeServerVersion::Exchange2010_SP1 => "Exchange2010_SP1",
+            ExchangeServerVersion::Exchange2010_SP2 => "Exchange2010_SP2",
+            ExchangeServerVersion::Exchange2012 => "Exchange2012",
+            ExchangeServerVersion::Exchange2013 => "Exchange2013",
+            ExchangeServerVersion::Exchange2013_SP1 => "Exchange2013_SP1",
+            ExchangeServerVersion::Exchange2015 => "Exchange2015",
+            ExchangeServerVersion::Exchange2016 => "Exchange2016",
+            ExchangeServerVersion::V2015_10_05 => "V2015_10_05",
+            ExchangeServerVersion::V2016_01_06 => "V2016_01_06",
+            ExchangeServerVersion::V2016_04_13 => "V2016_04_13",
+            ExchangeServerVersion::V2016_07_13 => "V2016_07_13",
+            ExchangeServerVersion::V2016_10_10 => "V2016_10_10",
+            ExchangeServerVersion::V2017_01_07 => "V2017_01_07",
+            ExchangeServerVersion::V2017_04_14 => "V2017_04_14",
+            ExchangeServerVersion::V2017_07_11 => "V2017_07_11",
+            ExchangeServerVersion::V2017_10_09 => "V2017_10_09",
+            ExchangeServerVersion::V2018_01_08 => "V2018_01_08",
+            ExchangeServerVersion::Unknown(value) => value,
+        }
+    }
 }
 
-/// Parses the provided string into a known version identifier.
+/// Parses the provided string into a version identifier.
 impl TryFrom<&str> for ExchangeServerVersion {
-    /// If the provided string could not be turned into a known version
-    /// identifier, [`Error::UnknownServerVersion`] is returned.
+    /// Strings which do not match a known version identifier are preserved as
+    /// [`ExchangeServerVersion::Unknown`]. If the provided string is empty,
+    /// [`Error::UnknownServerVersion`] is returned.
     type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "Exchange2007" => Ok(ExchangeServerVersion::Exchange2007),
-            "Exchange2007_SP1" => Ok(ExchangeServerVersion::Exchange2007_SP1),
-            "Exchange2010" => Ok(ExchangeServerVersion::Exchange2010),
-            "Exchange2010_SP1" => Ok(ExchangeServerVersion::Exchange2010_SP1),
-            "Exchange2010_SP2" => Ok(ExchangeServerVersion::Exchange2010_SP2),
-            "Exchange2013" => Ok(ExchangeServerVersion::Exchange2013),
-            "Exchange2013_SP1" => Ok(ExchangeServerVersion::Exchange2013_SP1),
-
-            _ => Err(Error::UnknownServerVersion(value.to_owned())),
-        }
+        let version = match value {
+            "Exchange2007" => ExchangeServerVersion::Exchange2007,
+            "Exchange2007_SP1" => ExchangeServerVersion::Exchange2007_SP1,
+            "Exchange2009" => ExchangeServerVersion::Exchange2009,
+            "Exchange2010" => ExchangeServerVersion::Exchange2010,
+            "Exchange2010_SP1" => ExchangeServerVersion::Exchange2010_SP1,
+            "Exchange2010_SP2" => ExchangeServerVersion::Exchange2010_SP2,
+            "Exchange2012" => ExchangeServerVersion::Exchange2012,
+            "Exchange2013" => ExchangeServerVersion::Exchange2013,
+            "Exchange2013_SP1" => ExchangeServerVersion::Exchange2013_SP1,
+            "Exchange2015" => ExchangeServerVersion::Exchange2015,
+            "Exchange2016" => ExchangeServerVersion::Exchange2016,
+            "V2015_10_05" => ExchangeServerVersion::V2015_10_05,
+            "V2016_01_06" => ExchangeServerVersion::V2016_01_06,
+            "V2016_04_13" => ExchangeServerVersion::V2016_04_13,
+            "V2016_07_13" => ExchangeServerVersion::V2016_07_13,
+            "V2016_10_10" => ExchangeServerVersion::V2016_10_10,
+            "V2017_01_07" => ExchangeServerVersion::V2017_01_07,
+            "V2017_04_14" => ExchangeServerVersion::V2017_04_14,
+            "V2017_07_11" => ExchangeServerVersion::V2017_07_11,
+            "V2017_10_09" => ExchangeServerVersion::V2017_10_09,
+            "V2018_01_08" => ExchangeServerVersion::V2018_01_08,
+
+            "" => return Err(Error::UnknownServerVersion(value.to_owned())),
+            _ => ExchangeServerVersion::Unknown(value.to_owned()),
+        };
+
+        Ok(version)
     }
 }
 
-// While we don't strictly need this implementation for serialization
-// (`xml-struct` knows how to string-ify unit enum variants without additional
-// guidance), consumers can require it to persist the version associated with a
-// given server.
+// While we don't strictly need this implementation for serialization,
+// consumers can require it to persist the version associated with a given
+// server.
 impl From<ExchangeServerVersion> for String {
     fn from(value: ExchangeServerVersion) -> Self {
         match value {
-            ExchangeServerVersion::Exchange2007 => "Exchange2007",
-            ExchangeServerVersion::Exchange2007_SP1 => "Exchange2007_SP1",
-            ExchangeServerVersion::Exchange2010 => "Exchange2010",
-            ExchangeServerVersion::Exchange2010_SP1 => "Exchange2010_SP1",
-            ExchangeServerVersion::Exchange2010_SP2 => "Exchange2010_SP2",
-            ExchangeServerVersion::Exchange2013 => "Exchange2013",
-            ExchangeServerVersion::Exchange2013_SP1 => "Exchange2013_SP1",
+            ExchangeServerVersion::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for ExchangeServerVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ExchangeServerVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        ExchangeServerVersion::try_from(value.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+impl XmlSerialize for ExchangeServerVersion {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.as_str().serialize_child_nodes(writer)
+    }
+}
+
+impl XmlSerializeAttr for ExchangeServerVersion {
+    fn serialize_as_attribute(&self, start_tag: &mut quick_xml::events::BytesStart, name: &str) {
+        self.as_str().serialize_as_attribute(start_tag, name)
+    }
+}
+
+/// An element of a request which is not supported by the server version
+/// targeted by the request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsupportedElement {
+    /// The path of the element within the request, e.g.
+    /// `CreateItem/Items/Message/Preview`.
+    pub path: String,
+
+    /// The oldest server version supporting the element.
+    pub min_version: ExchangeServerVersion,
+}
+
+impl std::fmt::Display for UnsupportedElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (requires {})", self.path, self.min_version)
+    }
+}
+
+/// A structure which may contain elements requiring a minimum server version.
+///
+/// The default implementation reports no unsupported elements, which is
+/// appropriate for structures containing no version-specific elements.
+pub trait VersionRequirements {
+    /// Records the elements of this structure, located at `path` within a
+    /// request, which are not supported by `version`.
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        let _ = (version, path, unsupported);
+    }
+
+    /// Removes the elements of this structure which are not supported by
+    /// `version`.
+    ///
+    /// Elements which cannot be removed without changing the meaning of the
+    /// request are left in place, to be reported by [`check_version`].
+    ///
+    /// [`check_version`]: `VersionRequirements::check_version`
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        let _ = version;
+    }
+}
+
+impl<T> VersionRequirements for &T
+where
+    T: VersionRequirements,
+{
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        T::check_version(self, version, path, unsupported)
+    }
+}
+
+/// Records an element at `path` if it is present and not supported by
+/// `version`.
+pub(crate) fn require_version(
+    present: bool,
+    min_version: ExchangeServerVersion,
+    version: &ExchangeServerVersion,
+    path: String,
+    unsupported: &mut Vec<UnsupportedElement>,
+) {
+    if present && *version < min_version {
+        unsupported.push(UnsupportedElement { path, min_version });
+    }
+}
+
+/// An optional field of `T` which requires a minimum server version.
+///
+/// A table of these fields lets a type derive both
+/// [`VersionRequirements::check_version`] and
+/// [`VersionRequirements::strip_unsupported`] from the same data.
+pub(crate) struct VersionedField<T> {
+    /// The name of the element representing the field.
+    name: &'static str,
+
+    /// The oldest server version supporting the field.
+    min_version: ExchangeServerVersion,
+
+    /// Whether the field is present.
+    is_present: fn(&T) -> bool,
+
+    /// Removes the field, if this does not change the meaning of the request.
+    strip: Option<fn(&mut T)>,
+}
+
+impl<T> VersionedField<T> {
+    /// A field which cannot be removed without changing the meaning of the
+    /// request, and so must be reported if unsupported.
+    pub(crate) const fn new(
+        name: &'static str,
+        min_version: ExchangeServerVersion,
+        is_present: fn(&T) -> bool,
+    ) -> Self {
+        Self {
+            name,
+            min_version,
+            is_present,
+            strip: None,
+        }
+    }
+
+    /// A field which can be removed if unsupported without changing the
+    /// meaning of the request, e.g. because the server ignores it in requests.
+    pub(crate) const fn removable(
+        name: &'static str,
+        min_version: ExchangeServerVersion,
+        is_present: fn(&T) -> bool,
+        strip: fn(&mut T),
+    ) -> Self {
+        Self {
+            name,
+            min_version,
+            is_present,
+            strip: Some(strip),
+        }
+    }
+}
+
+/// Records the fields of `value`, located at `path` within a request, which
+/// are present and not supported by `version`.
+pub(crate) fn check_fields<T>(
+    fields: &[VersionedField<T>],
+    value: &T,
+    version: &ExchangeServerVersion,
+    path: &str,
+    unsupported: &mut Vec<UnsupportedElement>,
+) {
+    for field in fields {
+        require_version(
+            (field.is_present)(value),
+            field.min_version.clone(),
+            version,
+            format!("{path}/{}", field.name),
+            unsupported,
+        );
+    }
+}
+
+/// Removes the removable fields of `value` which are not supported by
+/// `version`.
+pub(crate) fn strip_fields<T>(
+    fields: &[VersionedField<T>],
+    value: &mut T,
+    version: &ExchangeServerVersion,
+) {
+    for field in fields {
+        if let Some(strip) = field.strip {
+            if *version < field.min_version {
+                strip(value);
+            }
         }
-        .into()
     }
 }
 
@@ -68,7 +334,7 @@ impl From<ExchangeServerVersion> for String {
 /// the attached response.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/serverversioninfo>
-#[derive(Clone, Debug, Deserialize, XmlSerialize)]
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize)]
 pub struct ServerVersionInfo {
     #[xml_struct(attribute)]
     #[serde(rename = "@MajorVersion")]
@@ -90,3 +356,290 @@ pub struct ServerVersionInfo {
     #[serde(rename = "@Version")]
     pub version: Option<String>,
 }
+
+impl ServerVersionInfo {
+    /// Parses the build number of the server.
+    ///
+    /// Returns `None` if the major or minor version is missing or not numeric.
+    /// Missing build numbers are treated as 0.
+    pub fn build(&self) -> Option<ServerBuild> {
+        fn parse(value: &Option<String>) -> Option<u16> {
+            value.as_deref()?.trim().parse().ok()
+        }
+
+        Some(ServerBuild {
+            major: parse(&self.major_version)?,
+            minor: parse(&self.minor_version)?,
+            major_build: parse(&self.major_build_number).unwrap_or_default(),
+            minor_build: parse(&self.minor_build_number).unwrap_or_default(),
+        })
+    }
+
+    /// Determines the newest schema version supported by the server.
+    ///
+    /// The version reported by the server is preferred. If it is missing or
+    /// not known to this crate, e.g. the `V2_4` style identifiers reported by
+    /// some on-premises servers, the version is inferred from the build
+    /// number. An unknown version is only returned if the build number is
+    /// also missing.
+    pub fn highest_supported_version(&self) -> Option<ExchangeServerVersion> {
+        let reported = self
+            .version
+            .as_deref()
+            .and_then(|version| ExchangeServerVersion::try_from(version).ok());
+
+        match reported {
+            Some(ExchangeServerVersion::Unknown(_)) | None => self
+                .build()
+                .and_then(|build| build.highest_supported_version())
+                .or(reported),
+            known => known,
+        }
+    }
+
+    /// Proposes a schema version to retry a request with after the server
+    /// rejected it for targeting `requested`, e.g. with
+    /// `ErrorInvalidServerVersion`.
+    ///
+    /// Returns `None` if the server does not report a version older than
+    /// `requested`.
+    pub fn propose_version(
+        &self,
+        requested: &ExchangeServerVersion,
+    ) -> Option<ExchangeServerVersion> {
+        let supported = self.highest_supported_version()?;
+
+        (supported < *requested).then_some(supported)
+    }
+}
+
+/// The build number of an Exchange Server instance, e.g. 15.1.2507.6.
+///
+/// Builds are ordered by their components, from most to least significant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerBuild {
+    pub major: u16,
+    pub minor: u16,
+    pub major_build: u16,
+    pub minor_build: u16,
+}
+
+impl ServerBuild {
+    /// Determines the newest schema version supported by servers with this
+    /// build, or `None` for builds older than Exchange 2007.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/new-features/build-numbers-and-release-dates>
+    pub fn highest_supported_version(&self) -> Option<ExchangeServerVersion> {
+        let version = match (self.major, self.minor) {
+            (major, _) if major < 8 => return None,
+            (8, 0) => ExchangeServerVersion::Exchange2007,
+            (8, _) => ExchangeServerVersion::Exchange2007_SP1,
+            (9..=14, 0) => ExchangeServerVersion::Exchange2010,
+            (9..=14, 1) => ExchangeServerVersion::Exchange2010_SP1,
+            (9..=14, _) => ExchangeServerVersion::Exchange2010_SP2,
+
+            // Exchange 2013 SP1 is build 15.0.847.
+            (15, 0) if self.major_build < 847 => ExchangeServerVersion::Exchange2013,
+            (15, 0) => ExchangeServerVersion::Exchange2013_SP1,
+            _ => ExchangeServerVersion::Exchange2016,
+        };
+
+        Some(version)
+    }
+}
+
+impl std::fmt::Display for ServerBuild {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.major_build, self.minor_build
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_and_unknown_versions() {
+        assert_eq!(
+            ExchangeServerVersion::try_from("Exchange2016").unwrap(),
+            ExchangeServerVersion::Exchange2016
+        );
+        assert_eq!(
+            ExchangeServerVersion::try_from("V2015_10_05").unwrap(),
+            ExchangeServerVersion::V2015_10_05
+        );
+        assert_eq!(
+            ExchangeServerVersion::try_from("V2020_01_01").unwrap(),
+            ExchangeServerVersion::Unknown("V2020_01_01".to_string())
+        );
+        assert!(matches!(
+            ExchangeServerVersion::try_from(""),
+            Err(Error::UnknownServerVersion(_))
+        ));
+
+        assert_eq!(
+            String::from(ExchangeServerVersion::Exchange2013_SP1),
+            "Exchange2013_SP1"
+        );
+        assert_eq!(
+            String::from(ExchangeServerVersion::Unknown("V2020_01_01".to_string())),
+            "V2020_01_01"
+        );
+    }
+
+    #[test]
+    fn orders_versions_chronologically() {
+        let mut versions = vec![
+            ExchangeServerVersion::Unknown("V2021_01_01".to_string()),
+            ExchangeServerVersion::V2018_01_08,
+            ExchangeServerVersion::Exchange2016,
+            ExchangeServerVersion::Unknown("V2019_06_01".to_string()),
+            ExchangeServerVersion::Exchange2007_SP1,
+            ExchangeServerVersion::Exchange2013_SP1,
+            ExchangeServerVersion::Exchange2010,
+        ];
+        versions.sort();
+
+        assert_eq!(
+            versions,
+            vec![
+                ExchangeServerVersion::Exchange2007_SP1,
+                ExchangeServerVersion::Exchange2010,
+                ExchangeServerVersion::Exchange2013_SP1,
+                ExchangeServerVersion::Exchange2016,
+                ExchangeServerVersion::V2018_01_08,
+                ExchangeServerVersion::Unknown("V2019_06_01".to_string()),
+                ExchangeServerVersion::Unknown("V2021_01_01".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn determines_highest_supported_version() {
+        let info: ServerVersionInfo = quick_xml::de::from_str(
+            r#"<h:ServerVersionInfo MajorVersion="15" MinorVersion="20" MajorBuildNumber="7892" MinorBuildNumber="23" Version="V2018_01_08"/>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            info.build(),
+            Some(ServerBuild {
+                major: 15,
+                minor: 20,
+                major_build: 7892,
+                minor_build: 23,
+            })
+        );
+        assert_eq!(
+            info.highest_supported_version(),
+            Some(ExchangeServerVersion::V2018_01_08)
+        );
+
+        let info = ServerVersionInfo {
+            major_version: Some("15".to_string()),
+            minor_version: Some("0".to_string()),
+            major_build_number: Some("1497".to_string()),
+            minor_build_number: Some("2".to_string()),
+            version: None,
+        };
+        assert_eq!(
+            info.highest_supported_version(),
+            Some(ExchangeServerVersion::Exchange2013_SP1)
+        );
+
+        let cases = [
+            ((8, 3, 83, 6), Some(ExchangeServerVersion::Exchange2007_SP1)),
+            (
+                (14, 3, 123, 4),
+                Some(ExchangeServerVersion::Exchange2010_SP2),
+            ),
+            ((15, 0, 516, 32), Some(ExchangeServerVersion::Exchange2013)),
+            ((15, 1, 2507, 6), Some(ExchangeServerVersion::Exchange2016)),
+            ((6, 5, 7638, 1), None),
+        ];
+
+        for ((major, minor, major_build, minor_build), expected) in cases {
+            let build = ServerBuild {
+                major,
+                minor,
+                major_build,
+                minor_build,
+            };
+            assert_eq!(build.highest_supported_version(), expected, "for {build}");
+        }
+
+        assert!(
+            ServerBuild {
+                major: 15,
+                minor: 1,
+                major_build: 2507,
+                minor_build: 6,
+            } > ServerBuild {
+                major: 15,
+                minor: 0,
+                major_build: 1497,
+                minor_build: 48,
+            }
+        );
+    }
+
+    #[test]
+    fn resolves_on_premises_version_from_build() {
+        // As reported by an on-premises Exchange 2013 CU23 server.
+        let info: ServerVersionInfo = quick_xml::de::from_str(
+            r#"<h:ServerVersionInfo MajorVersion="15" MinorVersion="0" MajorBuildNumber="1497" MinorBuildNumber="2" Version="V2_4" xmlns:h="http://schemas.microsoft.com/exchange/services/2006/types" xmlns="http://schemas.microsoft.com/exchange/services/2006/types"/>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            info.highest_supported_version(),
+            Some(ExchangeServerVersion::Exchange2013_SP1)
+        );
+
+        let info = ServerVersionInfo {
+            version: Some("V2_4".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            info.highest_supported_version(),
+            Some(ExchangeServerVersion::Unknown("V2_4".to_string()))
+        );
+    }
+
+    #[test]
+    fn proposes_lower_version() {
+        let info = ServerVersionInfo {
+            major_version: Some("15".to_string()),
+            minor_version: Some("0".to_string()),
+            major_build_number: Some("1497".to_string()),
+            minor_build_number: Some("2".to_string()),
+            version: Some("V2_4".to_string()),
+        };
+
+        assert_eq!(
+            info.propose_version(&ExchangeServerVersion::Exchange2016),
+            Some(ExchangeServerVersion::Exchange2013_SP1)
+        );
+        assert_eq!(
+            info.propose_version(&ExchangeServerVersion::Exchange2010_SP2),
+            None
+        );
+
+        let info = ServerVersionInfo {
+            version: Some("Exchange2010_SP2".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            info.propose_version(&ExchangeServerVersion::Exchange2013),
+            Some(ExchangeServerVersion::Exchange2010_SP2)
+        );
+        assert_eq!(
+            ServerVersionInfo::default().propose_version(&ExchangeServerVersion::Exchange2013),
+            None
+        );
+    }
+}
@@ -0,0 +1,124 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{EmailAddress, UserOofSettings, MESSAGES_NS_URI};
+
+/// A request to change the out-of-office settings of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/setuseroofsettings-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    SetUserOofSettingsResponseMessage,
+    shape = ResponseMessage,
+    name = SetUserOofSettingsRequest
+)]
+pub struct SetUserOofSettings {
+    /// The mailbox whose settings to change.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailbox-availability>
+    #[xml_struct(ns_prefix = "t")]
+    pub mailbox: EmailAddress,
+
+    /// The new out-of-office settings of the mailbox.
+    #[xml_struct(ns_prefix = "t")]
+    pub user_oof_settings: UserOofSettings,
+}
+
+/// The contents of a successful response to a `SetUserOofSettingsRequest`.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/setuseroofsettingsresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SetUserOofSettingsResponseMessage {}
+
+#[cfg(test)]
+mod test {
+    use time::{format_description::well_known::Iso8601, OffsetDateTime};
+
+    use crate::{
+        oof::{ExternalAudience, OofDuration, OofState, ReplyBody},
+        set_user_oof_settings::{
+            SetUserOofSettings, SetUserOofSettingsResponse, SetUserOofSettingsResponseMessage,
+        },
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        DateTime, EmailAddress, ResponseClass, UserOofSettings,
+    };
+
+    #[test]
+    fn test_serialize_set_user_oof_settings() {
+        let set_user_oof_settings = SetUserOofSettings {
+            mailbox: EmailAddress {
+                address: "user@example.com".to_string(),
+                ..Default::default()
+            },
+            user_oof_settings: UserOofSettings {
+                oof_state: OofState::Scheduled,
+                external_audience: ExternalAudience::All,
+                duration: Some(OofDuration {
+                    start_time: DateTime(
+                        OffsetDateTime::parse("2024-07-01T08:00:00Z", &Iso8601::DEFAULT).unwrap(),
+                    ),
+                    end_time: DateTime(
+                        OffsetDateTime::parse("2024-07-15T17:00:00Z", &Iso8601::DEFAULT).unwrap(),
+                    ),
+                }),
+                internal_reply: Some(ReplyBody {
+                    message: Some("I am on vacation.".to_string()),
+                }),
+                external_reply: Some(ReplyBody {
+                    message: Some("I am out of the office.".to_string()),
+                }),
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <SetUserOofSettingsRequest xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <t:Mailbox>
+                <t:Address>user@example.com</t:Address>
+              </t:Mailbox>
+              <t:UserOofSettings>
+                <t:OofState>Scheduled</t:OofState>
+                <t:ExternalAudience>All</t:ExternalAudience>
+                <t:Duration>
+                  <t:StartTime>2024-07-01T08:00:00.000000000Z</t:StartTime>
+                  <t:EndTime>2024-07-15T17:00:00.000000000Z</t:EndTime>
+                </t:Duration>
+                <t:InternalReply>
+                  <t:Message>I am on vacation.</t:Message>
+                </t:InternalReply>
+                <t:ExternalReply>
+                  <t:Message>I am out of the office.</t:Message>
+                </t:ExternalReply>
+              </t:UserOofSettings>
+            </SetUserOofSettingsRequest>"#,
+        );
+
+        assert_serialized_content(
+            &set_user_oof_settings,
+            "SetUserOofSettingsRequest",
+            &expected,
+        );
+    }
+
+    #[test]
+    fn test_deserialize_set_user_oof_settings_response() {
+        let content = r#"<SetUserOofSettingsResponse xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseMessage ResponseClass="Success">
+                    <ResponseCode>NoError</ResponseCode>
+                  </ResponseMessage>
+                </SetUserOofSettingsResponse>"#;
+
+        let expected = SetUserOofSettingsResponse {
+            response_message: ResponseClass::Success(SetUserOofSettingsResponseMessage {}),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -12,13 +12,15 @@ use xml_struct::XmlSerialize;
 
 use crate::{
     response::ResponseCode, types::sealed, types::server_version, Error, MessageXml, Operation,
-    OperationResponse, SOAP_NS_URI, TYPES_NS_URI,
+    OperationResponse, TimeZoneDefinition, SOAP_NS_URI, TYPES_NS_URI,
 };
 
 mod de;
 use self::de::DeserializeEnvelope;
 
-use super::server_version::ExchangeServerVersion;
+use super::server_version::{
+    require_version, ExchangeServerVersion, UnsupportedElement, VersionRequirements,
+};
 
 /// An element that can be found in the `soap:Header` section of an request or a
 /// response.
@@ -43,6 +45,64 @@ pub enum Header {
         version: ExchangeServerVersion,
     },
 
+    /// The time zone in which times in the attached request should be
+    /// interpreted, and in which times in the response should be expressed.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/timezonecontext>
+    TimeZoneContext {
+        #[xml_struct(ns_prefix = "t")]
+        #[serde(rename = "TimeZoneDefinition")]
+        time_zone_definition: TimeZoneDefinition,
+    },
+
+    /// The account to impersonate when performing the attached request.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/exchangeimpersonation>
+    #[allow(non_snake_case)]
+    ExchangeImpersonation {
+        #[xml_struct(ns_prefix = "t")]
+        #[serde(rename = "ConnectingSID")]
+        connecting_SID: ConnectingSid,
+    },
+
+    /// The culture to use when accessing the mailbox, e.g. for the names of
+    /// default folders, as an RFC 3066 culture name such as "en-US".
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxculture>
+    MailboxCulture {
+        #[xml_struct(flatten)]
+        #[serde(rename = "$text")]
+        culture: String,
+    },
+
+    /// The precision with which times should be expressed in the response.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/datetimeprecision>
+    ///
+    /// This element was introduced in Exchange 2010 SP2.
+    DateTimePrecision {
+        #[xml_struct(flatten)]
+        #[serde(rename = "$text")]
+        precision: DateTimePrecision,
+    },
+
+    /// The management roles to use when performing the attached request.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/managementrole>
+    ///
+    /// This element was introduced in Exchange 2013.
+    ManagementRole {
+        /// Roles assigned to the user making the request.
+        #[xml_struct(ns_prefix = "t")]
+        #[serde(rename = "UserRoles")]
+        user_roles: Option<Roles>,
+
+        /// Roles assigned to the application making the request.
+        #[xml_struct(ns_prefix = "t")]
+        #[serde(rename = "ApplicationRoles")]
+        application_roles: Option<Roles>,
+    },
+
     /// The version information of the Exchange Server instance that generated
     /// the attached response.
     ///
@@ -50,6 +110,102 @@ pub enum Header {
     ServerVersionInfo(server_version::ServerVersionInfo),
 }
 
+impl Header {
+    /// Whether this header only affects the presentation of the response, such
+    /// that removing it does not change the meaning of the request.
+    fn is_informational(&self) -> bool {
+        matches!(self, Header::DateTimePrecision { .. })
+    }
+}
+
+impl VersionRequirements for Header {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        use ExchangeServerVersion::*;
+
+        let (name, min_version) = match self {
+            Header::TimeZoneContext { .. } => ("TimeZoneContext", Exchange2010),
+            Header::DateTimePrecision { .. } => ("DateTimePrecision", Exchange2010_SP2),
+            Header::ManagementRole { .. } => ("ManagementRole", Exchange2013),
+            _ => return,
+        };
+
+        require_version(
+            true,
+            min_version,
+            version,
+            format!("{path}/{name}"),
+            unsupported,
+        );
+    }
+}
+
+/// An identifier for the account to impersonate in an `ExchangeImpersonation`
+/// header.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/connectingsid>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum ConnectingSid {
+    /// The user principal name (UPN) of the account.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/principalname>
+    PrincipalName(String),
+
+    /// The security descriptor definition language (SDDL) form of the security
+    /// identifier of the account, e.g. `S-1-5-21-...`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sid>
+    #[allow(clippy::upper_case_acronyms)]
+    SID(String),
+
+    /// The primary SMTP address of the account.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/primarysmtpaddress>
+    PrimarySmtpAddress(String),
+
+    /// An SMTP address of the account.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/smtpaddress>
+    SmtpAddress(String),
+}
+
+/// The precision of times in responses.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/datetimeprecision>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DateTimePrecision {
+    Seconds,
+    Milliseconds,
+}
+
+/// A list of management role names.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userroles>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Roles {
+    #[serde(rename = "Role", default)]
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for Roles {
+    fn serialize_child_nodes<W>(&self, writer: &mut Writer<W>) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for role in &self.inner {
+            role.serialize_as_element(writer, "t:Role")?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A SOAP envelope containing the body of an EWS operation or response.
 ///
 /// See <https://www.w3.org/TR/2000/NOTE-SOAP-20000508/#_Toc478383494>
@@ -99,6 +255,112 @@ where
 
         Ok(writer.into_inner())
     }
+
+    /// The schema version targeted by this envelope, as given by its
+    /// `RequestServerVersion` header.
+    ///
+    /// When no such header is present, Exchange assumes the request targets
+    /// Exchange 2007.
+    pub fn request_server_version(&self) -> ExchangeServerVersion {
+        self.headers
+            .iter()
+            .find_map(|header| match header {
+                Header::RequestServerVersion { version } => Some(version.clone()),
+                _ => None,
+            })
+            .unwrap_or(ExchangeServerVersion::Exchange2007)
+    }
+
+    /// Lists the elements of this envelope which are not supported by the
+    /// given server version.
+    pub fn unsupported_elements(&self, version: &ExchangeServerVersion) -> Vec<UnsupportedElement> {
+        let mut unsupported = Vec::new();
+
+        for header in &self.headers {
+            header.check_version(version, "Header", &mut unsupported);
+        }
+
+        let name = <B as sealed::EnvelopeBodyContents>::NAME;
+        require_version(
+            true,
+            B::MIN_VERSION,
+            version,
+            name.to_string(),
+            &mut unsupported,
+        );
+        self.body.check_version(version, name, &mut unsupported);
+
+        unsupported
+    }
+
+    /// Retargets a copy of this envelope at an older schema version after the
+    /// server rejected it with `ErrorInvalidServerVersion` or
+    /// `ErrorIncorrectSchemaVersion`.
+    ///
+    /// The version is proposed from the `ServerVersionInfo` header of the
+    /// failed response. Informational headers and fields unsupported by that
+    /// version are removed where this does not change the meaning of the
+    /// request;
+    /// otherwise, an [`Error::UnsupportedByServerVersion`] lists the
+    /// remaining offending elements. This envelope is left unchanged in
+    /// either case.
+    pub fn downgrade(
+        &self,
+        server_version: &server_version::ServerVersionInfo,
+    ) -> Result<Self, Error>
+    where
+        B: Clone,
+    {
+        let requested = self.request_server_version();
+        let version = server_version
+            .propose_version(&requested)
+            .ok_or(Error::NoOlderServerVersion(requested))?;
+
+        let mut downgraded = self.clone();
+        downgraded.headers.retain(|header| {
+            if !header.is_informational() {
+                return true;
+            }
+
+            let mut unsupported = Vec::new();
+            header.check_version(&version, "Header", &mut unsupported);
+            unsupported.is_empty()
+        });
+        downgraded.body.strip_unsupported(&version);
+
+        match downgraded
+            .headers
+            .iter_mut()
+            .find(|header| matches!(header, Header::RequestServerVersion { .. }))
+        {
+            Some(Header::RequestServerVersion { version: header }) => *header = version,
+            _ => downgraded
+                .headers
+                .insert(0, Header::RequestServerVersion { version }),
+        }
+
+        downgraded.validate_version()?;
+        Ok(downgraded)
+    }
+
+    /// Checks that every element of this envelope is supported by the schema
+    /// version given in its `RequestServerVersion` header.
+    ///
+    /// This should be called before serializing the envelope, as servers reject
+    /// requests with elements newer than the requested schema version.
+    pub fn validate_version(&self) -> Result<(), Error> {
+        let version = self.request_server_version();
+        let unsupported = self.unsupported_elements(&version);
+
+        if unsupported.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedByServerVersion {
+                version,
+                unsupported,
+            })
+        }
+    }
 }
 
 impl<B> Envelope<B>
@@ -192,9 +454,13 @@ mod tests {
     use xml_struct::XmlSerialize;
 
     use crate::{
+        create_item::CreateItem,
         get_folder::{GetFolderResponse, GetFolderResponseMessage},
+        mark_as_junk::MarkAsJunk,
         response::{ResponseClass, ResponseCode, ResponseError, ResponseMessages},
+        server_version::{ExchangeServerVersion, ServerVersionInfo, UnsupportedElement},
         sync_folder_items::SyncFolderItemsResponse,
+        test_utils::minify_xml,
         types::{
             common::message_xml::{
                 MessageXmlElement, MessageXmlElements, MessageXmlTagged, MessageXmlValue,
@@ -202,10 +468,12 @@ mod tests {
             },
             sealed::EnvelopeBodyContents,
         },
-        Error, Folder, FolderId, Folders, MessageXml, OperationResponse,
+        update_item::{ItemChange, ItemChangeDescription, ItemChangeInner, UpdateItem, Updates},
+        BaseItemId, Body, BodyType, Error, Folder, FolderId, Folders, Message, MessageDisposition,
+        MessageXml, OperationResponse, PathToElement, RealItem, TimeZoneDefinition,
     };
 
-    use super::Envelope;
+    use super::{ConnectingSid, DateTimePrecision, Envelope, Header, Roles};
 
     fn assert_deserialized_envelope_body<T>(content: &str, expected: T)
     where
@@ -232,6 +500,10 @@ mod tests {
                     total_count: None,
                     child_folder_count: None,
                     extended_property: None,
+                    effective_rights: None,
+                    policy_tag: None,
+                    archive_tag: None,
+                    permission_set: None,
                     unread_count: None,
                 }],
             },
@@ -286,15 +558,431 @@ mod tests {
     /// A meaningless struct.
     #[derive(Clone, Debug, XmlSerialize)]
     #[operation_response(Bar)]
-    // We never construct `Foo` directly, but we rely on the generated
-    // `FooResponse` class in the tests below.
-    #[allow(dead_code)]
     struct Foo {}
 
     /// A meaningless struct.
     #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
     pub struct Bar {}
 
+    fn assert_serialized_envelope(headers: Vec<Header>, expected_headers: &str) {
+        let envelope = Envelope {
+            headers,
+            body: Foo {},
+        };
+
+        let expected = minify_xml(&format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <soap:Header>{expected_headers}</soap:Header>
+              <soap:Body><Foo/></soap:Body>
+            </soap:Envelope>"#
+        ));
+
+        let actual = envelope
+            .as_xml_document()
+            .expect("serialization should succeed");
+        assert_eq!(std::str::from_utf8(&actual).unwrap(), expected);
+    }
+
+    #[test]
+    fn serialize_envelope_with_exchange_impersonation() {
+        let cases = [
+            (
+                ConnectingSid::PrincipalName("user@example.com".to_string()),
+                "<t:PrincipalName>user@example.com</t:PrincipalName>",
+            ),
+            (
+                ConnectingSid::SID("S-1-5-21-1234-5678-9012-1001".to_string()),
+                "<t:SID>S-1-5-21-1234-5678-9012-1001</t:SID>",
+            ),
+            (
+                ConnectingSid::PrimarySmtpAddress("user@example.com".to_string()),
+                "<t:PrimarySmtpAddress>user@example.com</t:PrimarySmtpAddress>",
+            ),
+            (
+                ConnectingSid::SmtpAddress("alias@example.com".to_string()),
+                "<t:SmtpAddress>alias@example.com</t:SmtpAddress>",
+            ),
+        ];
+
+        for (connecting_sid, expected) in cases {
+            assert_serialized_envelope(
+                vec![Header::ExchangeImpersonation {
+                    connecting_SID: connecting_sid,
+                }],
+                &format!(
+                    "<t:ExchangeImpersonation><t:ConnectingSID>{expected}</t:ConnectingSID></t:ExchangeImpersonation>"
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_envelope_with_request_headers() {
+        let headers = vec![
+            Header::MailboxCulture {
+                culture: "en-US".to_string(),
+            },
+            Header::DateTimePrecision {
+                precision: DateTimePrecision::Milliseconds,
+            },
+            Header::ManagementRole {
+                user_roles: Some(Roles {
+                    inner: vec!["MailboxSearch".to_string()],
+                }),
+                application_roles: Some(Roles {
+                    inner: vec![
+                        "UserApplication".to_string(),
+                        "ArchiveApplication".to_string(),
+                    ],
+                }),
+            },
+        ];
+
+        let expected = r#"
+            <t:MailboxCulture>en-US</t:MailboxCulture>
+            <t:DateTimePrecision>Milliseconds</t:DateTimePrecision>
+            <t:ManagementRole>
+              <t:UserRoles>
+                <t:Role>MailboxSearch</t:Role>
+              </t:UserRoles>
+              <t:ApplicationRoles>
+                <t:Role>UserApplication</t:Role>
+                <t:Role>ArchiveApplication</t:Role>
+              </t:ApplicationRoles>
+            </t:ManagementRole>"#;
+
+        assert_serialized_envelope(headers, expected);
+    }
+
+    #[test]
+    fn validate_envelope_version() {
+        let envelope = Envelope {
+            headers: vec![
+                Header::RequestServerVersion {
+                    version: ExchangeServerVersion::Exchange2007_SP1,
+                },
+                Header::TimeZoneContext {
+                    time_zone_definition: TimeZoneDefinition {
+                        id: Some("Pacific Standard Time".to_string()),
+                        ..Default::default()
+                    },
+                },
+                Header::MailboxCulture {
+                    culture: "en-US".to_string(),
+                },
+            ],
+            body: CreateItem {
+                message_disposition: Some(MessageDisposition::SaveOnly),
+                saved_item_folder_id: None,
+                items: vec![RealItem::Message(Message {
+                    subject: Some("Hello".to_string()),
+                    preview: Some("Hello world".to_string()),
+                    start_time_zone: Some(TimeZoneDefinition::default()),
+                    ..Default::default()
+                })],
+            },
+        };
+
+        let err = envelope
+            .validate_version()
+            .expect_err("validation should fail for Exchange2007_SP1");
+        let Error::UnsupportedByServerVersion {
+            version,
+            unsupported,
+        } = &err
+        else {
+            panic!("unexpected error {err:?}");
+        };
+
+        assert_eq!(*version, ExchangeServerVersion::Exchange2007_SP1);
+        assert_eq!(
+            unsupported,
+            &[
+                UnsupportedElement {
+                    path: "Header/TimeZoneContext".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2010,
+                },
+                UnsupportedElement {
+                    path: "CreateItem/Items/Message/Preview".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2013,
+                },
+                UnsupportedElement {
+                    path: "CreateItem/Items/Message/StartTimeZone".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2010,
+                },
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "request is not supported by server version Exchange2007_SP1: \
+            Header/TimeZoneContext (requires Exchange2010), \
+            CreateItem/Items/Message/Preview (requires Exchange2013), \
+            CreateItem/Items/Message/StartTimeZone (requires Exchange2010)"
+        );
+
+        assert_eq!(
+            envelope.unsupported_elements(&ExchangeServerVersion::Exchange2010_SP2),
+            vec![UnsupportedElement {
+                path: "CreateItem/Items/Message/Preview".to_string(),
+                min_version: ExchangeServerVersion::Exchange2013,
+            }]
+        );
+        assert!(envelope
+            .unsupported_elements(&ExchangeServerVersion::Exchange2013)
+            .is_empty());
+    }
+
+    #[test]
+    fn validate_envelope_operation_version() {
+        let envelope = Envelope {
+            headers: vec![],
+            body: MarkAsJunk {
+                is_junk: true,
+                move_item: false,
+                item_ids: vec![],
+            },
+        };
+
+        assert_eq!(
+            envelope.request_server_version(),
+            ExchangeServerVersion::Exchange2007
+        );
+        assert!(matches!(
+            envelope.validate_version(),
+            Err(Error::UnsupportedByServerVersion { unsupported, .. })
+                if unsupported == [UnsupportedElement {
+                    path: "MarkAsJunk".to_string(),
+                    min_version: ExchangeServerVersion::Exchange2013,
+                }]
+        ));
+
+        let envelope = Envelope {
+            headers: vec![Header::RequestServerVersion {
+                version: ExchangeServerVersion::Exchange2016,
+            }],
+            ..envelope
+        };
+        assert!(envelope.validate_version().is_ok());
+    }
+
+    #[test]
+    fn downgrade_envelope_version() {
+        let envelope = Envelope {
+            headers: vec![
+                Header::RequestServerVersion {
+                    version: ExchangeServerVersion::Exchange2016,
+                },
+                Header::DateTimePrecision {
+                    precision: DateTimePrecision::Milliseconds,
+                },
+                Header::MailboxCulture {
+                    culture: "en-US".to_string(),
+                },
+            ],
+            body: CreateItem {
+                message_disposition: Some(MessageDisposition::SaveOnly),
+                saved_item_folder_id: None,
+                items: vec![RealItem::Message(Message {
+                    subject: Some("Hello".to_string()),
+                    preview: Some("Hello world".to_string()),
+                    ..Default::default()
+                })],
+            },
+        };
+
+        let server_version = ServerVersionInfo {
+            major_version: Some("8".to_string()),
+            minor_version: Some("3".to_string()),
+            major_build_number: Some("83".to_string()),
+            minor_build_number: Some("6".to_string()),
+            version: None,
+        };
+
+        let downgraded = envelope
+            .downgrade(&server_version)
+            .expect("downgrading should succeed");
+        let actual = downgraded.as_xml_document().unwrap();
+
+        let expected = minify_xml(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <soap:Header>
+                <t:RequestServerVersion Version="Exchange2007_SP1"/>
+                <t:MailboxCulture>en-US</t:MailboxCulture>
+              </soap:Header>
+              <soap:Body>
+                <CreateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly">
+                  <Items>
+                    <t:Message>
+                      <t:Subject>Hello</t:Subject>
+                    </t:Message>
+                  </Items>
+                </CreateItem>
+              </soap:Body>
+            </soap:Envelope>"#,
+        );
+        assert_eq!(std::str::from_utf8(&actual).unwrap(), expected);
+
+        // The original envelope is left untouched.
+        assert_eq!(
+            envelope.request_server_version(),
+            ExchangeServerVersion::Exchange2016
+        );
+        assert_eq!(envelope.headers.len(), 3);
+
+        assert!(matches!(
+            downgraded.downgrade(&server_version),
+            Err(Error::NoOlderServerVersion(
+                ExchangeServerVersion::Exchange2007_SP1
+            ))
+        ));
+    }
+
+    #[test]
+    fn downgrade_update_item_envelope() {
+        let envelope = Envelope {
+            headers: vec![Header::RequestServerVersion {
+                version: ExchangeServerVersion::Exchange2013,
+            }],
+            body: UpdateItem {
+                message_disposition: MessageDisposition::SaveOnly,
+                conflict_resolution: None,
+                item_changes: vec![ItemChange {
+                    item_change: ItemChangeInner {
+                        item_id: BaseItemId::ItemId {
+                            id: "AAMkADAwATM0MDAAMS1p".to_string(),
+                            change_key: None,
+                        },
+                        updates: Updates {
+                            inner: vec![ItemChangeDescription::SetItemField {
+                                field_uri: PathToElement::FieldURI {
+                                    field_URI: "item:Subject".to_string(),
+                                },
+                                message: Message {
+                                    subject: Some("Hello".to_string()),
+                                    preview: Some("Hello world".to_string()),
+                                    text_body: Some(Body {
+                                        body_type: BodyType::Text,
+                                        is_truncated: None,
+                                        content: Some("Hello world".to_string()),
+                                    }),
+                                    ..Default::default()
+                                },
+                            }],
+                        },
+                    },
+                }],
+            },
+        };
+
+        let server_version = ServerVersionInfo {
+            version: Some("Exchange2010_SP2".to_string()),
+            ..Default::default()
+        };
+
+        let downgraded = envelope
+            .downgrade(&server_version)
+            .expect("downgrading should succeed");
+
+        assert_eq!(
+            downgraded.request_server_version(),
+            ExchangeServerVersion::Exchange2010_SP2
+        );
+        let ItemChangeDescription::SetItemField { message, .. } =
+            &downgraded.body.item_changes[0].item_change.updates.inner[0];
+        assert_eq!(message.subject.as_deref(), Some("Hello"));
+        assert_eq!(message.preview, None);
+        assert_eq!(message.text_body, None);
+    }
+
+    #[test]
+    fn downgrade_envelope_with_unsupported_elements() {
+        let envelope = Envelope {
+            headers: vec![
+                Header::RequestServerVersion {
+                    version: ExchangeServerVersion::Exchange2016,
+                },
+                Header::TimeZoneContext {
+                    time_zone_definition: TimeZoneDefinition {
+                        id: Some("Pacific Standard Time".to_string()),
+                        ..Default::default()
+                    },
+                },
+                Header::ManagementRole {
+                    user_roles: Some(Roles {
+                        inner: vec!["UserOptions".to_string()],
+                    }),
+                    application_roles: None,
+                },
+            ],
+            body: CreateItem {
+                message_disposition: Some(MessageDisposition::SaveOnly),
+                saved_item_folder_id: None,
+                items: vec![RealItem::Message(Message {
+                    subject: Some("Hello".to_string()),
+                    preview: Some("Hello world".to_string()),
+                    start_time_zone: Some(TimeZoneDefinition::default()),
+                    ..Default::default()
+                })],
+            },
+        };
+
+        let server_version = ServerVersionInfo {
+            version: Some("Exchange2007_SP1".to_string()),
+            ..Default::default()
+        };
+
+        // Headers and fields which change the meaning of the request are
+        // reported rather than silently removed, unlike the read-only
+        // `Preview`.
+        let err = envelope.downgrade(&server_version).unwrap_err();
+        let Error::UnsupportedByServerVersion { unsupported, .. } = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        let paths: Vec<_> = unsupported
+            .iter()
+            .map(|element| element.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "Header/TimeZoneContext",
+                "Header/ManagementRole",
+                "CreateItem/Items/Message/StartTimeZone",
+            ]
+        );
+    }
+
+    #[test]
+    fn downgrade_envelope_with_unsupported_operation() {
+        let envelope = Envelope {
+            headers: vec![Header::RequestServerVersion {
+                version: ExchangeServerVersion::Exchange2013,
+            }],
+            body: MarkAsJunk {
+                is_junk: true,
+                move_item: false,
+                item_ids: vec![],
+            },
+        };
+
+        let server_version = ServerVersionInfo {
+            version: Some("Exchange2010_SP2".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            envelope.downgrade(&server_version),
+            Err(Error::UnsupportedByServerVersion { version, .. })
+                if version == ExchangeServerVersion::Exchange2010_SP2
+        ));
+        assert_eq!(
+            envelope.request_server_version(),
+            ExchangeServerVersion::Exchange2013
+        );
+    }
+
     #[test]
     fn deserialize_envelope_with_schema_fault() {
         // This test will require significant changes if we add SchemaValidation
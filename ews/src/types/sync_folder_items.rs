@@ -6,6 +6,7 @@ use ews_proc_macros::operation_response;
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::{BaseFolderId, BaseItemId, ItemId, ItemShape, RealItem, MESSAGES_NS_URI};
 
 /// A request for a list of items which have been created, updated, or deleted
@@ -14,7 +15,7 @@ use crate::{BaseFolderId, BaseItemId, ItemId, ItemShape, RealItem, MESSAGES_NS_U
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/syncfolderitems>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(SyncFolderItemsResponseMessage)]
+#[operation_response(SyncFolderItemsResponseMessage, manual_version_requirements)]
 pub struct SyncFolderItems {
     /// A description of the information to be included in the response for each
     /// changed item.
@@ -46,6 +47,22 @@ pub struct SyncFolderItems {
     pub sync_scope: Option<SyncScope>,
 }
 
+impl VersionRequirements for SyncFolderItems {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        self.item_shape
+            .check_version(version, &format!("{path}/ItemShape"), unsupported);
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        self.item_shape.strip_unsupported(version);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
 pub struct SyncFolderItemsResponseMessage {
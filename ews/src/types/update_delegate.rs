@@ -0,0 +1,94 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    delegates::{DelegateResponseMessage, DelegateUsers, DeliverMeetingRequests},
+    Mailbox, MESSAGES_NS_URI,
+};
+
+/// A request to change the permissions of existing delegates of a mailbox, or
+/// the delivery of meeting requests to them.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updatedelegate>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    DelegateResponseMessage,
+    min_version = Exchange2007_SP1,
+    shape = ResponseClass
+)]
+pub struct UpdateDelegate {
+    /// The mailbox whose delegates to update.
+    pub mailbox: Mailbox,
+
+    /// The delegates to update.
+    pub delegate_users: Option<DelegateUsers>,
+
+    /// The recipients of meeting requests sent to the mailbox.
+    pub deliver_meeting_requests: Option<DeliverMeetingRequests>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        delegates::{
+            DelegateFolderPermissionLevel, DelegatePermissions, DelegateUsers,
+            DeliverMeetingRequests,
+        },
+        test_utils::{assert_serialized_content, minify_xml},
+        update_delegate::UpdateDelegate,
+        DelegateUser, Mailbox, UserId,
+    };
+
+    #[test]
+    fn test_serialize_update_delegate() {
+        let update_delegate = UpdateDelegate {
+            mailbox: Mailbox {
+                email_address: Some("owner@example.com".to_string()),
+                ..Default::default()
+            },
+            delegate_users: Some(DelegateUsers {
+                inner: vec![DelegateUser {
+                    user_id: UserId {
+                        primary_smtp_address: Some("assistant@example.com".to_string()),
+                        ..Default::default()
+                    },
+                    delegate_permissions: Some(DelegatePermissions {
+                        tasks_folder_permission_level: Some(DelegateFolderPermissionLevel::Author),
+                        ..Default::default()
+                    }),
+                    receive_copies_of_meeting_messages: None,
+                    view_private_items: Some(true),
+                }],
+            }),
+            deliver_meeting_requests: Some(DeliverMeetingRequests::DelegatesOnly),
+        };
+
+        let expected = minify_xml(
+            r#"
+            <UpdateDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <Mailbox>
+                <t:EmailAddress>owner@example.com</t:EmailAddress>
+              </Mailbox>
+              <DelegateUsers>
+                <t:DelegateUser>
+                  <t:UserId>
+                    <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                  </t:UserId>
+                  <t:DelegatePermissions>
+                    <t:TasksFolderPermissionLevel>Author</t:TasksFolderPermissionLevel>
+                  </t:DelegatePermissions>
+                  <t:ViewPrivateItems>true</t:ViewPrivateItems>
+                </t:DelegateUser>
+              </DelegateUsers>
+              <DeliverMeetingRequests>DelegatesOnly</DeliverMeetingRequests>
+            </UpdateDelegate>"#,
+        );
+
+        assert_serialized_content(&update_delegate, "UpdateDelegate", &expected);
+    }
+}
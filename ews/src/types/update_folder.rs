@@ -6,6 +6,7 @@ use ews_proc_macros::operation_response;
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::{BaseFolderId, MESSAGES_NS_URI};
 
 use super::{Folder, Folders, PathToElement};
@@ -13,7 +14,7 @@ use super::{Folder, Folders, PathToElement};
 /// The unique identifier of an update to be performed on a folder.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updates-folder>
-#[derive(Debug, XmlSerialize)]
+#[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(variant_ns_prefix = "t")]
 pub enum Updates {
     /// Not implemented in EWS (as per the documentation page for this element), but still an option
@@ -25,8 +26,10 @@ pub enum Updates {
     SetFolderField {
         #[xml_struct(ns_prefix = "t", flatten)]
         field_URI: PathToElement,
+        // The `Folder` is boxed so as to keep the in-memory size of the enum
+        // itself relatively low.
         #[xml_struct(ns_prefix = "t", flatten)]
-        folder: Folder,
+        folder: Box<Folder>,
     },
 
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deletefolderfield>
@@ -37,7 +40,7 @@ pub enum Updates {
     },
 }
 
-#[derive(Debug, XmlSerialize)]
+#[derive(Clone, Debug, XmlSerialize)]
 pub struct FolderChanges {
     #[xml_struct(ns_prefix = "t")]
     pub folder_change: FolderChange,
@@ -46,7 +49,7 @@ pub struct FolderChanges {
 /// A collection of changes to be performed on a folder.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/folderchange>.
-#[derive(Debug, XmlSerialize)]
+#[derive(Clone, Debug, XmlSerialize)]
 pub struct FolderChange {
     /// The folder to be updated.
     ///
@@ -64,13 +67,38 @@ pub struct FolderChange {
 /// An operation to update a given property of a specified folder.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updatefolder>.
-#[derive(Debug, XmlSerialize)]
+#[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(UpdateFolderResponseMessage)]
+#[operation_response(UpdateFolderResponseMessage, manual_version_requirements)]
 pub struct UpdateFolder {
     pub folder_changes: FolderChanges,
 }
 
+impl VersionRequirements for UpdateFolder {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        if let Updates::SetFolderField { folder, .. } = &self.folder_changes.folder_change.updates {
+            folder.check_version(
+                version,
+                &format!("{path}/FolderChanges/FolderChange/Updates/SetFolderField"),
+                unsupported,
+            );
+        }
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        if let Updates::SetFolderField { folder, .. } =
+            &mut self.folder_changes.folder_change.updates
+        {
+            folder.strip_unsupported(version);
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct UpdateFolderResponseMessage {
@@ -80,8 +108,14 @@ pub struct UpdateFolderResponseMessage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::permissions::{
+        Permission, PermissionActionType, PermissionLevel, PermissionReadAccess, Permissions,
+    };
     use crate::test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml};
-    use crate::{BaseFolderId, FolderId, ResponseClass, ResponseMessages};
+    use crate::{
+        BaseFolderId, DistinguishedUser, EffectiveRights, FolderId, PermissionSet, ResponseClass,
+        ResponseMessages, RetentionTag, UserId,
+    };
 
     #[test]
     fn serialize_update_request() {
@@ -96,7 +130,7 @@ mod tests {
                         field_URI: PathToElement::FieldURI {
                             field_URI: "folder:DisplayName".to_string(),
                         },
-                        folder: Folder::Folder {
+                        folder: Box::new(Folder::Folder {
                             display_name: Some("NewFolderName".to_string()),
                             folder_id: None,
                             parent_folder_id: None,
@@ -104,8 +138,12 @@ mod tests {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: None,
                             unread_count: None,
-                        },
+                        }),
                     },
                 },
             },
@@ -133,6 +171,239 @@ mod tests {
         assert_serialized_content(&update_folder, "UpdateFolder", &expected);
     }
 
+    #[test]
+    fn serialize_update_permission_set() {
+        let update_folder = UpdateFolder {
+            folder_changes: FolderChanges {
+                folder_change: FolderChange {
+                    folder_id: BaseFolderId::FolderId {
+                        id: "AScB".to_string(),
+                        change_key: None,
+                    },
+                    updates: Updates::SetFolderField {
+                        field_URI: PathToElement::FieldURI {
+                            field_URI: "folder:PermissionSet".to_string(),
+                        },
+                        folder: Box::new(Folder::Folder {
+                            folder_id: None,
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: None,
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: Some(PermissionSet {
+                                permissions: Permissions {
+                                    inner: vec![
+                                        Permission {
+                                            user_id: UserId {
+                                                distinguished_user: Some(
+                                                    DistinguishedUser::Default,
+                                                ),
+                                                ..Default::default()
+                                            },
+                                            permission_level: PermissionLevel::Reviewer,
+                                            ..Default::default()
+                                        },
+                                        Permission {
+                                            user_id: UserId {
+                                                primary_smtp_address: Some(
+                                                    "colleague@example.com".to_string(),
+                                                ),
+                                                ..Default::default()
+                                            },
+                                            can_create_items: Some(true),
+                                            edit_items: Some(PermissionActionType::Owned),
+                                            delete_items: Some(PermissionActionType::Owned),
+                                            read_items: Some(PermissionReadAccess::FullDetails),
+                                            permission_level: PermissionLevel::Custom,
+                                            ..Default::default()
+                                        },
+                                    ],
+                                },
+                                unknown_entries: None,
+                            }),
+                            unread_count: None,
+                        }),
+                    },
+                },
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <UpdateFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <FolderChanges>
+                <t:FolderChange>
+                  <t:FolderId Id="AScB"/>
+                  <t:Updates>
+                    <t:SetFolderField>
+                      <t:FieldURI FieldURI="folder:PermissionSet"/>
+                      <t:Folder>
+                        <t:PermissionSet>
+                          <t:Permissions>
+                            <t:Permission>
+                              <t:UserId>
+                                <t:DistinguishedUser>Default</t:DistinguishedUser>
+                              </t:UserId>
+                              <t:PermissionLevel>Reviewer</t:PermissionLevel>
+                            </t:Permission>
+                            <t:Permission>
+                              <t:UserId>
+                                <t:PrimarySmtpAddress>colleague@example.com</t:PrimarySmtpAddress>
+                              </t:UserId>
+                              <t:CanCreateItems>true</t:CanCreateItems>
+                              <t:EditItems>Owned</t:EditItems>
+                              <t:DeleteItems>Owned</t:DeleteItems>
+                              <t:ReadItems>FullDetails</t:ReadItems>
+                              <t:PermissionLevel>Custom</t:PermissionLevel>
+                            </t:Permission>
+                          </t:Permissions>
+                        </t:PermissionSet>
+                      </t:Folder>
+                    </t:SetFolderField>
+                  </t:Updates>
+                </t:FolderChange>
+              </FolderChanges>
+            </UpdateFolder>"#,
+        );
+
+        assert_serialized_content(&update_folder, "UpdateFolder", &expected);
+    }
+
+    fn apply_policy_tag() -> UpdateFolder {
+        UpdateFolder {
+            folder_changes: FolderChanges {
+                folder_change: FolderChange {
+                    folder_id: BaseFolderId::FolderId {
+                        id: "AScC".to_string(),
+                        change_key: None,
+                    },
+                    updates: Updates::SetFolderField {
+                        field_URI: PathToElement::FieldURI {
+                            field_URI: "folder:PolicyTag".to_string(),
+                        },
+                        folder: Box::new(Folder::Folder {
+                            folder_id: None,
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: None,
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            effective_rights: None,
+                            policy_tag: Some(RetentionTag {
+                                is_explicit: true,
+                                retention_id: "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d".to_string(),
+                            }),
+                            archive_tag: None,
+                            permission_set: None,
+                            unread_count: None,
+                        }),
+                    },
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn serialize_apply_policy_tag() {
+        let update_folder = apply_policy_tag();
+
+        let expected = minify_xml(
+            r#"
+            <UpdateFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <FolderChanges>
+                <t:FolderChange>
+                  <t:FolderId Id="AScC"/>
+                  <t:Updates>
+                    <t:SetFolderField>
+                      <t:FieldURI FieldURI="folder:PolicyTag"/>
+                      <t:Folder>
+                        <t:PolicyTag IsExplicit="true">0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d</t:PolicyTag>
+                      </t:Folder>
+                    </t:SetFolderField>
+                  </t:Updates>
+                </t:FolderChange>
+              </FolderChanges>
+            </UpdateFolder>"#,
+        );
+
+        assert_serialized_content(&update_folder, "UpdateFolder", &expected);
+    }
+
+    #[test]
+    fn check_policy_tag_version() {
+        let update_folder = apply_policy_tag();
+
+        let mut unsupported = vec![];
+        update_folder.check_version(
+            &ExchangeServerVersion::Exchange2010,
+            "UpdateFolder",
+            &mut unsupported,
+        );
+        assert_eq!(
+            unsupported,
+            vec![UnsupportedElement {
+                path: "UpdateFolder/FolderChanges/FolderChange/Updates/SetFolderField/Folder/PolicyTag"
+                    .to_string(),
+                min_version: ExchangeServerVersion::Exchange2010_SP1,
+            }]
+        );
+
+        let mut unsupported = vec![];
+        update_folder.check_version(
+            &ExchangeServerVersion::Exchange2010_SP1,
+            "UpdateFolder",
+            &mut unsupported,
+        );
+        assert_eq!(unsupported, vec![]);
+    }
+
+    #[test]
+    fn strip_unsupported_effective_rights() {
+        let mut update_folder = apply_policy_tag();
+        let Updates::SetFolderField { folder, .. } =
+            &mut update_folder.folder_changes.folder_change.updates
+        else {
+            unreachable!();
+        };
+        let Folder::Folder {
+            effective_rights, ..
+        } = folder.as_mut()
+        else {
+            unreachable!();
+        };
+        *effective_rights = Some(EffectiveRights {
+            read: true,
+            view_private_items: Some(true),
+            ..Default::default()
+        });
+
+        update_folder.strip_unsupported(&ExchangeServerVersion::Exchange2010);
+
+        let mut unsupported = vec![];
+        update_folder.check_version(
+            &ExchangeServerVersion::Exchange2010,
+            "UpdateFolder",
+            &mut unsupported,
+        );
+
+        // The policy tag cannot be removed without changing the meaning of the
+        // request, unlike the read-only `ViewPrivateItems`.
+        let paths: Vec<_> = unsupported
+            .iter()
+            .map(|element| element.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            ["UpdateFolder/FolderChanges/FolderChange/Updates/SetFolderField/Folder/PolicyTag"]
+        );
+    }
+
     #[test]
     fn deserialize_update_response() {
         let content = r#"
@@ -167,6 +438,10 @@ mod tests {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            effective_rights: None,
+                            policy_tag: None,
+                            archive_tag: None,
+                            permission_set: None,
                             unread_count: None,
                         }],
                     },
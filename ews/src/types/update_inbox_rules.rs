@@ -0,0 +1,318 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{inbox_rules::RuleOperationErrors, Rule, MESSAGES_NS_URI};
+
+/// A request to create, modify or delete Inbox rules of a mailbox.
+///
+/// If any operation fails validation, none are applied and the response
+/// message lists the failures in its `rule_operation_errors`.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateinboxrules-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    UpdateInboxRulesResponseMessage,
+    min_version = Exchange2010,
+    shape = ResponseClass
+)]
+pub struct UpdateInboxRules {
+    /// The SMTP address of the mailbox whose rules to update, if other than the
+    /// mailbox of the authenticated user.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxsmtpaddress>
+    pub mailbox_smtp_address: Option<String>,
+
+    /// Whether to remove the rules created by Outlook which cannot be
+    /// represented with EWS.
+    ///
+    /// Updating rules fails if such rules exist and this is not set.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/removeoutlookruleblob>
+    pub remove_outlook_rule_blob: Option<bool>,
+
+    /// The operations to perform, in order.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/operations>
+    pub operations: Vec<RuleOperation>,
+}
+
+/// The contents of a response to an `UpdateInboxRules` request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateinboxrulesresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateInboxRulesResponseMessage {
+    /// The validation errors of the rule operations, if any operation failed
+    /// validation.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ruleoperationerrors>
+    pub rule_operation_errors: Option<RuleOperationErrors>,
+}
+
+/// An operation on a single Inbox rule.
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum RuleOperation {
+    /// Creates a new rule. The rule must not have an identifier.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createruleoperation>
+    CreateRuleOperation {
+        #[xml_struct(ns_prefix = "t")]
+        rule: Rule,
+    },
+
+    /// Replaces an existing rule, identified by the identifier of the rule.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/setruleoperation>
+    SetRuleOperation {
+        #[xml_struct(ns_prefix = "t")]
+        rule: Rule,
+    },
+
+    /// Deletes an existing rule.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deleteruleoperation>
+    DeleteRuleOperation {
+        #[xml_struct(ns_prefix = "t")]
+        rule_id: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        inbox_rules::{
+            RuleActions, RuleOperationError, RuleOperationErrors, RulePredicates,
+            RuleValidationError, RuleValidationErrorCode, RuleValidationErrors, TargetFolderId,
+        },
+        response::{ResponseCode, ResponseError},
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        update_inbox_rules::{
+            RuleOperation, UpdateInboxRules, UpdateInboxRulesResponse,
+            UpdateInboxRulesResponseMessage,
+        },
+        ArrayOfEmailAddresses, ArrayOfStrings, BaseFolderId, Mailbox, ResponseClass, Rule,
+    };
+
+    #[test]
+    fn test_serialize_update_inbox_rules() {
+        let update_inbox_rules = UpdateInboxRules {
+            mailbox_smtp_address: None,
+            remove_outlook_rule_blob: Some(true),
+            operations: vec![
+                RuleOperation::CreateRuleOperation {
+                    rule: Rule {
+                        display_name: "From my manager".to_string(),
+                        priority: 1,
+                        is_enabled: true,
+                        conditions: Some(RulePredicates {
+                            from_addresses: Some(ArrayOfEmailAddresses {
+                                inner: vec![Mailbox {
+                                    email_address: Some("manager@example.com".to_string()),
+                                    ..Default::default()
+                                }],
+                            }),
+                            ..Default::default()
+                        }),
+                        actions: Some(RuleActions {
+                            assign_categories: Some(ArrayOfStrings {
+                                inner: vec!["Important".to_string()],
+                            }),
+                            copy_to_folder: Some(TargetFolderId {
+                                folder_id: BaseFolderId::FolderId {
+                                    id: "AAMkADEzOTExYZRAAA=".to_string(),
+                                    change_key: None,
+                                },
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                },
+                RuleOperation::SetRuleOperation {
+                    rule: Rule {
+                        rule_id: Some("AQAAAAAAAAE=".to_string()),
+                        display_name: "Newsletters".to_string(),
+                        priority: 2,
+                        is_enabled: false,
+                        conditions: Some(RulePredicates {
+                            is_NDR: Some(true),
+                            ..Default::default()
+                        }),
+                        actions: Some(RuleActions {
+                            delete: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                },
+                RuleOperation::DeleteRuleOperation {
+                    rule_id: "AQAAAAAAAAI=".to_string(),
+                },
+            ],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <UpdateInboxRules xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <RemoveOutlookRuleBlob>true</RemoveOutlookRuleBlob>
+              <Operations>
+                <t:CreateRuleOperation>
+                  <t:Rule>
+                    <t:DisplayName>From my manager</t:DisplayName>
+                    <t:Priority>1</t:Priority>
+                    <t:IsEnabled>true</t:IsEnabled>
+                    <t:Conditions>
+                      <t:FromAddresses>
+                        <t:Address>
+                          <t:EmailAddress>manager@example.com</t:EmailAddress>
+                        </t:Address>
+                      </t:FromAddresses>
+                    </t:Conditions>
+                    <t:Actions>
+                      <t:AssignCategories>
+                        <t:String>Important</t:String>
+                      </t:AssignCategories>
+                      <t:CopyToFolder>
+                        <t:FolderId Id="AAMkADEzOTExYZRAAA="/>
+                      </t:CopyToFolder>
+                    </t:Actions>
+                  </t:Rule>
+                </t:CreateRuleOperation>
+                <t:SetRuleOperation>
+                  <t:Rule>
+                    <t:RuleId>AQAAAAAAAAE=</t:RuleId>
+                    <t:DisplayName>Newsletters</t:DisplayName>
+                    <t:Priority>2</t:Priority>
+                    <t:IsEnabled>false</t:IsEnabled>
+                    <t:Conditions>
+                      <t:IsNDR>true</t:IsNDR>
+                    </t:Conditions>
+                    <t:Actions>
+                      <t:Delete>true</t:Delete>
+                    </t:Actions>
+                  </t:Rule>
+                </t:SetRuleOperation>
+                <t:DeleteRuleOperation>
+                  <t:RuleId>AQAAAAAAAAI=</t:RuleId>
+                </t:DeleteRuleOperation>
+              </Operations>
+            </UpdateInboxRules>"#,
+        );
+
+        assert_serialized_content(&update_inbox_rules, "UpdateInboxRules", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_update_inbox_rules_response() {
+        let content = r#"<UpdateInboxRulesResponse ResponseClass="Success" xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <ResponseCode>NoError</ResponseCode>
+                </UpdateInboxRulesResponse>"#;
+
+        let expected = UpdateInboxRulesResponse {
+            response_message: ResponseClass::Success(UpdateInboxRulesResponseMessage {
+                rule_operation_errors: None,
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn test_deserialize_update_inbox_rules_validation_error() {
+        let content = r#"<UpdateInboxRulesResponse ResponseClass="Error" xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <MessageText>Validation error occurred while processing the rule.</MessageText>
+                  <ResponseCode>ErrorInboxRulesValidationError</ResponseCode>
+                  <DescriptiveLinkKey>0</DescriptiveLinkKey>
+                  <RuleOperationErrors>
+                    <t:RuleOperationError xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                      <t:OperationIndex>0</t:OperationIndex>
+                      <t:ValidationErrors>
+                        <t:Error>
+                          <t:FieldURI>MoveToFolder</t:FieldURI>
+                          <t:ErrorCode>InvalidFolderId</t:ErrorCode>
+                          <t:ErrorMessage>The folder ID is invalid.</t:ErrorMessage>
+                          <t:FieldValue>AAMkADEzOTExYZRAAA=</t:FieldValue>
+                        </t:Error>
+                        <t:Error>
+                          <t:FieldURI>SendSMSAlertToRecipients</t:FieldURI>
+                          <t:ErrorCode>SmsNotConfigured</t:ErrorCode>
+                          <t:ErrorMessage>Text messaging is not configured.</t:ErrorMessage>
+                          <t:FieldValue>+15555550100</t:FieldValue>
+                        </t:Error>
+                      </t:ValidationErrors>
+                    </t:RuleOperationError>
+                  </RuleOperationErrors>
+                </UpdateInboxRulesResponse>"#;
+
+        let expected = UpdateInboxRulesResponse {
+            response_message: ResponseClass::Error(ResponseError {
+                message_text: "Validation error occurred while processing the rule.".to_string(),
+                response_code: ResponseCode::ErrorInboxRulesValidationError,
+                message_xml: None,
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn test_deserialize_rule_operation_errors() {
+        let content = r#"<UpdateInboxRulesResponse xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                  <MessageText>Validation error occurred while processing the rule.</MessageText>
+                  <ResponseCode>ErrorInboxRulesValidationError</ResponseCode>
+                  <DescriptiveLinkKey>0</DescriptiveLinkKey>
+                  <RuleOperationErrors>
+                    <t:RuleOperationError xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                      <t:OperationIndex>0</t:OperationIndex>
+                      <t:ValidationErrors>
+                        <t:Error>
+                          <t:FieldURI>MoveToFolder</t:FieldURI>
+                          <t:ErrorCode>InvalidFolderId</t:ErrorCode>
+                          <t:ErrorMessage>The folder ID is invalid.</t:ErrorMessage>
+                          <t:FieldValue>AAMkADEzOTExYZRAAA=</t:FieldValue>
+                        </t:Error>
+                        <t:Error>
+                          <t:FieldURI>SendSMSAlertToRecipients</t:FieldURI>
+                          <t:ErrorCode>SmsNotConfigured</t:ErrorCode>
+                          <t:ErrorMessage>Text messaging is not configured.</t:ErrorMessage>
+                          <t:FieldValue>+15555550100</t:FieldValue>
+                        </t:Error>
+                      </t:ValidationErrors>
+                    </t:RuleOperationError>
+                  </RuleOperationErrors>
+                </UpdateInboxRulesResponse>"#;
+
+        let expected = UpdateInboxRulesResponseMessage {
+            rule_operation_errors: Some(RuleOperationErrors {
+                inner: vec![RuleOperationError {
+                    operation_index: 0,
+                    validation_errors: RuleValidationErrors {
+                        inner: vec![
+                            RuleValidationError {
+                                field_URI: "MoveToFolder".to_string(),
+                                error_code: RuleValidationErrorCode::InvalidFolderId,
+                                error_message: "The folder ID is invalid.".to_string(),
+                                field_value: "AAMkADEzOTExYZRAAA=".to_string(),
+                            },
+                            RuleValidationError {
+                                field_URI: "SendSMSAlertToRecipients".to_string(),
+                                error_code: RuleValidationErrorCode::Unknown,
+                                error_message: "Text messaging is not configured.".to_string(),
+                                field_value: "+15555550100".to_string(),
+                            },
+                        ],
+                    },
+                }],
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -6,6 +6,7 @@ use ews_proc_macros::operation_response;
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
+use crate::server_version::{ExchangeServerVersion, UnsupportedElement, VersionRequirements};
 use crate::types::common::{BaseItemId, Message, MessageDisposition, PathToElement};
 use crate::{Items, MESSAGES_NS_URI};
 
@@ -14,7 +15,7 @@ use crate::{Items, MESSAGES_NS_URI};
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateitem>
 #[derive(Clone, Debug, XmlSerialize)]
 #[xml_struct(default_ns = MESSAGES_NS_URI)]
-#[operation_response(UpdateItemResponseMessage)]
+#[operation_response(UpdateItemResponseMessage, manual_version_requirements)]
 pub struct UpdateItem {
     /// The action the Exchange server will take upon updating this item.
     ///
@@ -41,6 +42,40 @@ pub struct UpdateItem {
     pub item_changes: Vec<ItemChange>,
 }
 
+impl VersionRequirements for UpdateItem {
+    fn check_version(
+        &self,
+        version: &ExchangeServerVersion,
+        path: &str,
+        unsupported: &mut Vec<UnsupportedElement>,
+    ) {
+        let path = format!("{path}/ItemChanges/ItemChange/Updates");
+        for change in &self.item_changes {
+            for update in &change.item_change.updates.inner {
+                match update {
+                    ItemChangeDescription::SetItemField { message, .. } => message.check_version(
+                        version,
+                        &format!("{path}/SetItemField/Message"),
+                        unsupported,
+                    ),
+                }
+            }
+        }
+    }
+
+    fn strip_unsupported(&mut self, version: &ExchangeServerVersion) {
+        for change in &mut self.item_changes {
+            for update in &mut change.item_change.updates.inner {
+                match update {
+                    ItemChangeDescription::SetItemField { message, .. } => {
+                        message.strip_unsupported(version)
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct UpdateItemResponseMessage {
@@ -112,3 +147,62 @@ pub enum ItemChangeDescription {
         message: Message,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_serialized_content, minify_xml};
+    use crate::RetentionTag;
+
+    #[test]
+    fn serialize_apply_policy_tag() {
+        let update_item = UpdateItem {
+            message_disposition: MessageDisposition::SaveOnly,
+            conflict_resolution: Some(ConflictResolution::AlwaysOverwrite),
+            item_changes: vec![ItemChange {
+                item_change: ItemChangeInner {
+                    item_id: BaseItemId::ItemId {
+                        id: "AAMkA=".to_string(),
+                        change_key: Some("CQAAAB".to_string()),
+                    },
+                    updates: Updates {
+                        inner: vec![ItemChangeDescription::SetItemField {
+                            field_uri: PathToElement::FieldURI {
+                                field_URI: "item:PolicyTag".to_string(),
+                            },
+                            message: Message {
+                                policy_tag: Some(RetentionTag {
+                                    is_explicit: true,
+                                    retention_id: "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d"
+                                        .to_string(),
+                                }),
+                                ..Default::default()
+                            },
+                        }],
+                    },
+                },
+            }],
+        };
+
+        let expected = minify_xml(
+            r#"
+            <UpdateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly" ConflictResolution="AlwaysOverwrite">
+              <ItemChanges>
+                <t:ItemChange>
+                  <t:ItemId Id="AAMkA=" ChangeKey="CQAAAB"/>
+                  <t:Updates>
+                    <t:SetItemField>
+                      <t:FieldURI FieldURI="item:PolicyTag"/>
+                      <t:Message>
+                        <t:PolicyTag IsExplicit="true">0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d</t:PolicyTag>
+                      </t:Message>
+                    </t:SetItemField>
+                  </t:Updates>
+                </t:ItemChange>
+              </ItemChanges>
+            </UpdateItem>"#,
+        );
+
+        assert_serialized_content(&update_item, "UpdateItem", &expected);
+    }
+}
@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{UserConfiguration, MESSAGES_NS_URI};
+
+/// A request to replace the contents of a user configuration object.
+///
+/// Parts of the configuration object which are omitted are left unchanged.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateuserconfiguration-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(UpdateUserConfigurationResponseMessage, min_version = Exchange2010)]
+pub struct UpdateUserConfiguration {
+    /// The configuration object to update and its new contents.
+    pub user_configuration: UserConfiguration,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserConfigurationResponseMessage {}
@@ -0,0 +1,196 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Base64Binary, FolderId, ItemId, MESSAGES_NS_URI};
+
+/// A request to import items from full-fidelity data streams, such as those
+/// produced by [`ExportItems`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/uploaditems-operation>
+///
+/// [`ExportItems`]: crate::export_items::ExportItems
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(UploadItemsResponseMessage, min_version = Exchange2010_SP1)]
+pub struct UploadItems {
+    /// The items to import.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/items-nonemptyarrayofuploaditemstype>
+    pub items: ArrayOfUploadItems,
+}
+
+/// A list of items to import.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArrayOfUploadItems {
+    pub inner: Vec<UploadItem>,
+}
+
+impl XmlSerialize for ArrayOfUploadItems {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for item in &self.inner {
+            item.serialize_as_element(writer, "t:Item")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An item to import.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/item-uploaditemtype>
+#[derive(Clone, Debug, XmlSerialize, PartialEq, Eq)]
+pub struct UploadItem {
+    /// Whether to create a new item or to overwrite an existing one.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/item-uploaditemtype#attributes>
+    #[xml_struct(attribute)]
+    pub create_action: CreateAction,
+
+    /// Whether the item is an associated item, e.g. a hidden configuration
+    /// object, rather than a normal item.
+    #[xml_struct(attribute)]
+    pub is_associated: Option<bool>,
+
+    /// The folder in which to create the item.
+    #[xml_struct(ns_prefix = "t")]
+    pub parent_folder_id: FolderId,
+
+    /// The ID of the item to overwrite.
+    ///
+    /// Required when the action is [`CreateAction::Update`] or
+    /// [`CreateAction::UpdateOrCreate`].
+    #[xml_struct(ns_prefix = "t")]
+    pub item_id: Option<ItemId>,
+
+    /// The contents of the item, as returned by [`ExportItems`].
+    ///
+    /// [`ExportItems`]: crate::export_items::ExportItems
+    #[xml_struct(ns_prefix = "t")]
+    pub data: Base64Binary,
+}
+
+/// The action to take when importing an item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/item-uploaditemtype#attributes>
+#[derive(Clone, Copy, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum CreateAction {
+    /// Create a new item with a new ID.
+    CreateNew,
+
+    /// Overwrite an existing item, failing if it does not exist.
+    Update,
+
+    /// Overwrite an existing item, creating it if it does not exist.
+    UpdateOrCreate,
+}
+
+/// A response to a request for an individual item within an [`UploadItems`]
+/// operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/uploaditemsresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UploadItemsResponseMessage {
+    /// The ID of the imported item, omitted if the upload failed.
+    pub item_id: Option<ItemId>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content, minify_xml},
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn test_serialize_upload_items() {
+        let upload_items = UploadItems {
+            items: ArrayOfUploadItems {
+                inner: vec![
+                    UploadItem {
+                        create_action: CreateAction::CreateNew,
+                        is_associated: Some(false),
+                        parent_folder_id: FolderId {
+                            id: "AAMkADAwATM0MDAAMS1n".to_string(),
+                            change_key: None,
+                        },
+                        item_id: None,
+                        data: Base64Binary(vec![0, 0, 0, 0, 1, 0, 0, 0]),
+                    },
+                    UploadItem {
+                        create_action: CreateAction::Update,
+                        is_associated: None,
+                        parent_folder_id: FolderId {
+                            id: "AAMkADAwATM0MDAAMS1n".to_string(),
+                            change_key: None,
+                        },
+                        item_id: Some(ItemId {
+                            id: "AAMkADAwATM0MDAAMS1o".to_string(),
+                            change_key: Some("CQAAABYAAAAA".to_string()),
+                        }),
+                        data: Base64Binary(vec![0, 0, 0, 0, 2, 0, 0, 0]),
+                    },
+                ],
+            },
+        };
+
+        let expected = minify_xml(
+            r#"
+            <UploadItems xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+              <Items>
+                <t:Item CreateAction="CreateNew" IsAssociated="false">
+                  <t:ParentFolderId Id="AAMkADAwATM0MDAAMS1n"/>
+                  <t:Data>AAAAAAEAAAA=</t:Data>
+                </t:Item>
+                <t:Item CreateAction="Update">
+                  <t:ParentFolderId Id="AAMkADAwATM0MDAAMS1n"/>
+                  <t:ItemId Id="AAMkADAwATM0MDAAMS1o" ChangeKey="CQAAABYAAAAA"/>
+                  <t:Data>AAAAAAIAAAA=</t:Data>
+                </t:Item>
+              </Items>
+            </UploadItems>"#,
+        );
+
+        assert_serialized_content(&upload_items, "UploadItems", &expected);
+    }
+
+    #[test]
+    fn test_deserialize_upload_items_response() {
+        let content = r#"
+            <m:UploadItemsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                                   xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <m:ResponseMessages>
+                <m:UploadItemsResponseMessage ResponseClass="Success">
+                  <m:ResponseCode>NoError</m:ResponseCode>
+                  <m:ItemId Id="AAMkADAwATM0MDAAMS1p" ChangeKey="CQAAABYAAAAB"/>
+                </m:UploadItemsResponseMessage>
+              </m:ResponseMessages>
+            </m:UploadItemsResponse>"#;
+
+        let expected = UploadItemsResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(UploadItemsResponseMessage {
+                    item_id: Some(ItemId {
+                        id: "AAMkADAwATM0MDAAMS1p".to_string(),
+                        change_key: Some("CQAAABYAAAAB".to_string()),
+                    }),
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
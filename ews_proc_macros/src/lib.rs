@@ -1,16 +1,44 @@
 use proc_macro::TokenStream;
+use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, Ident, ItemStruct};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Expr, Ident, ItemStruct, Meta,
+    Token,
+};
 
 /// Annotate a struct as having its response include response messages of the given type,
 /// and generate a response struct for it with the expected attributes and methods.
 ///
 /// Response structs are named by appending "Response" to the end of the name of this struct.
+///
+/// The response message type may be followed by these options:
+///
+/// - `min_version = <version>`: the oldest `ExchangeServerVersion` supporting the operation,
+///   e.g. `min_version = Exchange2013`. Defaults to `Exchange2007`.
+/// - `manual_version_requirements`: do not generate an empty implementation of
+///   `VersionRequirements`, so that the operation can check its own fields.
+/// - `shape = <shape>`: the structure of the response. `ResponseMessages` (the
+///   default) wraps a list of response messages in a `ResponseMessages`
+///   element, `ResponseClass` denotes a response element which is itself the
+///   single response message, carrying the `ResponseClass` attribute, and
+///   `ResponseMessage` denotes a response element containing a single
+///   `ResponseMessage` element followed by the contents of the response.
+/// - `name = <name>`: the name of the XML element representing the operation,
+///   if other than the name of this struct, e.g. `GetUserOofSettingsRequest`.
+/// - `response_name = <name>`: the name of the XML element representing the
+///   response, if other than the name of the response struct, e.g.
+///   `GetPersonaResponseMessage`.
 #[proc_macro_attribute]
 pub fn operation_response(attr: TokenStream, annotated_item: TokenStream) -> TokenStream {
-    let response_type = parse_macro_input!(attr as Ident);
+    let args = parse_macro_input!(attr with Punctuated::<Meta, Token![,]>::parse_terminated);
     let input_struct = parse_macro_input!(annotated_item as ItemStruct);
 
+    let options = match OperationOptions::from_args(args, input_struct.ident.span()) {
+        Ok(options) => options,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let response_type = options.response_type;
+
     let request_name = input_struct.ident.clone();
     let response_name = Ident::new(&format!("{request_name}Response"), request_name.span());
 
@@ -22,39 +50,193 @@ See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-ref
     );
     let response_doc_attr = quote! { #[doc = #response_doc] };
 
+    let min_version = options.min_version.map(|version| {
+        quote! {
+            const MIN_VERSION: crate::server_version::ExchangeServerVersion =
+                crate::server_version::ExchangeServerVersion::#version;
+        }
+    });
+
+    let version_requirements = (!options.manual_version_requirements).then(|| {
+        quote! {
+            impl crate::server_version::VersionRequirements for #request_name {}
+        }
+    });
+
+    let response_struct = match options.shape {
+        ResponseShape::Messages => quote! {
+            #response_doc_attr
+            #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct #response_name {
+                pub response_messages: crate::ResponseMessages<#response_type>,
+            }
+
+            impl crate::OperationResponse for #response_name {
+                type Message = #response_type;
+                fn response_messages(&self) -> &[crate::ResponseClass<Self::Message>] {
+                    self.response_messages.response_messages.as_slice()
+                }
+                fn into_response_messages(self) -> Vec<crate::ResponseClass<Self::Message>> {
+                    self.response_messages.response_messages
+                }
+            }
+        },
+        ResponseShape::Class | ResponseShape::Message => {
+            let deserialize_with = matches!(options.shape, ResponseShape::Message).then(|| {
+                quote! {
+                    #[serde(deserialize_with = "crate::response::deserialize_response_message")]
+                }
+            });
+
+            quote! {
+                #response_doc_attr
+                #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+                #[serde(transparent)]
+                pub struct #response_name {
+                    #deserialize_with
+                    pub response_message: crate::ResponseClass<#response_type>,
+                }
+
+                impl crate::OperationResponse for #response_name {
+                    type Message = #response_type;
+                    fn response_messages(&self) -> &[crate::ResponseClass<Self::Message>] {
+                        std::slice::from_ref(&self.response_message)
+                    }
+                    fn into_response_messages(self) -> Vec<crate::ResponseClass<Self::Message>> {
+                        vec![self.response_message]
+                    }
+                }
+            }
+        }
+    };
+
+    let operation_name = match &options.name {
+        Some(name) => name.to_string(),
+        None => request_name.to_string(),
+    };
+    let response_element_name = match &options.response_name {
+        Some(name) => name.to_string(),
+        None => response_name.to_string(),
+    };
+
     let expanded = quote! {
         #input_struct
 
         impl crate::Operation for #request_name {
             type Response = #response_name;
-            const NAME: &'static str = stringify!(#request_name);
+            const NAME: &'static str = #operation_name;
+            #min_version
         }
 
         impl crate::types::sealed::EnvelopeBodyContents for #request_name {
-            const NAME: &'static str = stringify!(#request_name);
+            const NAME: &'static str = #operation_name;
         }
 
-        #response_doc_attr
-        #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
-        #[serde(rename_all = "PascalCase")]
-        pub struct #response_name {
-            pub response_messages: crate::ResponseMessages<#response_type>,
-        }
+        #version_requirements
 
-        impl crate::OperationResponse for #response_name {
-            type Message = #response_type;
-            fn response_messages(&self) -> &[crate::ResponseClass<Self::Message>] {
-                self.response_messages.response_messages.as_slice()
-            }
-            fn into_response_messages(self) -> Vec<crate::ResponseClass<Self::Message>> {
-                self.response_messages.response_messages
-            }
-        }
+        #response_struct
 
         impl crate::types::sealed::EnvelopeBodyContents for #response_name {
-            const NAME: &'static str = stringify!(#response_name);
+            const NAME: &'static str = #response_element_name;
         }
     };
 
     TokenStream::from(expanded)
 }
+
+/// The arguments provided to `#[operation_response(...)]`.
+struct OperationOptions {
+    response_type: Ident,
+    min_version: Option<Ident>,
+    manual_version_requirements: bool,
+    shape: ResponseShape,
+    name: Option<Ident>,
+    response_name: Option<Ident>,
+}
+
+/// The structure of the response to an operation.
+enum ResponseShape {
+    /// The response messages are contained in a `ResponseMessages` element.
+    Messages,
+
+    /// The response element is itself the single response message.
+    Class,
+
+    /// The response element contains a single `ResponseMessage` element
+    /// followed by the contents of the response.
+    Message,
+}
+
+impl OperationOptions {
+    fn from_args(args: Punctuated<Meta, Token![,]>, span: Span) -> syn::Result<Self> {
+        let mut args = args.into_iter();
+
+        let response_type = match args.next() {
+            Some(Meta::Path(path)) => path.require_ident()?.clone(),
+            Some(other) => {
+                return Err(syn::Error::new(
+                    other.span(),
+                    "expected the name of the response message type",
+                ))
+            }
+            None => {
+                return Err(syn::Error::new(
+                    span,
+                    "expected the name of the response message type",
+                ))
+            }
+        };
+
+        let mut options = Self {
+            response_type,
+            min_version: None,
+            manual_version_requirements: false,
+            shape: ResponseShape::Messages,
+            name: None,
+            response_name: None,
+        };
+
+        for arg in args {
+            match &arg {
+                Meta::NameValue(name_value) if name_value.path.is_ident("min_version") => {
+                    options.min_version = Some(expr_ident(&name_value.value)?);
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("shape") => {
+                    let shape = expr_ident(&name_value.value)?;
+                    options.shape = if shape == "ResponseMessages" {
+                        ResponseShape::Messages
+                    } else if shape == "ResponseClass" {
+                        ResponseShape::Class
+                    } else if shape == "ResponseMessage" {
+                        ResponseShape::Message
+                    } else {
+                        return Err(syn::Error::new(
+                            shape.span(),
+                            "expected `ResponseMessages`, `ResponseClass` or `ResponseMessage`",
+                        ));
+                    };
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("name") => {
+                    options.name = Some(expr_ident(&name_value.value)?);
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("response_name") => {
+                    options.response_name = Some(expr_ident(&name_value.value)?);
+                }
+                Meta::Path(path) if path.is_ident("manual_version_requirements") => {
+                    options.manual_version_requirements = true;
+                }
+                _ => return Err(syn::Error::new(arg.span(), "unrecognized option")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn expr_ident(expr: &Expr) -> syn::Result<Ident> {
+    match expr {
+        Expr::Path(path) => path.path.require_ident().cloned(),
+        _ => Err(syn::Error::new(expr.span(), "expected an identifier")),
+    }
+}